@@ -0,0 +1,262 @@
+use crate::fs::Fs;
+use crate::params::{EDWARDS_D, GENERATOR_T, GENERATOR_X, GENERATOR_Y};
+use bn_254::Fr;
+use zkstd::arithmetic::edwards::*;
+use zkstd::common::*;
+use zkstd::macros::curve::edwards::*;
+
+/// The affine form of coordinate
+#[derive(Clone, Copy, Debug, Encode, Decode)]
+pub struct Affine {
+    x: Fr,
+    y: Fr,
+}
+
+impl Add for Affine {
+    type Output = Extended;
+
+    fn add(self, rhs: Affine) -> Self::Output {
+        add_projective_point(self.to_extended(), rhs.to_extended())
+    }
+}
+
+impl Neg for Affine {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl Sub for Affine {
+    type Output = Extended;
+
+    fn sub(self, rhs: Affine) -> Self::Output {
+        add_projective_point(self.to_extended(), rhs.neg().to_extended())
+    }
+}
+
+impl Mul<Fs> for Affine {
+    type Output = Extended;
+
+    fn mul(self, rhs: Fs) -> Self::Output {
+        scalar_point(self.to_extended(), &rhs)
+    }
+}
+
+impl Mul<Affine> for Fs {
+    type Output = Extended;
+
+    fn mul(self, rhs: Affine) -> Self::Output {
+        scalar_point(rhs.to_extended(), &self)
+    }
+}
+
+/// The extended form of coordinate
+#[derive(Clone, Copy, Debug, Encode, Decode)]
+pub struct Extended {
+    x: Fr,
+    y: Fr,
+    t: Fr,
+    z: Fr,
+}
+
+impl Add for Extended {
+    type Output = Extended;
+
+    fn add(self, rhs: Extended) -> Self::Output {
+        add_projective_point(self, rhs)
+    }
+}
+
+impl Neg for Extended {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: self.y,
+            t: -self.t,
+            z: self.z,
+        }
+    }
+}
+
+impl Sub for Extended {
+    type Output = Extended;
+
+    fn sub(self, rhs: Extended) -> Self::Output {
+        add_projective_point(self, rhs.neg())
+    }
+}
+
+impl Mul<Fs> for Extended {
+    type Output = Extended;
+
+    fn mul(self, rhs: Fs) -> Self::Output {
+        scalar_point(self, &rhs)
+    }
+}
+
+impl Mul<Extended> for Fs {
+    type Output = Extended;
+
+    fn mul(self, rhs: Extended) -> Self::Output {
+        scalar_point(rhs, &self)
+    }
+}
+
+/// Compressed as the y coordinate with the parity of x in the most significant bit
+impl SigUtils<32> for Affine {
+    fn to_bytes(self) -> [u8; Self::LENGTH] {
+        let mut tmp = self.y.to_bytes();
+        let x = self.x.to_bytes();
+        tmp[31] |= x[0] << 7;
+
+        tmp
+    }
+
+    fn from_bytes(mut bytes: [u8; Self::LENGTH]) -> Option<Self> {
+        let sign = (bytes[31] >> 7) == 1;
+        bytes[31] &= 0b01111111;
+
+        let y = Fr::from_bytes(bytes)?;
+        // -x^2 + y^2 = 1 + dx^2y^2
+        let y2 = y.square();
+        let xx = (y2 - Fr::one()) * (EDWARDS_D * y2 + Fr::one()).invert()?;
+        let mut x = xx.sqrt()?;
+        // x = 0 has no negative so its sign bit must be clear
+        if x.is_zero() && sign {
+            return None;
+        }
+        if x.is_odd() ^ sign {
+            x = -x;
+        }
+        Some(Self { x, y })
+    }
+}
+
+impl SigUtils<32> for Extended {
+    fn to_bytes(self) -> [u8; Self::LENGTH] {
+        self.to_affine().to_bytes()
+    }
+
+    fn from_bytes(bytes: [u8; Self::LENGTH]) -> Option<Self> {
+        Affine::from_bytes(bytes).map(Affine::to_extended)
+    }
+}
+
+/// Ordered by the compressed encoding
+impl PartialOrd for Extended {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Extended {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_bytes().cmp(&other.to_bytes())
+    }
+}
+
+/// Baby Jubjub parameters of the RedDSA signature scheme
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BabyJubjub;
+
+impl RedDSA for BabyJubjub {
+    const COFACTOR_BITS: usize = 3;
+
+    type Base = Fr;
+
+    type Scalar = Fs;
+
+    type Affine = Affine;
+
+    type Extended = Extended;
+}
+
+twisted_edwards_curve_operation!(
+    Fs,
+    Fr,
+    EDWARDS_D,
+    Affine,
+    Extended,
+    GENERATOR_X,
+    GENERATOR_Y,
+    GENERATOR_T
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params::SQRT_MINUS_A;
+    use rand_core::OsRng;
+
+    #[test]
+    fn generator_test() {
+        let g = Affine::ADDITIVE_GENERATOR;
+        assert!(g.is_on_curve());
+        assert_eq!(g.to_extended(), Extended::ADDITIVE_GENERATOR);
+
+        // EIP-2494 Base8 on 168700x^2 + y^2 = 1 + 168696x^2y^2
+        let (a, d) = (Fr::from(168700), Fr::from(168696));
+        let x = g.get_x() * SQRT_MINUS_A.invert().unwrap();
+        let (xx, yy) = (x.square(), g.get_y().square());
+        assert_eq!(a * xx + yy, Fr::one() + d * xx * yy);
+        assert_eq!(-SQRT_MINUS_A.square(), a);
+
+        // the generator has prime order l
+        let l_minus_one = -Fs::one();
+        let p = g * l_minus_one;
+        assert_eq!(p, -Extended::ADDITIVE_GENERATOR);
+        assert!((p + g.to_extended()).is_identity());
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        let mut rng = OsRng;
+        for _ in 0..100 {
+            let a = Fs::random(&mut rng);
+            let b = Fs::random(&mut rng);
+            let g = Extended::ADDITIVE_GENERATOR;
+            let p = g * a;
+            let q = g * b;
+
+            assert!(p.is_on_curve());
+            assert_eq!(p + q, g * (a + b));
+            assert_eq!(p - q, g * (a - b));
+            assert_eq!(p.double(), p + p);
+            assert_eq!(p.to_affine().double(), p + p);
+            assert_eq!(p.to_affine() + q.to_affine(), p + q);
+            assert_eq!(p * b, q * a);
+            assert_eq!(p + Extended::ADDITIVE_IDENTITY, p);
+            assert!((p - p).is_identity());
+        }
+    }
+
+    #[test]
+    fn compression_test() {
+        let mut rng = OsRng;
+        for _ in 0..100 {
+            let p = Affine::random(&mut rng);
+            let bytes = p.to_bytes();
+            assert_eq!(Affine::from_bytes(bytes), Some(p));
+            assert_eq!(Extended::from_bytes(bytes), Some(p.to_extended()));
+        }
+        let identity = Affine::ADDITIVE_IDENTITY;
+        assert_eq!(Affine::from_bytes(identity.to_bytes()), Some(identity));
+
+        // non-canonical encodings of the points with x = 0
+        let mut bytes = identity.to_bytes();
+        bytes[31] |= 0x80;
+        assert_eq!(Affine::from_bytes(bytes), None);
+        assert_eq!(Extended::from_bytes(bytes), None);
+        let mut bytes = (-Fr::one()).to_bytes();
+        assert!(Affine::from_bytes(bytes).unwrap().get_x().is_zero());
+        bytes[31] |= 0x80;
+        assert_eq!(Affine::from_bytes(bytes), None);
+    }
+}
@@ -0,0 +1,203 @@
+use bn_254::Fr;
+use core::borrow::Borrow;
+use core::iter::{Product, Sum};
+use zkstd::arithmetic::bits_256::*;
+use zkstd::common::*;
+use zkstd::macros::field::*;
+
+/// l = 0x060c89ce5c263405370a08b6d0302b0bab3eedb83920ee0a677297dc392126f1
+pub(crate) const MODULUS: [u64; 4] = [
+    0x677297dc392126f1,
+    0xab3eedb83920ee0a,
+    0x370a08b6d0302b0b,
+    0x060c89ce5c263405,
+];
+
+const GENERATOR: [u64; 4] = [31, 0, 0, 0];
+
+/// generator of the scalar field
+pub const MULTIPLICATIVE_GENERATOR: Fs = Fs::to_mont_form(GENERATOR);
+
+/// `R = 2^256 mod l`
+/// `0x1f16424e1bb7724f85a9201d818f015e7acffc6a098f24b073315dea08f9c76`
+const R: [u64; 4] = [
+    0x073315dea08f9c76,
+    0xe7acffc6a098f24b,
+    0xf85a9201d818f015,
+    0x01f16424e1bb7724,
+];
+
+/// `R^2 = 2^512 mod l`
+/// `0x445b524f1ba50a8e472df203faa158f74646cacf5f84ec435e44abee7ecb21e`
+const R2: [u64; 4] = [
+    0x35e44abee7ecb21e,
+    0x74646cacf5f84ec4,
+    0xe472df203faa158f,
+    0x0445b524f1ba50a8,
+];
+
+/// `R^3 = 2^768 mod l`
+/// `0x2b4dbffb2bc97aaeefe3d08cc608b7b98dae87b55d7cd2a30886e7b42917c21`
+const R3: [u64; 4] = [
+    0x30886e7b42917c21,
+    0x98dae87b55d7cd2a,
+    0xeefe3d08cc608b7b,
+    0x02b4dbffb2bc97aa,
+];
+
+/// INV = -(l^{-1} mod 2^64) mod 2^64
+const INV: u64 = 0x532ce5aebc48f5ef;
+
+const S: usize = 4;
+
+/// GENERATOR^t where t * 2^s + 1 = l with t odd
+/// `0x17607cccda766fb904adf8e5a770f6e3fe66e76e0a41338daf49a41ac514473`
+pub const ROOT_OF_UNITY: Fs = Fs::to_mont_form([
+    0xdaf49a41ac514473,
+    0x3fe66e76e0a41338,
+    0x904adf8e5a770f6e,
+    0x017607cccda766fb,
+]);
+
+/// Baby Jubjub curve scalar field, the order of the prime subgroup
+#[derive(Clone, Copy, Decode, Encode, Serialize, Deserialize)]
+pub struct Fs(pub [u64; 4]);
+
+impl SigUtils<32> for Fs {
+    fn to_bytes(self) -> [u8; Self::LENGTH] {
+        let tmp = self.montgomery_reduce();
+
+        let mut res = [0; Self::LENGTH];
+        res[0..8].copy_from_slice(&tmp[0].to_le_bytes());
+        res[8..16].copy_from_slice(&tmp[1].to_le_bytes());
+        res[16..24].copy_from_slice(&tmp[2].to_le_bytes());
+        res[24..32].copy_from_slice(&tmp[3].to_le_bytes());
+
+        res
+    }
+
+    fn from_bytes(bytes: [u8; Self::LENGTH]) -> Option<Self> {
+        let l0 = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+        let l1 = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        let l2 = u64::from_le_bytes(bytes[16..24].try_into().unwrap());
+        let l3 = u64::from_le_bytes(bytes[24..32].try_into().unwrap());
+
+        let (_, borrow) = sbb(l0, MODULUS[0], 0);
+        let (_, borrow) = sbb(l1, MODULUS[1], borrow);
+        let (_, borrow) = sbb(l2, MODULUS[2], borrow);
+        let (_, borrow) = sbb(l3, MODULUS[3], borrow);
+
+        if borrow & 1 == 1 {
+            Some(Self([l0, l1, l2, l3]) * Self(R2))
+        } else {
+            None
+        }
+    }
+}
+
+impl Fs {
+    pub const fn to_mont_form(val: [u64; 4]) -> Self {
+        Self(to_mont_form(val, R2, MODULUS, INV))
+    }
+
+    pub(crate) const fn montgomery_reduce(self) -> [u64; 4] {
+        mont(
+            [self.0[0], self.0[1], self.0[2], self.0[3], 0, 0, 0, 0],
+            MODULUS,
+            INV,
+        )
+    }
+}
+
+impl<T> Product<T> for Fs
+where
+    T: Borrow<Fs>,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::one(), |acc, item| acc * *item.borrow())
+    }
+}
+
+impl<T> Sum<T> for Fs
+where
+    T: Borrow<Fs>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::zero(), |acc, item| acc + *item.borrow())
+    }
+}
+
+fft_field_operation!(
+    Fs,
+    MODULUS,
+    GENERATOR,
+    MULTIPLICATIVE_GENERATOR,
+    INV,
+    ROOT_OF_UNITY,
+    R,
+    R2,
+    R3,
+    S
+);
+
+/// Reduce a base field element modulo the subgroup order
+impl From<Fr> for Fs {
+    fn from(val: Fr) -> Fs {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&val.to_bytes());
+        Fs::from_bytes_wide(&bytes)
+    }
+}
+
+/// Embed a scalar into the base field, `l` is smaller than the bn254 scalar field modulus
+impl From<Fs> for Fr {
+    fn from(val: Fs) -> Fr {
+        Fr::to_mont_form(val.montgomery_reduce())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::paste;
+    use rand_core::OsRng;
+
+    field_test!(baby_jubjub_scalar, Fs, 1000);
+
+    #[test]
+    fn test_root_of_unity() {
+        let s = <Fs as FftField>::S;
+        let mut root_of_unity = <Fs as FftField>::ROOT_OF_UNITY;
+        (0..s).for_each(|_| root_of_unity.square_assign());
+        assert_eq!(root_of_unity, Fs::one())
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rng = OsRng;
+        for _ in 0..1000 {
+            let s = Fs::random(&mut rng);
+            let bytes = s.to_bytes();
+            let s_prime = Fs::from_bytes(bytes).unwrap();
+            assert_eq!(s, s_prime);
+        }
+    }
+
+    #[test]
+    fn to_fr_and_back() {
+        let mut rng = OsRng;
+        let x = Fs::random(&mut rng);
+        assert_eq!(x, Fs::from(Fr::from(x)));
+
+        // l reduces to zero
+        let l = Fr::to_mont_form(MODULUS);
+        assert_eq!(Fs::from(l), Fs::zero());
+        assert_eq!(Fs::from(l + Fr::one()), Fs::one());
+    }
+}
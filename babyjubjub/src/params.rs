@@ -0,0 +1,41 @@
+use bn_254::Fr;
+
+/// d = -168696 / 168700 of the reduced twisted Edwards form
+/// `0x1aee90f15f2189693df072d799fd11fc039b2959ebb7c867d075ca8cf4d7eb8e`
+pub const EDWARDS_D: Fr = Fr::to_mont_form([
+    0xd075ca8cf4d7eb8e,
+    0x039b2959ebb7c867,
+    0x3df072d799fd11fc,
+    0x1aee90f15f218969,
+]);
+
+/// sqrt(-168700) mapping the x coordinate of the a = 168700 form to the reduced form
+/// `0xe0ff35005d6e54b2c7ac0ce84e6117774a5f6ca321ea1176c7be2c1d8b2d768`
+pub const SQRT_MINUS_A: Fr = Fr::to_mont_form([
+    0x6c7be2c1d8b2d768,
+    0x74a5f6ca321ea117,
+    0x2c7ac0ce84e61177,
+    0x0e0ff35005d6e54b,
+]);
+
+// generator of the prime order subgroup
+pub const GENERATOR_X: Fr = Fr::to_mont_form([
+    0x51e8dc38f1aac062,
+    0xf0b773ad92c6ce19,
+    0x2e018e11bfe76f10,
+    0x1b024eef745002f4,
+]);
+
+pub const GENERATOR_Y: Fr = Fr::to_mont_form([
+    0x4b3c257a872d7d8b,
+    0xfce0051fb9e13377,
+    0x25572e1cd16bf9ed,
+    0x25797203f7a0b249,
+]);
+
+pub const GENERATOR_T: Fr = Fr::to_mont_form([
+    0x8c5f0a5adcee13f6,
+    0xd70950e7adac2af8,
+    0x68cb336eb1247b76,
+    0x0f3295119dc889c5,
+]);
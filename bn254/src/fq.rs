@@ -132,6 +132,18 @@ impl Fq {
         }
     }
 
+    /// Canonical big-endian encoding as used by the Ethereum precompiles
+    pub fn to_bytes_be(self) -> [u8; Self::LENGTH] {
+        let mut bytes = self.to_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    pub fn from_bytes_be(mut bytes: [u8; Self::LENGTH]) -> Option<Self> {
+        bytes.reverse();
+        Self::from_bytes(bytes)
+    }
+
     pub fn lexicographically_largest(&self) -> bool {
         // This can be determined by checking to see if the element is
         // larger than (p - 1) // 2. If we subtract by ((p - 1) // 2) + 1
@@ -201,7 +213,9 @@ mod tests {
             let bytes = s.to_bytes();
             let s_prime = Fq::from_bytes(bytes).unwrap();
             assert_eq!(s, s_prime);
+            assert_eq!(Fq::from_bytes_be(s.to_bytes_be()), Some(s));
         }
+        assert_eq!(Fq::from_bytes_be([0xff; 32]), None);
     }
 
     #[test]
@@ -13,6 +13,11 @@ pub const BETA: Fq = Fq([
     0xc26a2ff874fd029b,
 ]);
 
+/// Flags in the most significant byte of a compressed point,
+/// the base field modulus leaves the two most significant bits free
+pub(crate) const INFINITY_FLAG: u8 = 0x80;
+pub(crate) const SIGN_FLAG: u8 = 0x40;
+
 /// The projective form of coordinate
 #[derive(Debug, Clone, Copy, Decode, Encode)]
 pub struct G1Affine {
@@ -32,6 +37,8 @@ fn endomorphism(p: &G1Affine) -> G1Affine {
 
 impl G1Affine {
     pub const RAW_SIZE: usize = 97;
+    pub const COMPRESSED_SIZE: usize = 32;
+    pub const UNCOMPRESSED_SIZE: usize = 64;
 
     pub fn from_slice_unchecked(bytes: &[u8]) -> Self {
         let mut x = [0u64; 4];
@@ -68,6 +75,70 @@ impl G1Affine {
         let endomorphism_p = endomorphism(self);
         minus_x_squared_times_p == G1Projective::from(endomorphism_p)
     }
+
+    /// Big-endian x coordinate with the infinity flag in the most significant bit
+    /// and whether y is lexicographically largest in the next bit
+    pub fn to_compressed(&self) -> [u8; Self::COMPRESSED_SIZE] {
+        if self.is_infinity {
+            let mut bytes = [0; Self::COMPRESSED_SIZE];
+            bytes[0] = INFINITY_FLAG;
+            return bytes;
+        }
+        let mut bytes = self.x.to_bytes_be();
+        if self.y.lexicographically_largest() {
+            bytes[0] |= SIGN_FLAG;
+        }
+        bytes
+    }
+
+    /// Decode a point encoded by `to_compressed`, which is always on the curve
+    pub fn from_compressed(bytes: &[u8; Self::COMPRESSED_SIZE]) -> Option<Self> {
+        let (infinity, sign) = (bytes[0] & INFINITY_FLAG != 0, bytes[0] & SIGN_FLAG != 0);
+        let mut x = *bytes;
+        x[0] &= !(INFINITY_FLAG | SIGN_FLAG);
+        if infinity {
+            return (!sign && x.iter().all(|byte| *byte == 0)).then_some(Self::ADDITIVE_IDENTITY);
+        }
+        let x = Fq::from_bytes_be(x)?;
+        let y = (x.square() * x + G1_PARAM_B).sqrt()?;
+        let y = if y.lexicographically_largest() == sign {
+            y
+        } else {
+            -y
+        };
+
+        Some(Self {
+            x,
+            y,
+            is_infinity: false,
+        })
+    }
+
+    /// Big-endian x and y coordinates as in EIP-196, the identity is encoded as zeros
+    pub fn to_uncompressed(&self) -> [u8; Self::UNCOMPRESSED_SIZE] {
+        let mut bytes = [0; Self::UNCOMPRESSED_SIZE];
+        if !self.is_infinity {
+            bytes[..32].copy_from_slice(&self.x.to_bytes_be());
+            bytes[32..].copy_from_slice(&self.y.to_bytes_be());
+        }
+        bytes
+    }
+
+    /// Decode a point encoded by `to_uncompressed` and check that it is on the curve
+    pub fn from_uncompressed(bytes: &[u8; Self::UNCOMPRESSED_SIZE]) -> Option<Self> {
+        if bytes.iter().all(|byte| *byte == 0) {
+            return Some(Self::ADDITIVE_IDENTITY);
+        }
+        let x = Fq::from_bytes_be(bytes[..32].try_into().unwrap())?;
+        let y = Fq::from_bytes_be(bytes[32..].try_into().unwrap())?;
+        let point = Self {
+            x,
+            y,
+            is_infinity: false,
+        };
+
+        point.is_on_curve().then_some(point)
+    }
 }
 
 impl Add for G1Affine {
@@ -230,6 +301,37 @@ mod tests {
 
     curve_test!(bn254, Fr, G1Affine, G1Projective, 100);
 
+    #[test]
+    fn test_encoding() {
+        let mut rng = OsRng;
+        for _ in 0..100 {
+            let p = G1Affine::random(&mut rng);
+            assert_eq!(G1Affine::from_compressed(&p.to_compressed()), Some(p));
+            assert_eq!(G1Affine::from_compressed(&(-p).to_compressed()), Some(-p));
+            assert_eq!(G1Affine::from_uncompressed(&p.to_uncompressed()), Some(p));
+        }
+        let identity = G1Affine::ADDITIVE_IDENTITY;
+        assert_eq!(
+            G1Affine::from_compressed(&identity.to_compressed()),
+            Some(identity)
+        );
+        assert_eq!(
+            G1Affine::from_uncompressed(&identity.to_uncompressed()),
+            Some(identity)
+        );
+
+        // the generator (1, 2) in the encoding of the precompiles
+        let mut bytes = [0; 64];
+        bytes[31] = 1;
+        bytes[63] = 2;
+        assert_eq!(G1Affine::ADDITIVE_GENERATOR.to_uncompressed(), bytes);
+        bytes[63] = 3;
+        assert_eq!(G1Affine::from_uncompressed(&bytes), None);
+        let mut flags = [0; 32];
+        flags[0] = INFINITY_FLAG | SIGN_FLAG;
+        assert_eq!(G1Affine::from_compressed(&flags), None);
+    }
+
     #[test]
     fn test_batch_normalize() {
         let a = G1Projective::ADDITIVE_GENERATOR.double();
@@ -1,6 +1,7 @@
 use crate::fq::Fq;
 use crate::fqn::Fq2;
 use crate::fr::Fr;
+use crate::g1::{INFINITY_FLAG, SIGN_FLAG};
 use crate::pairing::{SIX_U_PLUS_2_NAF, XI_TO_Q_MINUS_1_OVER_2};
 use crate::params::*;
 use core::borrow::Borrow;
@@ -20,16 +21,92 @@ pub struct G2Affine {
 }
 
 impl G2Affine {
+    pub const COMPRESSED_SIZE: usize = 64;
+    pub const UNCOMPRESSED_SIZE: usize = 128;
+
     /// Returns true if this point is free of an $h$-torsion component, and so it
     /// exists within the $q$-order subgroup $\mathbb{G}_2$. This should always return true
     /// unless an "unchecked" API was used.
     pub fn is_torsion_free(&self) -> bool {
-        // Algorithm from Section 4 of https://eprint.iacr.org/2021/1130
-        // Updated proof of correctness in https://eprint.iacr.org/2022/352
+        // Algorithm from Section 3 of https://eprint.iacr.org/2022/352 for BN curves
         //
-        // Check that psi(P) == [x] P
+        // Check that psi(P) == [6x^2] P
         let p = G2Projective::from(*self);
-        p.psi() == p.mul_by_x()
+        p.psi() == p.mul_by_six_x_squared()
+    }
+
+    /// Big-endian x coordinate, imaginary part first as in EIP-197, with the flags
+    /// of `G1Affine::to_compressed` in the most significant bits
+    pub fn to_compressed(&self) -> [u8; Self::COMPRESSED_SIZE] {
+        let mut bytes = [0; Self::COMPRESSED_SIZE];
+        if self.is_infinity {
+            bytes[0] = INFINITY_FLAG;
+            return bytes;
+        }
+        bytes[..32].copy_from_slice(&self.x.0[1].to_bytes_be());
+        bytes[32..].copy_from_slice(&self.x.0[0].to_bytes_be());
+        if self.y.lexicographically_largest() {
+            bytes[0] |= SIGN_FLAG;
+        }
+        bytes
+    }
+
+    /// Decode a point encoded by `to_compressed` and check that it is in the subgroup
+    pub fn from_compressed(bytes: &[u8; Self::COMPRESSED_SIZE]) -> Option<Self> {
+        let (infinity, sign) = (bytes[0] & INFINITY_FLAG != 0, bytes[0] & SIGN_FLAG != 0);
+        let mut x = *bytes;
+        x[0] &= !(INFINITY_FLAG | SIGN_FLAG);
+        if infinity {
+            return (!sign && x.iter().all(|byte| *byte == 0)).then_some(Self::ADDITIVE_IDENTITY);
+        }
+        let x = Fq2([
+            Fq::from_bytes_be(x[32..].try_into().unwrap())?,
+            Fq::from_bytes_be(x[..32].try_into().unwrap())?,
+        ]);
+        let y = (x.square() * x + G2_PARAM_B).sqrt()?;
+        let y = if y.lexicographically_largest() == sign {
+            y
+        } else {
+            -y
+        };
+        let point = Self {
+            x,
+            y,
+            is_infinity: false,
+        };
+
+        point.is_torsion_free().then_some(point)
+    }
+
+    /// Big-endian x and y coordinates, imaginary parts first as in EIP-197,
+    /// the identity is encoded as zeros
+    pub fn to_uncompressed(&self) -> [u8; Self::UNCOMPRESSED_SIZE] {
+        let mut bytes = [0; Self::UNCOMPRESSED_SIZE];
+        if !self.is_infinity {
+            let coordinates = [self.x.0[1], self.x.0[0], self.y.0[1], self.y.0[0]];
+            for (chunk, c) in bytes.chunks_exact_mut(32).zip(coordinates) {
+                chunk.copy_from_slice(&c.to_bytes_be());
+            }
+        }
+        bytes
+    }
+
+    /// Decode a point encoded by `to_uncompressed` and check that it is in the subgroup
+    pub fn from_uncompressed(bytes: &[u8; Self::UNCOMPRESSED_SIZE]) -> Option<Self> {
+        if bytes.iter().all(|byte| *byte == 0) {
+            return Some(Self::ADDITIVE_IDENTITY);
+        }
+        let mut coordinates = [Fq::zero(); 4];
+        for (c, chunk) in coordinates.iter_mut().zip(bytes.chunks_exact(32)) {
+            *c = Fq::from_bytes_be(chunk.try_into().unwrap())?;
+        }
+        let point = Self {
+            x: Fq2([coordinates[1], coordinates[0]]),
+            y: Fq2([coordinates[3], coordinates[2]]),
+            is_infinity: false,
+        };
+
+        (point.is_on_curve() && point.is_torsion_free()).then_some(point)
     }
 }
 
@@ -85,66 +162,6 @@ pub struct G2Projective {
     pub(crate) z: Fq2,
 }
 
-impl G2Projective {
-    /// Multiply `self` by `crate::BN_X`, using double and add.
-    fn mul_by_x(&self) -> G2Projective {
-        let mut xself = G2Projective::ADDITIVE_IDENTITY;
-        // NOTE: in BLS12-381 we can just skip the first bit.
-        // TODO: need to test conversion to bytes and back
-        let mut x = BN_X >> 1;
-        let mut acc = *self;
-        while x != 0 {
-            acc = acc.double();
-            if x % 2 == 1 {
-                xself += acc;
-            }
-            x >>= 1;
-        }
-        // finally, flip the sign
-        if BN_X_IS_NEGATIVE {
-            xself = -xself;
-        }
-        xself
-    }
-
-    fn psi(&self) -> G2Projective {
-        // 1 / ((u+1) ^ ((q-1)/3))
-        let psi_coeff_x = Fq2([
-            Fq::zero(),
-            Fq([
-                0x890dc9e4867545c3,
-                0x2af322533285a5d5,
-                0x50880866309b7e2c,
-                0xa20d1b8c7e881024,
-            ]),
-        ]);
-        // 1 / ((u+1) ^ (p-1)/2)
-        let psi_coeff_y = Fq2([
-            Fq([
-                0x3e2f585da55c9ad1,
-                0x4294213d86c18183,
-                0x382844c88b623732,
-                0x92ad2afd19103e18,
-            ]),
-            Fq([
-                0x7bcfa7a25aa30fda,
-                0xdc17dec12a927e7c,
-                0x2f088dd86b4ebef1,
-                0xd1ca2087da74d4a7,
-            ]),
-        ]);
-
-        G2Projective {
-            // x = frobenius(x)/((u+1)^((p-1)/3))
-            x: self.x.frobenius_map() * psi_coeff_x,
-            // y = frobenius(y)/(u+1)^((p-1)/2)
-            y: self.y.frobenius_map() * psi_coeff_y,
-            // z = frobenius(z)
-            z: self.z.frobenius_map(),
-        }
-    }
-}
-
 impl Add for G2Projective {
     type Output = Self;
 
@@ -210,6 +227,33 @@ impl ParityCmp for PairingCoeff {}
 impl ParityCmp for G2PairingAffine {}
 
 impl G2Projective {
+    /// Multiply `self` by `6 * crate::BN_X^2`, using double and add.
+    fn mul_by_six_x_squared(&self) -> G2Projective {
+        let mut x = 6 * BN_X as u128 * BN_X as u128;
+        let mut xself = G2Projective::ADDITIVE_IDENTITY;
+        let mut acc = *self;
+        while x != 0 {
+            if x % 2 == 1 {
+                xself += acc;
+            }
+            acc = acc.double();
+            x >>= 1;
+        }
+        xself
+    }
+
+    /// The untwist-Frobenius-twist endomorphism, as applied to Q in the Miller loop
+    fn psi(&self) -> G2Projective {
+        G2Projective {
+            // x = frobenius(x) * (u + 9)^((p - 1) / 3)
+            x: self.x.frobenius_map() * FROBENIUS_COEFF_FQ6_C1[1],
+            // y = frobenius(y) * (u + 9)^((p - 1) / 2)
+            y: self.y.frobenius_map() * XI_TO_Q_MINUS_1_OVER_2,
+            // z = frobenius(z)
+            z: self.z.frobenius_map(),
+        }
+    }
+
     pub(crate) fn double_eval(&mut self) -> PairingCoeff {
         // Adaptation of Algorithm 26, https://eprint.iacr.org/2010/354.pdf
         let tmp0 = self.x.square();
@@ -348,4 +392,72 @@ mod tests {
     use rand_core::OsRng;
 
     curve_test!(bn254, Fr, G2Affine, G2Projective, 50);
+
+    #[test]
+    fn test_encoding() {
+        use super::*;
+
+        let mut rng = OsRng;
+        for _ in 0..20 {
+            let p = G2Affine::random(&mut rng);
+            assert_eq!(G2Affine::from_compressed(&p.to_compressed()), Some(p));
+            assert_eq!(G2Affine::from_compressed(&(-p).to_compressed()), Some(-p));
+            assert_eq!(G2Affine::from_uncompressed(&p.to_uncompressed()), Some(p));
+        }
+        let identity = G2Affine::ADDITIVE_IDENTITY;
+        assert_eq!(
+            G2Affine::from_compressed(&identity.to_compressed()),
+            Some(identity)
+        );
+        assert_eq!(
+            G2Affine::from_uncompressed(&identity.to_uncompressed()),
+            Some(identity)
+        );
+
+        // a point on the twist outside of the subgroup
+        let mut x = Fq2::one();
+        let point = loop {
+            if let Some(y) = (x.square() * x + G2_PARAM_B).sqrt() {
+                break G2Affine {
+                    x,
+                    y,
+                    is_infinity: false,
+                };
+            }
+            x += Fq2::one();
+        };
+        assert!(!point.is_torsion_free());
+        assert_eq!(G2Affine::from_uncompressed(&point.to_uncompressed()), None);
+        assert_eq!(G2Affine::from_compressed(&point.to_compressed()), None);
+    }
+
+    #[test]
+    fn test_torsion_free() {
+        use super::*;
+
+        let mut rng = OsRng;
+        let generator = G2Affine::ADDITIVE_GENERATOR;
+        assert!(generator.is_torsion_free());
+        assert!(G2Affine::ADDITIVE_IDENTITY.is_torsion_free());
+        for _ in 0..10 {
+            assert!(G2Affine::random(&mut rng).is_torsion_free());
+        }
+
+        // the point of the twist with x = 1, which is not in the subgroup
+        let x = Fq2::one();
+        let y = (x.square() * x + G2_PARAM_B).sqrt().unwrap();
+        let point = G2Affine {
+            x,
+            y,
+            is_infinity: false,
+        };
+        assert!(point.is_on_curve());
+        assert!(!point.is_torsion_free());
+        let p = G2Projective::from(point);
+        assert!(p * -Fr::one() != -p);
+
+        // a point of the subgroup shifted by it
+        let shifted = G2Affine::from(G2Projective::from(generator) + p);
+        assert!(!shifted.is_torsion_free());
+    }
 }
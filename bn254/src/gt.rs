@@ -1,4 +1,5 @@
-use crate::Fq12;
+use crate::fr::MODULUS;
+use crate::{Fq, Fq12, Fq2, Fq6, Fr};
 use zkstd::common::*;
 
 /// pairing target group of 12 degree extension of field
@@ -152,8 +153,79 @@ impl MulAssign for Gt {
     }
 }
 
+impl Mul<Fr> for Gt {
+    type Output = Gt;
+
+    /// Variable time in the scalar, which must not be secret
+    fn mul(self, rhs: Fr) -> Gt {
+        Gt(pow_vartime(self.0, &rhs.montgomery_reduce()))
+    }
+}
+
 impl Gt {
+    pub const SIZE: usize = 384;
+
     pub fn double(&self) -> Gt {
         Gt(self.0.square())
     }
+
+    /// Big-endian coefficients of the Fq12 element, lowest degree first
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        let coefficients = self.0 .0.iter().flat_map(|c| c.0).flat_map(|c| c.0);
+        for (chunk, c) in bytes.chunks_exact_mut(32).zip(coefficients) {
+            chunk.copy_from_slice(&c.to_bytes_be());
+        }
+        bytes
+    }
+
+    /// Decode an element encoded by `to_bytes` and check that it is in the subgroup
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Option<Self> {
+        let mut coefficients = [Fq::zero(); 12];
+        for (c, chunk) in coefficients.iter_mut().zip(bytes.chunks_exact(32)) {
+            *c = Fq::from_bytes_be(chunk.try_into().unwrap())?;
+        }
+        let fq2 = |i: usize| Fq2([coefficients[i], coefficients[i + 1]]);
+        let fq6 = |i: usize| Fq6([fq2(i), fq2(i + 2), fq2(i + 4)]);
+        let element = Fq12([fq6(0), fq6(6)]);
+
+        (pow_vartime(element, &MODULUS) == Fq12::one()).then_some(Self(element))
+    }
+}
+
+fn pow_vartime(base: Fq12, by: &[u64; 4]) -> Fq12 {
+    let mut res = Fq12::one();
+    for e in by.iter().rev() {
+        for i in (0..64).rev() {
+            res = res.square();
+            if ((*e >> i) & 1) == 1 {
+                res *= base;
+            }
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AteParing, G1Affine, G2Affine, G2PairingAffine};
+    use rand_core::OsRng;
+
+    #[test]
+    fn scalar_and_bytes_test() {
+        let a = Fr::random(&mut OsRng);
+        let p = G1Affine::random(&mut OsRng);
+        let q = G2Affine::random(&mut OsRng);
+        let pairing = |p: G1Affine, q: G2Affine| {
+            AteParing::multi_miller_loop(&[(p, G2PairingAffine::from(q))]).final_exp()
+        };
+        let gt = pairing(p, q);
+
+        assert_eq!(pairing(G1Affine::from(p * a), q), gt * a);
+        assert_eq!(gt * Fr::zero(), Gt::ADDITIVE_IDENTITY);
+        assert_eq!(Gt::from_bytes(&gt.to_bytes()), Some(gt));
+        assert_eq!(Gt::from_bytes(&[0; Gt::SIZE]), None);
+        assert_eq!(Gt::from_bytes(&[0xff; Gt::SIZE]), None);
+    }
 }
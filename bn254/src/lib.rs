@@ -35,4 +35,4 @@ pub use fr::{Fr, MULTIPLICATIVE_GENERATOR, ROOT_OF_UNITY, TWO_ADACITY};
 pub use g1::{G1Affine, G1Projective};
 pub use g2::{G2Affine, G2PairingAffine, G2Projective, PairingCoeff};
 pub use gt::Gt;
-pub use pairing::AteParing;
+pub use pairing::{AteParing, Bn254};
@@ -1,4 +1,6 @@
-use crate::{Fq, Fq12, Fq2, G1Affine, G2Affine, G2PairingAffine, Gt};
+use crate::{
+    Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2PairingAffine, G2Projective, Gt,
+};
 use zkstd::common::*;
 
 // 6U+2 for in NAF form
@@ -82,3 +84,26 @@ impl AteParing {
         acc
     }
 }
+
+/// Bn254 curve as the pairing engine of pairing-based arguments
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bn254;
+
+impl PairingEngine for Bn254 {
+    type Fr = Fr;
+    type G1Affine = G1Affine;
+    type G1Projective = G1Projective;
+    type G2Affine = G2Affine;
+    type G2Projective = G2Projective;
+    type G2Prepared = G2PairingAffine;
+    type Gt = Gt;
+    type MillerLoopOutput = Fq12;
+
+    fn multi_miller_loop(pairs: &[(G1Affine, G2PairingAffine)]) -> Fq12 {
+        AteParing::multi_miller_loop(pairs)
+    }
+
+    fn final_exponentiation(f: Fq12) -> Gt {
+        f.final_exp()
+    }
+}
@@ -0,0 +1,86 @@
+use bn_254::Fr as BnScalar;
+use std::{env, fs, process};
+use zkgroth16::{Bn254Driver, Circuit, Error, MpcParameters, PowersOfTau};
+use zkstd::{
+    circuit::prelude::{FieldAssignment, R1cs},
+    common::OsRng,
+};
+
+// Phase 2 ceremony of the circuit x^3 + x + 5 = o, one step per invocation
+//
+//   ceremony new powersOfTau28_hez_final_08.ptau round0.params
+//   ceremony contribute round0.params round1.params
+//   ceremony contribute round1.params round2.params
+//   ceremony verify round0.params round2.params
+#[derive(Debug, Default)]
+pub struct DummyCircuit {
+    x: BnScalar,
+    o: BnScalar,
+}
+
+impl Circuit for DummyCircuit {
+    type Driver = Bn254Driver;
+
+    fn synthesize(&self, composer: &mut R1cs<Self::Driver>) -> Result<(), Error> {
+        let x = FieldAssignment::instance(composer, self.x);
+        let o = FieldAssignment::instance(composer, self.o);
+        let c = FieldAssignment::constant(&BnScalar::from(5));
+
+        let sym1 = FieldAssignment::mul(composer, &x, &x);
+        let y = FieldAssignment::mul(composer, &sym1, &x);
+        let sym2 = FieldAssignment::add(composer, &y, &x);
+
+        FieldAssignment::enforce_eq(composer, &(&sym2 + &c), &o);
+
+        Ok(())
+    }
+}
+
+fn read(path: &str) -> MpcParameters {
+    let bytes = fs::read(path).expect("Failed to read the parameters");
+    MpcParameters::from_bytes(&bytes).expect("Failed to decode the parameters")
+}
+
+fn write(path: &str, params: &MpcParameters) {
+    fs::write(path, params.to_bytes()).expect("Failed to write the parameters")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    match args[..] {
+        ["new", ptau, output] => {
+            let bytes = fs::read(ptau).expect("Failed to read the powers of tau");
+            let powers = PowersOfTau::from_ptau(&bytes, &mut OsRng)
+                .expect("Failed to decode the powers of tau");
+            let params = MpcParameters::from_powers_of_tau::<DummyCircuit>(&powers)
+                .expect("Failed to compile circuit");
+            write(output, &params);
+        }
+        ["contribute", input, output] => {
+            let mut params = read(input);
+            let hash = params.contribute(&mut OsRng);
+            write(output, &params);
+            println!("contribution {}", hex(&hash));
+        }
+        ["verify", initial, last] => {
+            let hashes = read(last)
+                .verify(&read(initial), &mut OsRng)
+                .expect("Failed to verify the contributions");
+            for (index, hash) in hashes.iter().enumerate() {
+                println!("contribution {} {}", index, hex(hash));
+            }
+        }
+        _ => {
+            eprintln!("usage: ceremony new <ptau> <output>");
+            eprintln!("       ceremony contribute <input> <output>");
+            eprintln!("       ceremony verify <initial> <last>");
+            process::exit(1);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use bn_254::Fr as BnScalar;
+use bn_254::{Bn254, Fr as BnScalar};
 use zkgroth16::{Bn254Driver, Circuit, Error, ZkSnark};
 use zkstd::{
     circuit::prelude::{FieldAssignment, R1cs},
@@ -29,7 +29,9 @@ impl Default for DummyCircuit {
 }
 
 impl Circuit for DummyCircuit {
-    fn synthesize(&self, composer: &mut R1cs<Bn254Driver>) -> Result<(), Error> {
+    type Driver = Bn254Driver;
+
+    fn synthesize(&self, composer: &mut R1cs<Self::Driver>) -> Result<(), Error> {
         // Declare public input
         let x = FieldAssignment::instance(composer, self.x);
         // Declare public output
@@ -61,7 +63,7 @@ fn main() {
 
     // Setup prover and verifier
     let (mut prover, verifier) =
-        ZkSnark::setup::<DummyCircuit>(OsRng).expect("Failed to compile circuit");
+        ZkSnark::<Bn254>::setup::<DummyCircuit>(OsRng).expect("Failed to compile circuit");
 
     // Generate proof
     let proof = prover
@@ -0,0 +1,810 @@
+//! Aggregation of Groth16 proofs over the same verifying key, following SnarkPack.
+//!
+//! The aggregator commits to the vectors of `A`, `B` and `C` with pairing-based commitments in
+//! `Gt`, then combines the verification equations with the powers of a random `r`:
+//!
+//! sum r^i e(A_i, B_i) = (sum r^i) e(alpha, beta) + e(sum r^i ic_i, gamma) + e(sum r^i C_i, delta)
+//!
+//! An inner pairing product argument (TIPP) shows that the left side is the pairing product
+//! of the committed `A` and `B * r^i`, and a multiexponentiation argument (MIPP) that
+//! `sum r^i C_i` is computed from the committed `C`. Both halve the vectors in every round,
+//! so that the proof and the verifier work are logarithmic in the number of proofs, apart
+//! from combining the public inputs. The commitment keys of the last round are checked with
+//! KZG openings against the powers of two trapdoors `a` and `b`.
+
+use crate::error::Error;
+use crate::msm::msm_curve_addition;
+use crate::proof::Proof;
+use crate::ptau::PowersOfTau;
+use crate::verifier::Verifier;
+
+use blake2b_simd::State;
+use bn_254::{AteParing, Bn254, Fr, G1Affine, G1Projective, G2Affine, G2PairingAffine, Gt};
+#[cfg(test)]
+use zkstd::common::RngCore;
+use zkstd::common::{vec, BNAffine, FftField, Group, PrimeField, SigUtils, Vec};
+
+/// Powers of the trapdoors `a` and `b` to aggregate up to a number of proofs
+#[derive(Clone, Debug)]
+pub struct AggregationKey {
+    /// `g * a^i` and `g * b^i` for i below twice the number of proofs
+    pub(crate) g_a: Vec<G1Affine>,
+    pub(crate) g_b: Vec<G1Affine>,
+    /// `h * a^i` and `h * b^i` for i below the number of proofs
+    pub(crate) h_a: Vec<G2Affine>,
+    pub(crate) h_b: Vec<G2Affine>,
+}
+
+/// Part of the aggregation key needed to verify aggregate proofs, of constant size
+#[derive(Clone, Debug)]
+pub struct AggregationVerifyingKey {
+    pub(crate) g_a: G1Affine,
+    pub(crate) g_b: G1Affine,
+    pub(crate) h_a: G2Affine,
+    pub(crate) h_b: G2Affine,
+}
+
+/// Proof that all the aggregated proofs are valid for their public inputs
+#[derive(Clone, Debug)]
+pub struct AggregateProof {
+    /// commitments to `A` and `B`, and to `C`
+    pub(crate) com_ab: [Gt; 2],
+    pub(crate) com_c: [Gt; 2],
+    /// `sum e(A_i, B_i * r^i)` and `sum C_i * r^i`
+    pub(crate) ip_ab: Gt,
+    pub(crate) agg_c: G1Affine,
+    pub(crate) rounds: Vec<Round>,
+    /// vectors and commitment keys of length one after the last round
+    pub(crate) final_a: G1Affine,
+    pub(crate) final_b: G2Affine,
+    pub(crate) final_c: G1Affine,
+    pub(crate) final_v: [G2Affine; 2],
+    pub(crate) final_w: [G1Affine; 2],
+    /// KZG openings of the commitment keys
+    pub(crate) v_opening: [G2Affine; 2],
+    pub(crate) w_opening: [G1Affine; 2],
+}
+
+/// Cross terms of the halves in a round of the arguments
+#[derive(Clone, Debug)]
+pub(crate) struct Round {
+    pub(crate) com_ab: [[Gt; 2]; 2],
+    pub(crate) com_c: [[Gt; 2]; 2],
+    pub(crate) z_ab: [Gt; 2],
+    pub(crate) z_c: [G1Affine; 2],
+}
+
+/// Commitment key of G1 vectors in G2, or of G2 vectors in G1, under both trapdoors
+#[derive(Clone)]
+struct Key<A> {
+    a: Vec<A>,
+    b: Vec<A>,
+}
+
+impl<A: BNAffine<Scalar = Fr>> Key<A> {
+    fn split(&self) -> (Self, Self) {
+        let (a_l, a_r) = self.a.split_at(self.a.len() / 2);
+        let (b_l, b_r) = self.b.split_at(self.b.len() / 2);
+        (
+            Self {
+                a: a_l.to_vec(),
+                b: b_l.to_vec(),
+            },
+            Self {
+                a: a_r.to_vec(),
+                b: b_r.to_vec(),
+            },
+        )
+    }
+
+    fn fold(left: &Self, right: &Self, x: Fr) -> Self {
+        Self {
+            a: fold(&left.a, &right.a, x),
+            b: fold(&left.b, &right.b, x),
+        }
+    }
+}
+
+impl AggregationKey {
+    /// Key to aggregate up to `size` proofs with trapdoors sampled locally,
+    /// only meant for tests
+    #[cfg(test)]
+    pub(crate) fn setup<R: RngCore>(size: usize, rng: &mut R) -> Self {
+        let a = Fr::random(&mut *rng);
+        let b = Fr::random(&mut *rng);
+        let g1 = |x: Fr, len: usize| {
+            powers(x, len)
+                .into_iter()
+                .map(|x| G1Affine::from(G1Affine::ADDITIVE_GENERATOR * x))
+                .collect()
+        };
+        let g2 = |x: Fr, len: usize| {
+            powers(x, len)
+                .into_iter()
+                .map(|x| G2Affine::from(G2Affine::ADDITIVE_GENERATOR * x))
+                .collect()
+        };
+
+        Self {
+            g_a: g1(a, 2 * size),
+            g_b: g1(b, 2 * size),
+            h_a: g2(a, size),
+            h_b: g2(b, size),
+        }
+    }
+
+    /// Key to aggregate up to `size` proofs from the powers of tau of two independent phase 1
+    /// ceremonies, whose trapdoors are unknown as long as one participant of each ceremony
+    /// discarded their secret. Both ceremonies must provide `2 * size` powers in G1
+    /// and `size` powers in G2.
+    pub fn from_powers_of_tau(
+        a: &PowersOfTau,
+        b: &PowersOfTau,
+        size: usize,
+    ) -> Result<Self, Error> {
+        for powers in [a, b] {
+            if powers.tau_g1.len() < 2 * size {
+                return Err(Error::InsufficientPowersOfTau {
+                    required: 2 * size,
+                    provided: powers.tau_g1.len(),
+                });
+            }
+            if powers.tau_g2.len() < size {
+                return Err(Error::InsufficientPowersOfTau {
+                    required: size,
+                    provided: powers.tau_g2.len(),
+                });
+            }
+        }
+        // the commitments are only binding under two distinct trapdoors
+        if a.tau_g1[1] == b.tau_g1[1] {
+            return Err(Error::InvalidPowersOfTau);
+        }
+
+        Ok(Self {
+            g_a: a.tau_g1[..2 * size].to_vec(),
+            g_b: b.tau_g1[..2 * size].to_vec(),
+            h_a: a.tau_g2[..size].to_vec(),
+            h_b: b.tau_g2[..size].to_vec(),
+        })
+    }
+
+    pub fn verifying_key(&self) -> AggregationVerifyingKey {
+        AggregationVerifyingKey {
+            g_a: self.g_a[1],
+            g_b: self.g_b[1],
+            h_a: self.h_a[1],
+            h_b: self.h_b[1],
+        }
+    }
+
+    /// Aggregate proofs of the same circuit with their public inputs. The number of proofs
+    /// must be a power of two, so a proof can be repeated to pad them.
+    pub fn aggregate(&self, proofs: &[(Proof<Bn254>, Vec<Fr>)]) -> Result<AggregateProof, Error> {
+        let n = proofs.len();
+        if n < 2 || !n.is_power_of_two() || n > self.h_a.len() {
+            return Err(Error::UnsupportedAggregationSize { size: n });
+        }
+
+        let mut a = proofs.iter().map(|(proof, _)| proof.a).collect::<Vec<_>>();
+        let mut b = proofs.iter().map(|(proof, _)| proof.b).collect::<Vec<_>>();
+        let mut c = proofs.iter().map(|(proof, _)| proof.c).collect::<Vec<_>>();
+        let mut v = Key {
+            a: self.h_a[..n].to_vec(),
+            b: self.h_b[..n].to_vec(),
+        };
+        let mut w = Key {
+            a: self.g_a[n..2 * n].to_vec(),
+            b: self.g_b[n..2 * n].to_vec(),
+        };
+
+        let com_ab = add(commit_g1(&v, &a), commit_g2(&w, &b));
+        let com_c = commit_g1(&v, &c);
+        let mut transcript = Transcript::new(proofs.iter().map(|(_, inputs)| inputs), n);
+        transcript.gt(&com_ab).gt(&com_c);
+        let r = transcript.challenge();
+
+        // B * r^i under the key w * r^-i has the same commitment
+        let mut rs = powers(r, n);
+        let r_inverse = r.invert().expect("challenge is not zero");
+        let r_inverses = powers(r_inverse, n);
+        b = b
+            .iter()
+            .zip(rs.iter())
+            .map(|(b, r)| G2Affine::from(*b * *r))
+            .collect();
+        w.a = scale(&w.a, &r_inverses);
+        w.b = scale(&w.b, &r_inverses);
+
+        let ip_ab = pairing_product(&a, &b);
+        let agg_c = G1Affine::from(msm_curve_addition(&c, &rs));
+
+        let mut rounds = Vec::new();
+        let mut challenges = Vec::new();
+        while a.len() > 1 {
+            let half = a.len() / 2;
+            let (a_l, a_r) = a.split_at(half);
+            let (b_l, b_r) = b.split_at(half);
+            let (c_l, c_r) = c.split_at(half);
+            let (r_l, r_r) = rs.split_at(half);
+            let (v_l, v_r) = v.split();
+            let (w_l, w_r) = w.split();
+
+            let round = Round {
+                com_ab: [
+                    add(commit_g1(&v_l, a_r), commit_g2(&w_r, b_l)),
+                    add(commit_g1(&v_r, a_l), commit_g2(&w_l, b_r)),
+                ],
+                com_c: [commit_g1(&v_l, c_r), commit_g1(&v_r, c_l)],
+                z_ab: [pairing_product(a_r, b_l), pairing_product(a_l, b_r)],
+                z_c: [
+                    msm_curve_addition(c_r, r_l).into(),
+                    msm_curve_addition(c_l, r_r).into(),
+                ],
+            };
+            let x = transcript.round(&round).challenge();
+            let x_inverse = x.invert().expect("challenge is not zero");
+
+            a = fold(a_l, a_r, x);
+            b = fold(b_l, b_r, x_inverse);
+            c = fold(c_l, c_r, x);
+            rs = r_l
+                .iter()
+                .zip(r_r.iter())
+                .map(|(l, r)| *l + *r * x_inverse)
+                .collect();
+            v = Key::fold(&v_l, &v_r, x_inverse);
+            w = Key::fold(&w_l, &w_r, x);
+
+            rounds.push(round);
+            challenges.push(x);
+        }
+
+        let final_v = [v.a[0], v.b[0]];
+        let final_w = [w.a[0], w.b[0]];
+        let z = transcript
+            .g1(&[a[0], c[0], final_w[0], final_w[1]])
+            .g2(&[b[0], final_v[0], final_v[1]])
+            .challenge();
+
+        // v = h * f_v(a) and w = g * f_w(a), and the same for b
+        let (f_v, f_w) = key_polynomials(&challenges, r_inverse, n);
+        let q_v = quotient(&f_v, z);
+        let q_w = quotient(&f_w, z);
+
+        Ok(AggregateProof {
+            com_ab,
+            com_c,
+            ip_ab,
+            agg_c,
+            rounds,
+            final_a: a[0],
+            final_b: b[0],
+            final_c: c[0],
+            final_v,
+            final_w,
+            v_opening: [
+                msm_curve_addition(&self.h_a[..q_v.len()], &q_v).into(),
+                msm_curve_addition(&self.h_b[..q_v.len()], &q_v).into(),
+            ],
+            w_opening: [
+                msm_curve_addition(&self.g_a[..q_w.len()], &q_w).into(),
+                msm_curve_addition(&self.g_b[..q_w.len()], &q_w).into(),
+            ],
+        })
+    }
+}
+
+impl Verifier<Bn254> {
+    /// Verify an aggregate of proofs of the circuit with their public inputs in order
+    pub fn verify_aggregate(
+        &self,
+        key: &AggregationVerifyingKey,
+        proof: &AggregateProof,
+        public_inputs: &[Vec<Fr>],
+    ) -> Result<(), Error> {
+        match self.aggregate_holds(key, proof, public_inputs)? {
+            true => Ok(()),
+            false => Err(Error::AggregateVerificationError),
+        }
+    }
+
+    fn aggregate_holds(
+        &self,
+        key: &AggregationVerifyingKey,
+        proof: &AggregateProof,
+        public_inputs: &[Vec<Fr>],
+    ) -> Result<bool, Error> {
+        let n = public_inputs.len();
+        if n < 2 || !n.is_power_of_two() || proof.rounds.len() != n.trailing_zeros() as usize {
+            return Err(Error::UnsupportedAggregationSize { size: n });
+        }
+        let vk = &self.vk;
+        for inputs in public_inputs {
+            if inputs.len() + 1 != vk.ic.len() {
+                return Err(Error::InconsistentPublicInputsLen {
+                    expected: vk.ic.len() - 1,
+                    provided: inputs.len(),
+                });
+            }
+        }
+
+        let mut transcript = Transcript::new(public_inputs.iter(), n);
+        transcript.gt(&proof.com_ab).gt(&proof.com_c);
+        let r = transcript.challenge();
+        let r_inverse = r.invert().expect("challenge is not zero");
+
+        // sum r^i e(A_i, B_i) = (sum r^i) e(alpha, beta) + e(sum r^i ic_i, gamma) + e(agg_c, delta)
+        let rs = powers(r, n);
+        let mut scalars = vec![Fr::zero(); vk.ic.len()];
+        for (r, inputs) in rs.iter().zip(public_inputs) {
+            scalars[0] += r;
+            for (scalar, input) in scalars[1..].iter_mut().zip(inputs) {
+                *scalar += *r * input;
+            }
+        }
+        let ic = msm_curve_addition(&vk.ic, &scalars);
+        let groth16 = AteParing::multi_miller_loop(&[
+            (ic.into(), vk.neg_gamma_g2.clone()),
+            (proof.agg_c, vk.neg_delta_g2.clone()),
+        ])
+        .final_exp();
+        if proof.ip_ab + groth16 != vk.alpha_g1_beta_g2 * scalars[0] {
+            return Ok(false);
+        }
+
+        // replay the rounds on the commitments and the inner products
+        let mut com_ab = proof.com_ab;
+        let mut com_c = proof.com_c;
+        let mut z_ab = proof.ip_ab;
+        let mut z_c = G1Projective::from(proof.agg_c);
+        let mut challenges = Vec::with_capacity(proof.rounds.len());
+        for round in proof.rounds.iter() {
+            let x = transcript.round(round).challenge();
+            let x_inverse = x.invert().expect("challenge is not zero");
+            let [l, r] = round.com_ab;
+            com_ab = add(com_ab, add(scale_gt(l, x), scale_gt(r, x_inverse)));
+            let [l, r] = round.com_c;
+            com_c = add(com_c, add(scale_gt(l, x), scale_gt(r, x_inverse)));
+            z_ab = z_ab + round.z_ab[0] * x + round.z_ab[1] * x_inverse;
+            z_c += round.z_c[0] * x + round.z_c[1] * x_inverse;
+            challenges.push(x);
+        }
+
+        let AggregateProof {
+            final_a,
+            final_b,
+            final_c,
+            final_v,
+            final_w,
+            ..
+        } = *proof;
+        let z = transcript
+            .g1(&[final_a, final_c, final_w[0], final_w[1]])
+            .g2(&[final_b, final_v[0], final_v[1]])
+            .challenge();
+
+        // the arguments hold for the vectors of length one
+        let inverses = challenges
+            .iter()
+            .map(|x| x.invert().expect("challenge is not zero"))
+            .collect::<Vec<_>>();
+        let r_final = evaluate(&inverses, r, n);
+        for k in 0..2 {
+            if com_ab[k] != pairing_product(&[final_a, final_w[k]], &[final_v[k], final_b])
+                || com_c[k] != pairing_product(&[final_c], &[final_v[k]])
+            {
+                return Ok(false);
+            }
+        }
+        if z_ab != pairing_product(&[final_a], &[final_b])
+            || G1Affine::from(z_c) != G1Affine::from(final_c * r_final)
+        {
+            return Ok(false);
+        }
+
+        // the commitment keys are derived from the challenges
+        let f_v = evaluate(&inverses, z, n);
+        let shifted = challenges
+            .iter()
+            .zip(round_powers(r_inverse, n))
+            .map(|(x, r)| *x * r)
+            .collect::<Vec<_>>();
+        let f_w = z.pow(n as u64) * evaluate(&shifted, z, n);
+        let g = G1Affine::ADDITIVE_GENERATOR;
+        let h = G2Affine::ADDITIVE_GENERATOR;
+        let holds = [(key.g_a, key.h_a), (key.g_b, key.h_b)]
+            .iter()
+            .enumerate()
+            .all(|(k, (g_k, h_k))| {
+                let v = G2Affine::from(final_v[k] - h * f_v);
+                let w = G1Affine::from(final_w[k] - g * f_w);
+                pairing_check(&[(G1Affine::from(*g_k - g * z), proof.v_opening[k]), (-g, v)])
+                    && pairing_check(&[(proof.w_opening[k], G2Affine::from(*h_k - h * z)), (-w, h)])
+            });
+
+        Ok(holds)
+    }
+}
+
+/// Fiat-Shamir transcript of the aggregation
+struct Transcript(State);
+
+impl Transcript {
+    fn new<'a>(public_inputs: impl Iterator<Item = &'a Vec<Fr>>, n: usize) -> Self {
+        let mut state = State::new();
+        state.update(b"zkgroth16 snarkpack");
+        state.update(&(n as u64).to_be_bytes());
+        for inputs in public_inputs {
+            state.update(&(inputs.len() as u64).to_be_bytes());
+            for input in inputs {
+                state.update(&input.to_bytes());
+            }
+        }
+        Self(state)
+    }
+
+    fn gt(&mut self, elements: &[Gt]) -> &mut Self {
+        for element in elements {
+            self.0.update(&element.to_bytes());
+        }
+        self
+    }
+
+    fn g1(&mut self, points: &[G1Affine]) -> &mut Self {
+        for point in points {
+            self.0.update(&point.to_uncompressed());
+        }
+        self
+    }
+
+    fn g2(&mut self, points: &[G2Affine]) -> &mut Self {
+        for point in points {
+            self.0.update(&point.to_uncompressed());
+        }
+        self
+    }
+
+    fn round(&mut self, round: &Round) -> &mut Self {
+        for com in round.com_ab.iter().chain(round.com_c.iter()) {
+            self.gt(com);
+        }
+        self.gt(&round.z_ab).g1(&round.z_c)
+    }
+
+    /// Nonzero challenge, which is absorbed for the next one
+    fn challenge(&mut self) -> Fr {
+        loop {
+            let mut digest = [0; 64];
+            digest.copy_from_slice(self.0.finalize().as_bytes());
+            self.0.update(&digest);
+            let challenge = Fr::from_hash(&digest);
+            if challenge != Fr::zero() {
+                return challenge;
+            }
+        }
+    }
+}
+
+fn powers(x: Fr, len: usize) -> Vec<Fr> {
+    let mut acc = Fr::one();
+    (0..len)
+        .map(|_| {
+            let power = acc;
+            acc *= x;
+            power
+        })
+        .collect()
+}
+
+/// `x^(n / 2^(j + 1))` for the rounds j
+fn round_powers(x: Fr, n: usize) -> Vec<Fr> {
+    let mut powers = Vec::new();
+    let mut power = x;
+    let mut exponent = 1;
+    while exponent < n {
+        powers.push(power);
+        power = power.square();
+        exponent *= 2;
+    }
+    powers.reverse();
+    powers
+}
+
+/// `left + right * x` element-wise
+fn fold<A: BNAffine<Scalar = Fr>>(left: &[A], right: &[A], x: Fr) -> Vec<A> {
+    left.iter()
+        .zip(right.iter())
+        .map(|(l, r)| (*l + *r * x).into())
+        .collect()
+}
+
+fn scale<A: BNAffine<Scalar = Fr>>(points: &[A], scalars: &[Fr]) -> Vec<A> {
+    points
+        .iter()
+        .zip(scalars.iter())
+        .map(|(point, scalar)| (*point * *scalar).into())
+        .collect()
+}
+
+fn add(left: [Gt; 2], right: [Gt; 2]) -> [Gt; 2] {
+    [left[0] + right[0], left[1] + right[1]]
+}
+
+fn scale_gt(elements: [Gt; 2], x: Fr) -> [Gt; 2] {
+    [elements[0] * x, elements[1] * x]
+}
+
+fn pairing_product(g1: &[G1Affine], g2: &[G2Affine]) -> Gt {
+    let pairs = g1
+        .iter()
+        .zip(g2.iter())
+        .map(|(p, q)| (*p, G2PairingAffine::from(*q)))
+        .collect::<Vec<_>>();
+    AteParing::multi_miller_loop(&pairs).final_exp()
+}
+
+fn pairing_check(pairs: &[(G1Affine, G2Affine)]) -> bool {
+    let (g1, g2): (Vec<_>, Vec<_>) = pairs.iter().cloned().unzip();
+    pairing_product(&g1, &g2) == Gt::ADDITIVE_IDENTITY
+}
+
+/// Commitment to G1 points with a key in G2
+fn commit_g1(key: &Key<G2Affine>, points: &[G1Affine]) -> [Gt; 2] {
+    [
+        pairing_product(points, &key.a),
+        pairing_product(points, &key.b),
+    ]
+}
+
+/// Commitment to G2 points with a key in G1
+fn commit_g2(key: &Key<G1Affine>, points: &[G2Affine]) -> [Gt; 2] {
+    [
+        pairing_product(&key.a, points),
+        pairing_product(&key.b, points),
+    ]
+}
+
+/// Coefficients of `prod_j (1 + c_j X^(n / 2^(j + 1)))` for the challenges `c_j` of the rounds
+fn polynomial(challenges: &[Fr]) -> Vec<Fr> {
+    let mut coeffs = vec![Fr::one()];
+    for c in challenges.iter().rev() {
+        let high = coeffs.iter().map(|coeff| *coeff * c).collect::<Vec<_>>();
+        coeffs.extend(high);
+    }
+    coeffs
+}
+
+/// Evaluation of `polynomial(challenges)` at `z` in logarithmic time
+fn evaluate(challenges: &[Fr], z: Fr, n: usize) -> Fr {
+    challenges
+        .iter()
+        .zip(round_powers(z, n))
+        .fold(Fr::one(), |acc, (c, power)| acc * (Fr::one() + *c * power))
+}
+
+/// Polynomials of the last commitment keys in the trapdoor: `v` folds `h * a^i` with the
+/// inverses of the challenges, and `w` folds `g * a^(n + i) * r^-i` with the challenges
+fn key_polynomials(challenges: &[Fr], r_inverse: Fr, n: usize) -> (Vec<Fr>, Vec<Fr>) {
+    let inverses = challenges
+        .iter()
+        .map(|x| x.invert().expect("challenge is not zero"))
+        .collect::<Vec<_>>();
+    let shifted = challenges
+        .iter()
+        .zip(round_powers(r_inverse, n))
+        .map(|(x, r)| *x * r)
+        .collect::<Vec<_>>();
+
+    let mut f_w = vec![Fr::zero(); n];
+    f_w.extend(polynomial(&shifted));
+    (polynomial(&inverses), f_w)
+}
+
+/// Quotient of `f(X) - f(z)` by `X - z`
+fn quotient(coeffs: &[Fr], z: Fr) -> Vec<Fr> {
+    let mut quotient = vec![Fr::zero(); coeffs.len() - 1];
+    let mut acc = Fr::zero();
+    for i in (1..coeffs.len()).rev() {
+        acc = acc * z + coeffs[i];
+        quotient[i - 1] = acc;
+    }
+    quotient
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::ptau::tests::from_trapdoors;
+    use crate::tests::DummyCircuit;
+    use crate::zksnark::ZkSnark;
+
+    use zkstd::common::OsRng;
+
+    #[test]
+    fn aggregation_test() {
+        let (mut prover, verifier) =
+            ZkSnark::<Bn254>::setup::<DummyCircuit>(OsRng).expect("Failed to compile circuit");
+        let proofs = (1..9)
+            .map(|x| {
+                let x = Fr::from(x);
+                let o = x * x * x + x + Fr::from(5);
+                let proof = prover
+                    .create_proof(&mut OsRng, DummyCircuit::new(x, o))
+                    .expect("Failed to prove");
+                (proof, vec![x, o])
+            })
+            .collect::<Vec<_>>();
+        let inputs = proofs
+            .iter()
+            .map(|(_, inputs)| inputs.clone())
+            .collect::<Vec<_>>();
+
+        let key = AggregationKey::setup(8, &mut OsRng);
+        let vk = key.verifying_key();
+        for n in [2, 8] {
+            let aggregate = key.aggregate(&proofs[..n]).expect("Failed to aggregate");
+            assert_eq!(aggregate.rounds.len(), n.trailing_zeros() as usize);
+            verifier
+                .verify_aggregate(&vk, &aggregate, &inputs[..n])
+                .expect("Failed to verify the aggregate");
+        }
+        let aggregate = key.aggregate(&proofs[..4]).expect("Failed to aggregate");
+
+        // the aggregate and the keys are sent to the verifier
+        let decoded = AggregateProof::from_bytes(&aggregate.to_bytes()).unwrap();
+        assert_eq!(decoded.to_bytes(), aggregate.to_bytes());
+        let vk = AggregationVerifyingKey::from_uncompressed_bytes(&vk.to_uncompressed_bytes());
+        let vk = vk.unwrap();
+        let key = AggregationKey::from_bytes(&key.to_bytes()).unwrap();
+        verifier
+            .verify_aggregate(&vk, &decoded, &inputs[..4])
+            .expect("Failed to verify the aggregate");
+        let mut malformed = aggregate.to_bytes();
+        malformed[100] ^= 1;
+        assert!(AggregateProof::from_bytes(&malformed).is_err());
+
+        // public inputs of another proof, in another order or of another length
+        let mut wrong = inputs[..4].to_vec();
+        wrong[3] = inputs[4].clone();
+        assert!(matches!(
+            verifier.verify_aggregate(&vk, &aggregate, &wrong),
+            Err(Error::AggregateVerificationError)
+        ));
+        wrong = inputs[..4].to_vec();
+        wrong.swap(0, 1);
+        assert!(verifier.verify_aggregate(&vk, &aggregate, &wrong).is_err());
+        assert!(matches!(
+            verifier.verify_aggregate(&vk, &aggregate, &inputs[..2]),
+            Err(Error::UnsupportedAggregationSize { size: 2 })
+        ));
+        wrong = inputs[..4].to_vec();
+        wrong[2].pop();
+        assert!(matches!(
+            verifier.verify_aggregate(&vk, &aggregate, &wrong),
+            Err(Error::InconsistentPublicInputsLen {
+                expected: 2,
+                provided: 1
+            })
+        ));
+
+        // an invalid proof among valid ones
+        let mut invalid = proofs[..4].to_vec();
+        invalid[1].0.c = proofs[2].0.c;
+        let aggregate = key.aggregate(&invalid).expect("Failed to aggregate");
+        assert!(verifier
+            .verify_aggregate(&vk, &aggregate, &inputs[..4])
+            .is_err());
+
+        // tampered rounds and openings
+        let aggregate = key.aggregate(&proofs[..4]).expect("Failed to aggregate");
+        let mut tampered = aggregate.clone();
+        tampered.rounds[0].z_c.swap(0, 1);
+        assert!(verifier
+            .verify_aggregate(&vk, &tampered, &inputs[..4])
+            .is_err());
+        let mut tampered = aggregate.clone();
+        tampered.v_opening.swap(0, 1);
+        assert!(verifier
+            .verify_aggregate(&vk, &tampered, &inputs[..4])
+            .is_err());
+
+        // a key of another setup
+        let other = AggregationKey::setup(4, &mut OsRng).verifying_key();
+        assert!(verifier
+            .verify_aggregate(&other, &aggregate, &inputs[..4])
+            .is_err());
+
+        for n in [0, 1, 3, 16] {
+            let proofs = (0..n).map(|i| proofs[i % 8].clone()).collect::<Vec<_>>();
+            assert!(matches!(
+                key.aggregate(&proofs),
+                Err(Error::UnsupportedAggregationSize { size }) if size == n
+            ));
+        }
+    }
+
+    #[test]
+    fn powers_of_tau_key_test() {
+        let (mut prover, verifier) =
+            ZkSnark::<Bn254>::setup::<DummyCircuit>(OsRng).expect("Failed to compile circuit");
+        let proofs = (1..5)
+            .map(|x| {
+                let x = Fr::from(x);
+                let o = x * x * x + x + Fr::from(5);
+                let proof = prover
+                    .create_proof(&mut OsRng, DummyCircuit::new(x, o))
+                    .expect("Failed to prove");
+                (proof, vec![x, o])
+            })
+            .collect::<Vec<_>>();
+        let inputs = proofs
+            .iter()
+            .map(|(_, inputs)| inputs.clone())
+            .collect::<Vec<_>>();
+
+        // transcripts of two ceremonies with 2^3 powers in G2 and 2^4 - 1 in G1
+        let trapdoors = || from_trapdoors(3, Fr::random(&mut OsRng), Fr::one(), Fr::one());
+        let (a, b) = (trapdoors(), trapdoors());
+        let key = AggregationKey::from_powers_of_tau(&a, &b, 4).unwrap();
+        let aggregate = key.aggregate(&proofs).expect("Failed to aggregate");
+        verifier
+            .verify_aggregate(&key.verifying_key(), &aggregate, &inputs)
+            .expect("Failed to verify the aggregate");
+
+        // too few powers in G1 for 8 proofs, and the same ceremony twice
+        assert!(matches!(
+            AggregationKey::from_powers_of_tau(&a, &b, 8),
+            Err(Error::InsufficientPowersOfTau {
+                required: 16,
+                provided: 15
+            })
+        ));
+        assert!(matches!(
+            AggregationKey::from_powers_of_tau(&a, &a, 4),
+            Err(Error::InvalidPowersOfTau)
+        ));
+    }
+
+    #[test]
+    fn key_polynomials_test() {
+        let challenges = (0..3).map(|_| Fr::random(&mut OsRng)).collect::<Vec<_>>();
+        let r_inverse = Fr::random(&mut OsRng);
+        let z = Fr::random(&mut OsRng);
+        let horner = |coeffs: &[Fr], z: Fr| {
+            coeffs
+                .iter()
+                .rev()
+                .fold(Fr::zero(), |acc, coeff| acc * z + coeff)
+        };
+
+        // folding the powers of z matches the polynomials
+        let (f_v, f_w) = key_polynomials(&challenges, r_inverse, 8);
+        let mut v = powers(z, 8);
+        let mut w = powers(z, 16).split_off(8);
+        w = w
+            .iter()
+            .zip(powers(r_inverse, 8))
+            .map(|(w, r)| *w * r)
+            .collect();
+        for x in challenges.iter() {
+            let x_inverse = x.invert().unwrap();
+            let (v_l, v_r) = v.split_at(v.len() / 2);
+            v = v_l
+                .iter()
+                .zip(v_r)
+                .map(|(l, r)| *l + *r * x_inverse)
+                .collect();
+            let (w_l, w_r) = w.split_at(w.len() / 2);
+            w = w_l.iter().zip(w_r).map(|(l, r)| *l + *r * x).collect();
+        }
+        assert_eq!(horner(&f_v, z), v[0]);
+        assert_eq!(horner(&f_w, z), w[0]);
+
+        // the quotient opens the polynomial at another point
+        let y = Fr::random(&mut OsRng);
+        let q = quotient(&f_w, y);
+        assert_eq!(horner(&q, z) * (z - y), horner(&f_w, z) - horner(&f_w, y));
+    }
+}
@@ -8,7 +8,10 @@ use zkstd::common::{Debug, Decode, Encode};
 
 /// circuit trait
 pub trait Circuit: Default + Debug {
-    fn synthesize(&self, constraint_system: &mut R1cs<Bn254Driver>) -> Result<(), Error>;
+    /// driver over the scalar field of the pairing engine
+    type Driver: CircuitDriver;
+
+    fn synthesize(&self, constraint_system: &mut R1cs<Self::Driver>) -> Result<(), Error>;
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Decode, Encode)]
@@ -5,4 +5,17 @@ pub enum Error {
     UnsupportedWNAF2k,
     ProofVerificationError,
     InconsistentPublicInputsLen { expected: usize, provided: usize },
+    InconsistentWitnessLen { expected: usize, provided: usize },
+    InconsistentParametersLen { expected: usize, provided: usize },
+    UnsatisfiedConstraint { index: usize },
+    InvalidEncoding,
+    BatchVerificationError { index: usize },
+    InconsistentBatchVerification,
+    InconsistentParameters,
+    InvalidContribution { index: usize },
+    NoContributions,
+    InvalidPowersOfTau,
+    InsufficientPowersOfTau { required: usize, provided: usize },
+    UnsupportedAggregationSize { size: usize },
+    AggregateVerificationError,
 }
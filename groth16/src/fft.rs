@@ -1,7 +1,7 @@
 use crate::poly::{Coefficients, PointsValue};
 #[cfg(feature = "std")]
 use rayon::join;
-use zkstd::common::{FftField, Vec};
+use zkstd::common::{AddAssign, FftField, MulAssign, SubAssign, Vec};
 
 /// fft construction using n th root of unity supports polynomial operation less than n degree
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -105,6 +105,20 @@ impl<F: FftField> Fft<F> {
         Coefficients::new(coeffs.clone())
     }
 
+    /// perform inverse discrete fourier transform on the discrete logarithms of curve points
+    pub(crate) fn idft_points<G>(&self, mut points: Vec<G>) -> Vec<G>
+    where
+        G: Copy + Send + AddAssign + SubAssign + MulAssign<F>,
+    {
+        assert_eq!(points.len(), self.n);
+        self.bit_reverse
+            .iter()
+            .for_each(|(i, ri)| points.swap(*ri, *i));
+        classic_fft_arithmetic(&mut points, self.n, 1, &self.inv_twiddle_factors);
+        points.iter_mut().for_each(|point| *point *= self.n_inv);
+        points
+    }
+
     /// perform discrete fourier transform on coset
     pub(crate) fn coset_dft(&self, mut coeffs: Coefficients<F>) -> PointsValue<F> {
         coeffs
@@ -163,12 +177,11 @@ impl<F: FftField> Fft<F> {
 }
 
 // classic fft using divide and conquer algorithm
-fn classic_fft_arithmetic<F: FftField>(
-    coeffs: &mut [F],
-    n: usize,
-    twiddle_chunk: usize,
-    twiddles: &[F],
-) {
+fn classic_fft_arithmetic<F, T>(coeffs: &mut [T], n: usize, twiddle_chunk: usize, twiddles: &[F])
+where
+    F: FftField,
+    T: Copy + Send + AddAssign + SubAssign + MulAssign<F>,
+{
     if n == 2 {
         let t = coeffs[1];
         coeffs[1] = coeffs[0];
@@ -192,12 +205,11 @@ fn classic_fft_arithmetic<F: FftField>(
 }
 
 // butterfly arithmetic polynomial evaluation
-fn butterfly_arithmetic<F: FftField>(
-    left: &mut [F],
-    right: &mut [F],
-    twiddle_chunk: usize,
-    twiddles: &[F],
-) {
+fn butterfly_arithmetic<F, T>(left: &mut [T], right: &mut [T], twiddle_chunk: usize, twiddles: &[F])
+where
+    F: FftField,
+    T: Copy + AddAssign + SubAssign + MulAssign<F>,
+{
     // case when twiddle factor is one
     let t = right[0];
     right[0] = left[0];
@@ -1,73 +1,106 @@
 #![doc = include_str!("../README.md")]
 
+mod aggregate;
 mod circuit;
 mod error;
 mod fft;
+mod mpc;
 mod msm;
 mod params;
 mod poly;
 mod proof;
 mod prover;
+mod ptau;
+mod serialize;
+mod solidity;
 mod verifier;
 mod zksnark;
 
+pub use aggregate::{AggregateProof, AggregationKey, AggregationVerifyingKey};
 pub use circuit::{Bn254Driver, Circuit};
 pub use error::Error;
+pub use mpc::{MpcParameters, PublicKey};
+pub use params::{ConstraintMatrices, Parameters};
 pub use proof::Proof;
 pub use prover::Prover;
-pub use verifier::Verifier;
+pub use ptau::PowersOfTau;
+pub use verifier::{Verifier, VerifyingKey};
 pub use zksnark::ZkSnark;
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use bn_254::Fr as BnScalar;
+    use bn_254::{Bn254, Fr as BnScalar};
+    use parity_scale_codec::{Decode, Encode};
     use zkstd::circuit::prelude::{FieldAssignment, R1cs};
     use zkstd::common::OsRng;
 
-    #[test]
-    fn arithmetic_test() {
-        #[derive(Debug)]
-        pub struct DummyCircuit {
-            x: BnScalar,
-            o: BnScalar,
+    #[derive(Debug)]
+    pub struct DummyCircuit {
+        x: BnScalar,
+        o: BnScalar,
+    }
+
+    impl DummyCircuit {
+        pub fn new(x: BnScalar, o: BnScalar) -> Self {
+            Self { x, o }
         }
+    }
 
-        impl DummyCircuit {
-            pub fn new(x: BnScalar, o: BnScalar) -> Self {
-                Self { x, o }
-            }
+    impl Default for DummyCircuit {
+        fn default() -> Self {
+            Self::new(0.into(), 0.into())
         }
+    }
+
+    impl Circuit for DummyCircuit {
+        type Driver = Bn254Driver;
 
-        impl Default for DummyCircuit {
-            fn default() -> Self {
-                Self::new(0.into(), 0.into())
-            }
+        fn synthesize(&self, composer: &mut R1cs<Self::Driver>) -> Result<(), Error> {
+            let x = FieldAssignment::instance(composer, self.x);
+            let o = FieldAssignment::instance(composer, self.o);
+            let c = FieldAssignment::constant(&BnScalar::from(5));
+
+            let sym1 = FieldAssignment::mul(composer, &x, &x);
+            let y = FieldAssignment::mul(composer, &sym1, &x);
+            let sym2 = FieldAssignment::add(composer, &y, &x);
+
+            FieldAssignment::enforce_eq(composer, &(&sym2 + &c), &o);
+
+            Ok(())
         }
+    }
+
+    /// x^32 = o by repeated squaring, the six constraints are not a power of two
+    #[derive(Debug, Default)]
+    struct SquaringCircuit {
+        x: BnScalar,
+        o: BnScalar,
+    }
 
-        impl Circuit for DummyCircuit {
-            fn synthesize(&self, composer: &mut R1cs<Bn254Driver>) -> Result<(), Error> {
-                let x = FieldAssignment::instance(composer, self.x);
-                let o = FieldAssignment::instance(composer, self.o);
-                let c = FieldAssignment::constant(&BnScalar::from(5));
+    impl Circuit for SquaringCircuit {
+        type Driver = Bn254Driver;
 
-                let sym1 = FieldAssignment::mul(composer, &x, &x);
-                let y = FieldAssignment::mul(composer, &sym1, &x);
-                let sym2 = FieldAssignment::add(composer, &y, &x);
+        fn synthesize(&self, composer: &mut R1cs<Self::Driver>) -> Result<(), Error> {
+            let x = FieldAssignment::instance(composer, self.x);
+            let o = FieldAssignment::instance(composer, self.o);
 
-                FieldAssignment::enforce_eq(composer, &(&sym2 + &c), &o);
+            let y = (0..5).fold(x, |y, _| FieldAssignment::square(composer, &y));
+            FieldAssignment::enforce_eq(composer, &y, &o);
 
-                Ok(())
-            }
+            Ok(())
         }
+    }
 
+    #[test]
+    fn arithmetic_test() {
         let x = BnScalar::from(3);
         let o = BnScalar::from(35);
         let circuit = DummyCircuit::new(x, o);
 
         let (mut prover, verifier) =
-            ZkSnark::setup::<DummyCircuit>(OsRng).expect("Failed to compile circuit");
+            ZkSnark::<Bn254>::setup::<DummyCircuit>(OsRng).expect("Failed to compile circuit");
         let proof = prover
             .create_proof(&mut OsRng, circuit)
             .expect("Failed to prove");
@@ -75,4 +108,277 @@ mod tests {
             .verify(&proof, &[x, o])
             .expect("Failed to verify the proof");
     }
+
+    #[test]
+    fn domain_padding_test() {
+        let x = BnScalar::from(3);
+        let o = (0..5).fold(x, |y, _| y * y);
+
+        let (mut prover, verifier) =
+            ZkSnark::<Bn254>::setup::<SquaringCircuit>(OsRng).expect("Failed to compile circuit");
+        // the quotient of the domain of 8 points has 7 coefficients, more than m - 1 = 5
+        assert_eq!(prover.params.h.len(), 7);
+        let proof = prover
+            .create_proof(&mut OsRng, SquaringCircuit { x, o })
+            .expect("Failed to prove");
+        verifier
+            .verify(&proof, &[x, o])
+            .expect("Failed to verify the proof");
+    }
+
+    #[test]
+    fn assignment_test() {
+        let x = BnScalar::from(3);
+        let o = BnScalar::from(35);
+
+        let (mut prover, verifier) =
+            ZkSnark::<Bn254>::setup::<DummyCircuit>(OsRng).expect("Failed to compile circuit");
+
+        // the witness is computed by another tool and exchanged in the iden3 format
+        let mut cs = R1cs::<Bn254Driver>::default();
+        DummyCircuit::new(x, o).synthesize(&mut cs).unwrap();
+        let (mut r1cs, mut wtns) = (vec![], vec![]);
+        cs.write_r1cs(&mut r1cs);
+        cs.write_wtns(&mut wtns);
+        let mut cs = R1cs::<Bn254Driver>::read_r1cs(&r1cs).unwrap();
+        cs.read_wtns(&wtns).unwrap();
+
+        let (instance, witness) = (cs.x(), cs.w());
+        let proof = prover
+            .create_proof_with_assignment(&mut OsRng, &instance[1..], &witness)
+            .expect("Failed to prove");
+        verifier
+            .verify(&proof, &[x, o])
+            .expect("Failed to verify the proof");
+
+        // an assignment of another output fails the last constraint
+        prover
+            .create_proof_with_assignment_checked(&mut OsRng, &instance[1..], &witness)
+            .expect("Failed to prove");
+        assert!(matches!(
+            prover.create_proof_with_assignment_checked(
+                &mut OsRng,
+                &[x, o + BnScalar::one()],
+                &witness
+            ),
+            Err(Error::UnsatisfiedConstraint { index: 3 })
+        ));
+
+        // the lengths must match the parameters
+        assert!(matches!(
+            prover.create_proof_with_assignment(&mut OsRng, &instance, &witness),
+            Err(Error::InconsistentPublicInputsLen {
+                expected: 2,
+                provided: 3
+            })
+        ));
+        assert!(matches!(
+            prover.create_proof_with_assignment(&mut OsRng, &instance[1..], &witness[1..]),
+            Err(Error::InconsistentWitnessLen { .. })
+        ));
+    }
+
+    #[test]
+    fn unsatisfied_test() {
+        let x = BnScalar::from(3);
+        let o = BnScalar::from(36);
+
+        let (mut prover, verifier) =
+            ZkSnark::<Bn254>::setup::<DummyCircuit>(OsRng).expect("Failed to compile circuit");
+
+        // without the check the proof only fails to verify
+        let proof = prover
+            .create_proof(&mut OsRng, DummyCircuit::new(x, o))
+            .expect("Failed to prove");
+        assert!(verifier.verify(&proof, &[x, o]).is_err());
+
+        // the last constraint x^3 + x + 5 = o fails
+        assert!(matches!(
+            prover.create_proof_checked(&mut OsRng, DummyCircuit::new(x, o)),
+            Err(Error::UnsatisfiedConstraint { index: 3 })
+        ));
+        prover
+            .create_proof_checked(&mut OsRng, DummyCircuit::new(x, BnScalar::from(35)))
+            .expect("Failed to prove");
+
+        // parameters of another circuit
+        assert!(matches!(
+            prover.create_proof(&mut OsRng, SquaringCircuit::default()),
+            Err(Error::InconsistentParametersLen {
+                expected: 6,
+                provided: 4
+            })
+        ));
+        prover.params.h.pop();
+        assert!(matches!(
+            prover.create_proof(&mut OsRng, DummyCircuit::new(x, o)),
+            Err(Error::InconsistentParametersLen {
+                expected: 3,
+                provided: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn serialization_test() {
+        let x = BnScalar::from(3);
+        let o = BnScalar::from(35);
+
+        let (mut prover, _) =
+            ZkSnark::<Bn254>::setup::<DummyCircuit>(OsRng).expect("Failed to compile circuit");
+        let proof = prover
+            .create_proof(&mut OsRng, DummyCircuit::new(x, o))
+            .expect("Failed to prove");
+
+        // the proof and the keys are sent to another process
+        let compressed = proof.to_bytes();
+        let uncompressed = proof.to_uncompressed_bytes();
+        assert_eq!(compressed.len(), 128);
+        assert_eq!(uncompressed.len(), 256);
+        let proofs = [
+            Proof::from_bytes(&compressed).unwrap(),
+            Proof::from_uncompressed_bytes(&uncompressed).unwrap(),
+            Proof::decode(&mut &proof.encode()[..]).unwrap(),
+            serde_json::from_str(&serde_json::to_string(&proof).unwrap()).unwrap(),
+        ];
+        let vk = &prover.params.vk;
+        let vk = VerifyingKey::from_bytes(&vk.to_bytes()).unwrap();
+        assert_eq!(
+            VerifyingKey::decode(&mut &vk.encode()[..])
+                .unwrap()
+                .to_bytes(),
+            vk.to_bytes()
+        );
+        let verifier = Verifier::from_verifying_key(&vk);
+        for proof in proofs.iter() {
+            assert_eq!(proof.to_bytes(), compressed);
+            verifier
+                .verify(proof, &[x, o])
+                .expect("Failed to verify the proof");
+        }
+
+        let params = Parameters::from_uncompressed_bytes(&prover.params.to_uncompressed_bytes());
+        let params: Parameters<Bn254> =
+            serde_json::from_str(&serde_json::to_string(&params.unwrap()).unwrap()).unwrap();
+        assert_eq!(params.to_bytes(), prover.params.to_bytes());
+        let proof = Prover { params }
+            .create_proof(&mut OsRng, DummyCircuit::new(x, o))
+            .expect("Failed to prove");
+        verifier
+            .verify(&proof, &[x, o])
+            .expect("Failed to verify the proof");
+
+        // truncated, extended and malformed encodings
+        assert!(Proof::from_bytes(&compressed[..127]).is_err());
+        assert!(Proof::from_bytes(&[&compressed[..], &[0]].concat()).is_err());
+        let mut malformed = uncompressed.clone();
+        malformed[63] ^= 1;
+        assert!(Proof::from_uncompressed_bytes(&malformed).is_err());
+        assert!(Proof::decode(&mut &compressed[..64]).is_err());
+
+        // a key without the query of the constant one
+        let mut empty = vk.clone();
+        empty.ic.clear();
+        assert!(matches!(
+            VerifyingKey::from_bytes(&empty.to_bytes()),
+            Err(Error::InvalidEncoding)
+        ));
+        assert!(VerifyingKey::decode(&mut &empty.encode()[..]).is_err());
+    }
+
+    #[test]
+    fn batch_verification_test() {
+        let (mut prover, verifier) =
+            ZkSnark::<Bn254>::setup::<DummyCircuit>(OsRng).expect("Failed to compile circuit");
+        let mut proofs = (1..6)
+            .map(|x| {
+                let x = BnScalar::from(x);
+                let o = x * x * x + x + BnScalar::from(5);
+                let proof = prover
+                    .create_proof(&mut OsRng, DummyCircuit::new(x, o))
+                    .expect("Failed to prove");
+                (proof, vec![x, o])
+            })
+            .collect::<Vec<_>>();
+        verifier
+            .verify_batch(&proofs, &mut OsRng)
+            .expect("Failed to verify the proofs");
+        verifier
+            .verify_batch(&[], &mut OsRng)
+            .expect("Failed to verify no proofs");
+
+        proofs[3].1[1] += BnScalar::one();
+        assert!(matches!(
+            verifier.verify_batch(&proofs, &mut OsRng),
+            Err(Error::BatchVerificationError { index: 3 })
+        ));
+        proofs[3].1.pop();
+        assert!(matches!(
+            verifier.verify_batch(&proofs, &mut OsRng),
+            Err(Error::BatchVerificationError { index: 3 })
+        ));
+    }
+
+    #[test]
+    fn mpc_ceremony_test() {
+        let x = BnScalar::from(3);
+        let o = BnScalar::from(35);
+        let dir = std::env::temp_dir().join(format!("groth16-ceremony-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = |round: usize| dir.join(format!("round{}.params", round));
+
+        // the coordinator publishes the initial parameters
+        let initial = MpcParameters::new::<DummyCircuit, _>(&mut OsRng).unwrap();
+        std::fs::write(path(0), initial.to_bytes()).unwrap();
+
+        // every participant reads the last file and writes the next one
+        let mut hashes = Vec::new();
+        for round in 1..4 {
+            let bytes = std::fs::read(path(round - 1)).unwrap();
+            let mut params = MpcParameters::from_bytes(&bytes).unwrap();
+            hashes.push(params.contribute(&mut OsRng));
+            std::fs::write(path(round), params.to_bytes()).unwrap();
+        }
+
+        // anyone can check the transcript against the initial parameters
+        let initial = MpcParameters::from_bytes(&std::fs::read(path(0)).unwrap()).unwrap();
+        let last = MpcParameters::from_bytes(&std::fs::read(path(3)).unwrap()).unwrap();
+        assert_eq!(last.verify(&initial, &mut OsRng).unwrap(), hashes);
+        let halfway = MpcParameters::from_bytes(&std::fs::read(path(2)).unwrap()).unwrap();
+        assert_eq!(halfway.verify(&initial, &mut OsRng).unwrap(), hashes[..2]);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // a contribution replaced by another one
+        let mut forged = MpcParameters::from_bytes(&last.to_bytes()).unwrap();
+        forged.contributions[1] = forged.contributions[2].clone();
+        assert!(matches!(
+            forged.verify(&initial, &mut OsRng),
+            Err(Error::InvalidContribution { index: 1 })
+        ));
+
+        // delta updated without dividing the queries
+        let mut forged = MpcParameters::from_bytes(&last.to_bytes()).unwrap();
+        forged.params.h[0] = initial.params.h[0];
+        assert!(matches!(
+            forged.verify(&initial, &mut OsRng),
+            Err(Error::InconsistentParameters)
+        ));
+        assert!(matches!(
+            initial.verify(&last, &mut OsRng),
+            Err(Error::InconsistentParameters)
+        ));
+        // the initial parameters have the public delta = 1
+        assert!(matches!(
+            initial.verify(&initial, &mut OsRng),
+            Err(Error::NoContributions)
+        ));
+
+        let (mut prover, verifier) = last.keys();
+        let proof = prover
+            .create_proof(&mut OsRng, DummyCircuit::new(x, o))
+            .expect("Failed to prove");
+        verifier
+            .verify(&proof, &[x, o])
+            .expect("Failed to verify the proof");
+    }
 }
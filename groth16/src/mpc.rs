@@ -0,0 +1,339 @@
+//! Multi-party computation of the circuit-specific parameters (phase 2).
+//!
+//! The parameters start with `delta = 1` from the powers of tau of a phase 1 ceremony, so no
+//! trapdoor is known to the coordinator. Every participant multiplies `delta` by a secret
+//! and divides the `h` and `l` queries by it, and publishes a `PublicKey` proving knowledge
+//! of the secret. The final parameters are sound as long as one participant discarded their
+//! secret. Anyone holding the initial and the final parameters can check every contribution
+//! with `MpcParameters::verify`.
+
+use crate::circuit::Circuit;
+use crate::error::Error;
+use crate::msm::msm_curve_addition;
+use crate::params::Parameters;
+use crate::prover::Prover;
+use crate::ptau::PowersOfTau;
+use crate::verifier::Verifier;
+
+use blake2b_simd::State;
+use bn_254::{
+    AteParing, Bn254, Fq, Fq2, Fr, G1Affine, G2Affine, G2PairingAffine, G2Projective, Gt,
+};
+use zkstd::circuit::CircuitDriver;
+use zkstd::common::{BNAffine, BNCurve, BNProjective, CurveGroup, Group, PrimeField, RngCore, Vec};
+
+/// Cofactor of the G2 subgroup, `2p - r` in little-endian limbs
+const G2_COFACTOR: [u64; 4] = [
+    0x345f2299c0f9fa8d,
+    0x06ceecda572a2489,
+    0xb85045b68181585e,
+    0x30644e72e131a029,
+];
+
+/// Parameters of a circuit with the contributions to their `delta`
+pub struct MpcParameters {
+    pub(crate) params: Parameters<Bn254>,
+    /// hash of the initial parameters, which binds every contribution to the circuit
+    pub(crate) cs_hash: [u8; 64],
+    pub(crate) contributions: Vec<PublicKey>,
+}
+
+/// Public part of a contribution
+#[derive(Clone)]
+pub struct PublicKey {
+    /// `delta` in G1 after the contribution
+    pub(crate) delta_after: G1Affine,
+    /// proof of knowledge of the secret `d`: a random `s`, `s * d`, and `r * d`
+    /// for the point `r` hashed from the transcript and `s`
+    pub(crate) s: G1Affine,
+    pub(crate) s_delta: G1Affine,
+    pub(crate) r_delta: G2Affine,
+    /// hash of the transcript before the contribution
+    pub(crate) transcript: [u8; 64],
+}
+
+impl MpcParameters {
+    /// Initial parameters of the circuit with `delta = 1` and the other trapdoors sampled
+    /// locally, only meant for tests
+    #[cfg(test)]
+    pub(crate) fn new<C, R: RngCore>(rng: &mut R) -> Result<Self, Error>
+    where
+        C: Circuit,
+        C::Driver: CircuitDriver<Scalar = Fr>,
+    {
+        let alpha = Fr::random(&mut *rng);
+        let beta = Fr::random(&mut *rng);
+        let tau = Fr::random(&mut *rng);
+        let params = crate::zksnark::ZkSnark::<Bn254>::parameters::<C>(
+            alpha,
+            beta,
+            Fr::one(),
+            Fr::one(),
+            tau,
+        )?;
+
+        Ok(Self::from_parameters(params))
+    }
+
+    /// Initial parameters of the circuit derived from the powers of tau of a phase 1
+    /// ceremony, which anyone can reproduce to check the contributions
+    pub fn from_powers_of_tau<C>(powers: &PowersOfTau) -> Result<Self, Error>
+    where
+        C: Circuit,
+        C::Driver: CircuitDriver<Scalar = Fr>,
+    {
+        Ok(Self::from_parameters(powers.parameters::<C>()?))
+    }
+
+    pub(crate) fn from_parameters(params: Parameters<Bn254>) -> Self {
+        let cs_hash = hash(&params.to_bytes());
+
+        Self {
+            params,
+            cs_hash,
+            contributions: Vec::new(),
+        }
+    }
+
+    pub fn params(&self) -> &Parameters<Bn254> {
+        &self.params
+    }
+
+    /// Prover and verifier of the parameters after the last contribution
+    pub fn keys(self) -> (Prover<Bn254>, Verifier<Bn254>) {
+        let verifier = Verifier::from_verifying_key(&self.params.vk);
+
+        (
+            Prover {
+                params: self.params,
+            },
+            verifier,
+        )
+    }
+
+    /// Multiply `delta` by a random secret, which is dropped when returning.
+    /// Return the hash of the contribution, which the participant can look for
+    /// in the hashes returned by `verify`.
+    pub fn contribute<R: RngCore>(&mut self, rng: &mut R) -> [u8; 64] {
+        let delta = Fr::random(&mut *rng);
+        let delta_inverse = delta.invert().expect("delta is not zero");
+
+        let transcript = self.transcript();
+        let s = G1Affine::from(G1Affine::ADDITIVE_GENERATOR * Fr::random(&mut *rng));
+        let s_delta = G1Affine::from(s * delta);
+        let r = hash_to_g2(&transcript, &s, &s_delta);
+        let public_key = PublicKey {
+            delta_after: (self.params.vk.delta_g1 * delta).into(),
+            s,
+            s_delta,
+            r_delta: (r * delta).into(),
+            transcript,
+        };
+
+        let vk = &mut self.params.vk;
+        vk.delta_g1 = public_key.delta_after;
+        vk.delta_g2 = (vk.delta_g2 * delta).into();
+        for point in self.params.h.iter_mut().chain(self.params.l.iter_mut()) {
+            *point = (*point * delta_inverse).into();
+        }
+
+        let contribution = hash(&public_key.to_bytes());
+        self.contributions.push(public_key);
+        contribution
+    }
+
+    /// Check that these parameters were derived from the initial parameters by at least one
+    /// valid contribution, and return the hashes of the contributions in order
+    pub fn verify<R: RngCore>(
+        &self,
+        initial: &MpcParameters,
+        rng: &mut R,
+    ) -> Result<Vec<[u8; 64]>, Error> {
+        let (params, before) = (&self.params, &initial.params);
+        if !initial.contributions.is_empty()
+            || self.cs_hash != initial.cs_hash
+            || self.cs_hash != hash(&before.to_bytes())
+            || params.vk.alpha_g1 != before.vk.alpha_g1
+            || params.vk.beta_g1 != before.vk.beta_g1
+            || params.vk.beta_g2 != before.vk.beta_g2
+            || params.vk.gamma_g2 != before.vk.gamma_g2
+            || params.vk.ic != before.vk.ic
+            || params.a != before.a
+            || params.b_g1 != before.b_g1
+            || params.b_g2 != before.b_g2
+            || params.matrices != before.matrices
+            || params.h.len() != before.h.len()
+            || params.l.len() != before.l.len()
+        {
+            return Err(Error::InconsistentParameters);
+        }
+        // the initial delta is known to anyone
+        if self.contributions.is_empty() {
+            return Err(Error::NoContributions);
+        }
+
+        let mut delta = before.vk.delta_g1;
+        let mut contributions = Vec::with_capacity(self.contributions.len());
+        let mut transcript = State::new();
+        transcript.update(&self.cs_hash);
+        for (index, public_key) in self.contributions.iter().enumerate() {
+            let PublicKey {
+                delta_after,
+                s,
+                s_delta,
+                r_delta,
+                ..
+            } = *public_key;
+            let r = hash_to_g2(&public_key.transcript, &s, &s_delta).into();
+            if public_key.transcript != hash_state(&transcript)
+                || s.is_identity()
+                || delta_after.is_identity()
+                || !same_ratio((s, s_delta), (r, r_delta))
+                || !same_ratio((delta, delta_after), (r, r_delta))
+            {
+                return Err(Error::InvalidContribution { index });
+            }
+
+            let bytes = public_key.to_bytes();
+            transcript.update(&bytes);
+            contributions.push(hash(&bytes));
+            delta = delta_after;
+        }
+
+        // delta is consistent, and h and l were divided by it
+        let g1 = G1Affine::ADDITIVE_GENERATOR;
+        let g2 = G2Affine::ADDITIVE_GENERATOR;
+        if params.vk.delta_g1 != delta
+            || !same_ratio((g1, delta), (g2, params.vk.delta_g2))
+            || !same_ratio(
+                merge_pairs(&params.h, &before.h, rng),
+                (g2, params.vk.delta_g2),
+            )
+            || !same_ratio(
+                merge_pairs(&params.l, &before.l, rng),
+                (g2, params.vk.delta_g2),
+            )
+        {
+            return Err(Error::InconsistentParameters);
+        }
+
+        Ok(contributions)
+    }
+
+    /// Hash of the initial parameters and the contributions so far
+    fn transcript(&self) -> [u8; 64] {
+        let mut state = State::new();
+        state.update(&self.cs_hash);
+        for public_key in self.contributions.iter() {
+            state.update(&public_key.to_bytes());
+        }
+        hash_state(&state)
+    }
+}
+
+fn hash(bytes: &[u8]) -> [u8; 64] {
+    let mut state = State::new();
+    state.update(bytes);
+    hash_state(&state)
+}
+
+fn hash_state(state: &State) -> [u8; 64] {
+    let mut digest = [0; 64];
+    digest.copy_from_slice(state.finalize().as_bytes());
+    digest
+}
+
+/// Check that `a.1 = a.0 * x` and `b.1 = b.0 * x` for the same `x`
+pub(crate) fn same_ratio(a: (G1Affine, G1Affine), b: (G2Affine, G2Affine)) -> bool {
+    AteParing::multi_miller_loop(&[
+        (a.0, G2PairingAffine::from(b.1)),
+        (-a.1, G2PairingAffine::from(b.0)),
+    ])
+    .final_exp()
+        == Gt::ADDITIVE_IDENTITY
+}
+
+/// Random linear combinations of two sequences, which have the same ratio
+/// with overwhelming probability only if every pair has that ratio
+pub(crate) fn merge_pairs<A: BNAffine<Scalar = Fr>, R: RngCore>(
+    left: &[A],
+    right: &[A],
+    rng: &mut R,
+) -> (A, A) {
+    let coeffs = (0..left.len())
+        .map(|_| Fr::random(&mut *rng))
+        .collect::<Vec<_>>();
+
+    (
+        msm_curve_addition(left, &coeffs).into(),
+        msm_curve_addition(right, &coeffs).into(),
+    )
+}
+
+/// Hash to a point of G2 with unknown discrete logarithm by try-and-increment
+fn hash_to_g2(transcript: &[u8; 64], s: &G1Affine, s_delta: &G1Affine) -> G2Projective {
+    let mut state = State::new();
+    state.update(transcript);
+    state.update(&s.to_uncompressed());
+    state.update(&s_delta.to_uncompressed());
+
+    for counter in 0u32.. {
+        let mut digest = state.clone();
+        digest.update(&counter.to_be_bytes());
+        let digest = hash_state(&digest);
+
+        // 253 bits are below the modulus
+        let mut c1 = [0; 32];
+        let mut c0 = [0; 32];
+        c1.copy_from_slice(&digest[..32]);
+        c0.copy_from_slice(&digest[32..]);
+        let sign = c1[0] & 0x80 != 0;
+        c1[0] &= 0x1f;
+        c0[0] &= 0x1f;
+        let x = Fq2::new_unchecked([
+            Fq::from_bytes_be(c0).unwrap(),
+            Fq::from_bytes_be(c1).unwrap(),
+        ]);
+
+        if let Some(y) = (x.square() * x + G2Affine::PARAM_B).sqrt() {
+            let y = if y.lexicographically_largest() == sign {
+                y
+            } else {
+                -y
+            };
+            let point = G2Affine::from_x_and_y(x, y).to_extended();
+
+            // clear the cofactor
+            let mut acc = G2Projective::ADDITIVE_IDENTITY;
+            for limb in G2_COFACTOR.iter().rev() {
+                for i in (0..64).rev() {
+                    acc = acc.double();
+                    if (limb >> i) & 1 == 1 {
+                        acc += point;
+                    }
+                }
+            }
+            if !acc.is_identity() {
+                return acc;
+            }
+        }
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use zkstd::common::OsRng;
+
+    #[test]
+    fn hash_to_g2_test() {
+        let s = G1Affine::random(&mut OsRng);
+        let point = G2Affine::from(hash_to_g2(&[7; 64], &s, &s));
+        assert!(point.is_on_curve());
+        assert!(point.is_torsion_free());
+        assert!(!point.is_identity());
+        assert!(point != G2Affine::from(hash_to_g2(&[8; 64], &s, &s)));
+    }
+}
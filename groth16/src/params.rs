@@ -1,29 +1,96 @@
+use crate::error::Error;
 use crate::verifier::VerifyingKey;
 
-use bn_254::{G1Affine, G2Affine};
-use zkstd::common::Vec;
+use zkstd::circuit::CircuitDriver;
+use zkstd::common::{PairingEngine, PrimeField, Vec};
+use zkstd::matrix::SparseMatrix;
+use zkstd::r1cs::R1cs;
 
 #[derive(Clone, Debug)]
-pub struct Parameters {
-    pub vk: VerifyingKey,
+pub struct Parameters<P: PairingEngine> {
+    pub vk: VerifyingKey<P>,
 
     // Elements of the form ((tau^i * t(tau)) / delta) for i between 0 and
-    // m-2 inclusive. Never contains points at infinity.
-    pub h: Vec<G1Affine>,
+    // n-2 inclusive, where n is the size of the evaluation domain. Never
+    // contains points at infinity.
+    pub h: Vec<P::G1Affine>,
 
     // Elements of the form (beta * u_i(tau) + alpha v_i(tau) + w_i(tau)) / delta
     // for all auxiliary inputs. Variables can never be unconstrained, so this
     // never contains points at infinity.
-    pub l: Vec<G1Affine>,
+    pub l: Vec<P::G1Affine>,
 
     // QAP "A" polynomials evaluated at tau in the Lagrange basis. Never contains
     // points at infinity: polynomials that evaluate to zero are omitted from
     // the CRS and the prover can deterministically skip their evaluation.
-    pub a: Vec<G1Affine>,
+    pub a: Vec<P::G1Affine>,
 
     // QAP "B" polynomials evaluated at tau in the Lagrange basis. Needed in
     // G1 and G2 for C/B queries, respectively. Never contains points at
     // infinity for the same reason as the "A" polynomials.
-    pub b_g1: Vec<G1Affine>,
-    pub b_g2: Vec<G2Affine>,
+    pub b_g1: Vec<P::G1Affine>,
+    pub b_g2: Vec<P::G2Affine>,
+
+    // A, B and C matrices of the circuit, so that proofs can be created
+    // from an assignment without synthesizing the circuit again.
+    pub matrices: ConstraintMatrices<P::Fr>,
+}
+
+impl<P: PairingEngine> Parameters<P> {
+    /// Check that the lengths of the elements match the constraint matrices
+    pub(crate) fn check_dimensions(&self) -> Result<(), Error> {
+        let ConstraintMatrices {
+            num_instance,
+            num_witness,
+            num_constraints,
+            ..
+        } = self.matrices;
+        let variables = num_instance + num_witness;
+        let lengths = [
+            (num_instance, self.vk.ic.len()),
+            (num_constraints.next_power_of_two() - 1, self.h.len()),
+            (num_witness, self.l.len()),
+            (variables, self.a.len()),
+            (variables, self.b_g1.len()),
+            (variables, self.b_g2.len()),
+        ];
+        match lengths
+            .into_iter()
+            .find(|(expected, provided)| expected != provided)
+        {
+            Some((expected, provided)) => {
+                Err(Error::InconsistentParametersLen { expected, provided })
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Constraint matrices of a circuit with the lengths of its assignment
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintMatrices<F: PrimeField> {
+    /// Number of instance variables, including the constant one
+    pub num_instance: usize,
+    /// Number of witness variables
+    pub num_witness: usize,
+    /// Number of constraints, which is the number of rows of each matrix
+    pub num_constraints: usize,
+    pub a: SparseMatrix<F>,
+    pub b: SparseMatrix<F>,
+    pub c: SparseMatrix<F>,
+}
+
+impl<F: PrimeField> ConstraintMatrices<F> {
+    pub(crate) fn new<C: CircuitDriver<Scalar = F>>(cs: &R1cs<C>) -> Self {
+        let (a, b, c) = cs.matrices();
+
+        Self {
+            num_instance: cs.l(),
+            num_witness: cs.m_l_1(),
+            num_constraints: cs.m() as usize,
+            a,
+            b,
+            c,
+        }
+    }
 }
@@ -1,32 +1,22 @@
 use crate::error::Error;
 use crate::verifier::PreparedVerifyingKey;
 
-use bn_254::{AteParing, Fr, G1Affine, G1Projective, G2Affine, G2PairingAffine};
-use zkstd::common::BNProjective;
+use zkstd::common::{BNProjective, PairingEngine};
 
-pub struct Proof {
-    pub(crate) a: G1Affine,
-    pub(crate) b: G2Affine,
-    pub(crate) c: G1Affine,
+#[derive(Clone, Debug)]
+pub struct Proof<P: PairingEngine> {
+    pub(crate) a: P::G1Affine,
+    pub(crate) b: P::G2Affine,
+    pub(crate) c: P::G1Affine,
 }
 
-impl Proof {
+impl<P: PairingEngine> Proof<P> {
     pub(crate) fn verify(
         &self,
-        vk: &PreparedVerifyingKey,
-        public_inputs: &[Fr],
+        vk: &PreparedVerifyingKey<P>,
+        public_inputs: &[P::Fr],
     ) -> Result<(), Error> {
-        if (public_inputs.len() + 1) != vk.ic.len() {
-            return Err(Error::InconsistentPublicInputsLen {
-                expected: vk.ic.len() - 1,
-                provided: public_inputs.len(),
-            });
-        }
-        let mut acc = G1Projective::from(vk.ic[0]);
-
-        for (&i, &b) in public_inputs.iter().zip(vk.ic.iter().skip(1)) {
-            acc += b * i;
-        }
+        let acc = vk.prepare_inputs(public_inputs)?;
 
         // The original verification equation is:
         // A * B = alpha * beta + inputs * gamma + C * delta
@@ -36,12 +26,11 @@ impl Proof {
         // A * B + inputs * (-gamma) + C * (-delta) = alpha * beta
         // which allows us to do a single final exponentiation.
 
-        let pairing = AteParing::multi_miller_loop(&[
-            (self.a, G2PairingAffine::from(self.b)),
+        let pairing = P::final_exponentiation(P::multi_miller_loop(&[
+            (self.a, P::G2Prepared::from(self.b)),
             (acc.to_affine(), vk.neg_gamma_g2.clone()),
             (self.c, vk.neg_delta_g2.clone()),
-        ])
-        .final_exp();
+        ]));
 
         if pairing != vk.alpha_g1_beta_g2 {
             return Err(Error::ProofVerificationError);
@@ -6,31 +6,130 @@ use crate::params::Parameters;
 use crate::poly::PointsValue;
 use crate::proof::Proof;
 
-use bn_254::Fr;
-use zkstd::common::{CurveGroup, Group, RngCore};
+use zkstd::circuit::CircuitDriver;
+use zkstd::common::{CurveGroup, Group, PairingEngine, Ring, RngCore};
+use zkstd::matrix::DenseVectors;
 use zkstd::r1cs::R1cs;
 
 #[derive(Debug)]
-pub struct Prover {
-    pub params: Parameters,
+pub struct Prover<P: PairingEngine> {
+    pub params: Parameters<P>,
 }
 
-impl Prover {
-    /// Execute the gadget, and return whether all constraints were satisfied.
-    pub fn create_proof<C: Circuit, R: RngCore>(
+impl<P: PairingEngine> Prover<P> {
+    /// Synthesize the circuit and create a proof of its assignment, without checking that
+    /// the constraints are satisfied.
+    pub fn create_proof<C, R: RngCore>(
         &mut self,
         rng: &mut R,
         circuit: C,
-    ) -> Result<Proof, Error> {
+    ) -> Result<Proof<P>, Error>
+    where
+        C: Circuit,
+        C::Driver: CircuitDriver<Scalar = P::Fr>,
+    {
+        self.synthesize_and_prove(rng, circuit, false)
+    }
+
+    /// Same as `create_proof`, but check that the assignment satisfies every constraint
+    /// before proving, which costs a multiplication per constraint. Without the check, an
+    /// unsatisfied circuit yields a proof that fails to verify.
+    pub fn create_proof_checked<C, R: RngCore>(
+        &mut self,
+        rng: &mut R,
+        circuit: C,
+    ) -> Result<Proof<P>, Error>
+    where
+        C: Circuit,
+        C::Driver: CircuitDriver<Scalar = P::Fr>,
+    {
+        self.synthesize_and_prove(rng, circuit, true)
+    }
+
+    fn synthesize_and_prove<C, R: RngCore>(
+        &mut self,
+        rng: &mut R,
+        circuit: C,
+        check: bool,
+    ) -> Result<Proof<P>, Error>
+    where
+        C: Circuit,
+        C::Driver: CircuitDriver<Scalar = P::Fr>,
+    {
         let mut cs = R1cs::default();
         circuit.synthesize(&mut cs)?;
+        if cs.m() as usize != self.params.matrices.num_constraints {
+            return Err(Error::InconsistentParametersLen {
+                expected: cs.m() as usize,
+                provided: self.params.matrices.num_constraints,
+            });
+        }
 
-        let size = cs.m().next_power_of_two();
+        self.prove(rng, &cs.x()[1..], &cs.w(), check)
+    }
+
+    /// Create a proof from the public inputs and the witness of the circuit, using the
+    /// constraint matrices stored in the parameters instead of synthesizing the circuit.
+    /// The public inputs exclude the constant one, as in `Verifier::verify`.
+    pub fn create_proof_with_assignment<R: RngCore>(
+        &mut self,
+        rng: &mut R,
+        instance: &[P::Fr],
+        witness: &[P::Fr],
+    ) -> Result<Proof<P>, Error> {
+        self.prove(rng, instance, witness, false)
+    }
+
+    /// Same as `create_proof_with_assignment`, but check that the assignment satisfies
+    /// every constraint before proving, as `create_proof_checked` does.
+    pub fn create_proof_with_assignment_checked<R: RngCore>(
+        &mut self,
+        rng: &mut R,
+        instance: &[P::Fr],
+        witness: &[P::Fr],
+    ) -> Result<Proof<P>, Error> {
+        self.prove(rng, instance, witness, true)
+    }
+
+    fn prove<R: RngCore>(
+        &mut self,
+        rng: &mut R,
+        instance: &[P::Fr],
+        witness: &[P::Fr],
+        check: bool,
+    ) -> Result<Proof<P>, Error> {
+        let matrices = &self.params.matrices;
+        if instance.len() + 1 != matrices.num_instance {
+            return Err(Error::InconsistentPublicInputsLen {
+                expected: matrices.num_instance - 1,
+                provided: instance.len(),
+            });
+        }
+        if witness.len() != matrices.num_witness {
+            return Err(Error::InconsistentWitnessLen {
+                expected: matrices.num_witness,
+                provided: witness.len(),
+            });
+        }
+
+        self.params.check_dimensions()?;
+
+        let size = matrices.num_constraints.next_power_of_two();
         let k = size.trailing_zeros();
         let vk = self.params.vk.clone();
 
-        let fft = Fft::<Fr>::new(k as usize);
-        let (a, b, c) = cs.evaluate();
+        let fft = Fft::<P::Fr>::new(k as usize);
+        let input_assignment = [&[P::Fr::one()][..], instance].concat();
+        let z = DenseVectors::new([input_assignment.as_slice(), witness].concat());
+        let (m, l) = (matrices.num_constraints as u64, matrices.num_instance);
+        let a = matrices.a.prod(m, l, &z).get();
+        let b = matrices.b.prod(m, l, &z).get();
+        let c = matrices.c.prod(m, l, &z).get();
+        if check {
+            if let Some(index) = (0..a.len()).find(|&i| a[i] * b[i] != c[i]) {
+                return Err(Error::UnsatisfiedConstraint { index });
+            }
+        }
 
         // Do the calculation of H(X): A(X) * B(X) - C(X) == H(X) * T(X)
         let a = fft.idft(PointsValue(a));
@@ -50,26 +149,23 @@ impl Prover {
         // From here we do all evaluations with `msm_curve_addition` to not give access to original values.
         let q = msm_curve_addition(&self.params.h, &q);
 
-        let input_assignment = cs.x();
-        let aux_assignment = cs.w();
-
-        let l = msm_curve_addition(&self.params.l, &aux_assignment);
+        let l = msm_curve_addition(&self.params.l, witness);
 
         let a_inputs = msm_curve_addition(&self.params.a, &input_assignment);
-        let a_aux = msm_curve_addition(&self.params.a[cs.l()..], &aux_assignment);
+        let a_aux = msm_curve_addition(&self.params.a[matrices.num_instance..], witness);
 
         let b_g1_inputs = msm_curve_addition(&self.params.b_g1, &input_assignment);
-        let b_g1_aux = msm_curve_addition(&self.params.b_g1[cs.l()..], &aux_assignment);
+        let b_g1_aux = msm_curve_addition(&self.params.b_g1[matrices.num_instance..], witness);
 
         let b_g2_inputs = msm_curve_addition(&self.params.b_g2, &input_assignment);
-        let b_g2_aux = msm_curve_addition(&self.params.b_g2[cs.l()..], &aux_assignment);
+        let b_g2_aux = msm_curve_addition(&self.params.b_g2[matrices.num_instance..], witness);
 
         if vk.delta_g1.is_identity() || vk.delta_g2.is_identity() {
             return Err(Error::ProverSubVersionCrsAttack);
         }
 
-        let r = Fr::random(&mut *rng);
-        let s = Fr::random(&mut *rng);
+        let r = P::Fr::random(&mut *rng);
+        let s = P::Fr::random(&mut *rng);
 
         // Setup shift parameters r * delta and s * delta in A, B and C computations.
         let mut g_a = vk.delta_g1 * r + vk.alpha_g1;
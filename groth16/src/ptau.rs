@@ -0,0 +1,626 @@
+//! Powers of tau from a universal phase 1 ceremony.
+//!
+//! Two formats are supported: the `.ptau` files of snarkjs, and the `response` files of the
+//! perpetual powers of tau ceremony. Loading checks with pairings that the points are the powers
+//! of the same trapdoors, and the circuit specific parameters are derived from them in the
+//! Lagrange basis of the evaluation domain. `gamma` and `delta` start at one and `delta` is
+//! updated in phase 2, see `MpcParameters::from_powers_of_tau`.
+
+use crate::circuit::Circuit;
+use crate::error::Error;
+use crate::fft::Fft;
+use crate::mpc::{merge_pairs, same_ratio};
+use crate::params::{ConstraintMatrices, Parameters};
+use crate::verifier::VerifyingKey;
+
+use bn_254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use zkstd::circuit::CircuitDriver;
+use zkstd::common::{BNAffine, CurveGroup, FftField, Group, MulAssign, RngCore, SigUtils, Vec};
+use zkstd::r1cs::R1cs;
+
+/// Size of the header of a perpetual powers of tau response, the hash of the challenge
+const PPOT_HASH_SIZE: usize = 64;
+
+/// Size of the public key of the last contribution, which ends a response
+const PPOT_PUBLIC_KEY_SIZE: usize =
+    6 * G1Affine::UNCOMPRESSED_SIZE + 3 * G2Affine::UNCOMPRESSED_SIZE;
+
+/// Powers of the secrets `tau`, `alpha` and `beta` of a phase 1 ceremony, which support
+/// circuits with up to `2^power` constraints
+#[derive(Clone, Debug)]
+pub struct PowersOfTau {
+    /// tau^i in G1 for i below 2^(power + 1) - 1
+    pub(crate) tau_g1: Vec<G1Affine>,
+    /// tau^i in G2 for i below 2^power
+    pub(crate) tau_g2: Vec<G2Affine>,
+    /// alpha * tau^i in G1 for i below 2^power
+    pub(crate) alpha_tau_g1: Vec<G1Affine>,
+    /// beta * tau^i in G1 for i below 2^power
+    pub(crate) beta_tau_g1: Vec<G1Affine>,
+    pub(crate) beta_g2: G2Affine,
+}
+
+impl PowersOfTau {
+    /// Load a snarkjs `.ptau` file. The points are stored in little-endian Montgomery form,
+    /// and the sections other than the powers, such as the contributions, are skipped.
+    pub fn from_ptau<R: RngCore>(bytes: &[u8], rng: &mut R) -> Result<Self, Error> {
+        let mut bytes = bytes;
+        if take(&mut bytes, 4)? != b"ptau" || u32_le(&mut bytes)? != 1 {
+            return Err(Error::InvalidEncoding);
+        }
+        let sections = (0..u32_le(&mut bytes)?)
+            .map(|_| {
+                let id = u32_le(&mut bytes)?;
+                let size = u64_le(&mut bytes)?;
+                let size = usize::try_from(size).map_err(|_| Error::InvalidEncoding)?;
+                Ok((id, take(&mut bytes, size)?))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // n8, q, power and the power of the whole ceremony
+        let mut header = section(&sections, 1)?;
+        if u32_le(&mut header)? != 32 || take(&mut header, 32)? != modulus() {
+            return Err(Error::InvalidEncoding);
+        }
+        let n = domain_size(u32_le(&mut header)?)?;
+
+        let g1 = |id, len| points(section(&sections, id)?, len, 64, g1_montgomery);
+        let g2 = |id, len| points(section(&sections, id)?, len, 128, g2_montgomery);
+        let powers = Self {
+            tau_g1: g1(2, 2 * n - 1)?,
+            tau_g2: g2(3, n)?,
+            alpha_tau_g1: g1(4, n)?,
+            beta_tau_g1: g1(5, n)?,
+            beta_g2: g2(6, 1)?[0],
+        };
+
+        powers.verify(rng)?;
+        Ok(powers)
+    }
+
+    /// Load a `response` file of the perpetual powers of tau ceremony, which holds the powers
+    /// as compressed points between the hash of the challenge and the public key of the
+    /// contribution. The size of the file determines the power.
+    pub fn from_ppot_response<R: RngCore>(bytes: &[u8], rng: &mut R) -> Result<Self, Error> {
+        let size = |n: usize| {
+            PPOT_HASH_SIZE
+                + (2 * n - 1) * G1Affine::COMPRESSED_SIZE
+                + n * G2Affine::COMPRESSED_SIZE
+                + 2 * n * G1Affine::COMPRESSED_SIZE
+                + G2Affine::COMPRESSED_SIZE
+                + PPOT_PUBLIC_KEY_SIZE
+        };
+        let n = (1..=Fr::S)
+            .map(|power| 1 << power)
+            .find(|n| size(*n) == bytes.len())
+            .ok_or(Error::InvalidEncoding)?;
+
+        let mut bytes = &bytes[PPOT_HASH_SIZE..];
+        let (g1, g2) = (G1Affine::COMPRESSED_SIZE, G2Affine::COMPRESSED_SIZE);
+        let tau_g1 = points(take(&mut bytes, (2 * n - 1) * g1)?, 2 * n - 1, g1, g1_ppot)?;
+        let tau_g2 = points(take(&mut bytes, n * g2)?, n, g2, g2_ppot)?;
+        let alpha_tau_g1 = points(take(&mut bytes, n * g1)?, n, g1, g1_ppot)?;
+        let beta_tau_g1 = points(take(&mut bytes, n * g1)?, n, g1, g1_ppot)?;
+        let beta_g2 = g2_ppot(take(&mut bytes, G2Affine::COMPRESSED_SIZE)?)?;
+        let powers = Self {
+            tau_g1,
+            tau_g2,
+            alpha_tau_g1,
+            beta_tau_g1,
+            beta_g2,
+        };
+
+        powers.verify(rng)?;
+        Ok(powers)
+    }
+
+    /// The circuits can have up to `2^power` constraints
+    pub fn power(&self) -> u32 {
+        self.tau_g2.len().trailing_zeros()
+    }
+
+    /// Check that the points are the powers of the same `tau`, `alpha` and `beta`,
+    /// and that none of them is zero
+    fn verify<R: RngCore>(&self, rng: &mut R) -> Result<(), Error> {
+        let g1 = G1Affine::ADDITIVE_GENERATOR;
+        let g2 = G2Affine::ADDITIVE_GENERATOR;
+        let tau = (g2, self.tau_g2[1]);
+        let consecutive = |points: &[G1Affine], rng: &mut R| {
+            merge_pairs(&points[..points.len() - 1], &points[1..], rng)
+        };
+
+        if self.tau_g1[0] != g1
+            || self.tau_g2[0] != g2
+            || self.tau_g1[1].is_identity()
+            || self.alpha_tau_g1[0].is_identity()
+            || self.beta_tau_g1[0].is_identity()
+            || !same_ratio(consecutive(&self.tau_g1, rng), tau)
+            || !same_ratio(
+                (g1, self.tau_g1[1]),
+                merge_pairs(
+                    &self.tau_g2[..self.tau_g2.len() - 1],
+                    &self.tau_g2[1..],
+                    rng,
+                ),
+            )
+            || !same_ratio(consecutive(&self.alpha_tau_g1, rng), tau)
+            || !same_ratio(consecutive(&self.beta_tau_g1, rng), tau)
+            || !same_ratio((g1, self.beta_tau_g1[0]), (g2, self.beta_g2))
+        {
+            return Err(Error::InvalidPowersOfTau);
+        }
+
+        Ok(())
+    }
+
+    /// Evaluate the circuit at the trapdoors with `gamma = delta = 1`
+    pub(crate) fn parameters<C>(&self) -> Result<Parameters<Bn254>, Error>
+    where
+        C: Circuit,
+        C::Driver: CircuitDriver<Scalar = Fr>,
+    {
+        let circuit = C::default();
+        let mut cs = R1cs::default();
+
+        circuit.synthesize(&mut cs)?;
+
+        let size = cs.m().next_power_of_two() as usize;
+        if size > self.tau_g2.len() {
+            return Err(Error::InsufficientPowersOfTau {
+                required: size,
+                provided: self.tau_g2.len(),
+            });
+        }
+        let fft = Fft::<Fr>::new(size.trailing_zeros() as usize);
+
+        // tau^i * t(tau) = tau^(i + n) - tau^i
+        let h = (0..size - 1)
+            .map(|i| (self.tau_g1[i + size] - self.tau_g1[i].to_extended()).into())
+            .collect();
+
+        // Use inverse FFT to convert powers of tau to Lagrange coefficients
+        let tau_g1 = lagrange(&fft, &self.tau_g1[..size]);
+        let tau_g2 = lagrange(&fft, &self.tau_g2[..size]);
+        let alpha_tau_g1 = lagrange(&fft, &self.alpha_tau_g1[..size]);
+        let beta_tau_g1 = lagrange(&fft, &self.beta_tau_g1[..size]);
+
+        let ((at_inputs, bt_inputs, ct_inputs), (at_aux, bt_aux, ct_aux)) =
+            cs.z_vectors(cs.l(), cs.m_l_1());
+        let at = [at_inputs, at_aux].concat();
+        let bt = [bt_inputs, bt_aux].concat();
+        let ct = [ct_inputs, ct_aux].concat();
+
+        let a = at.iter().map(|at| eval(&tau_g1, at)).collect();
+        let b_g1 = bt.iter().map(|bt| eval(&tau_g1, bt)).collect();
+        let b_g2 = bt.iter().map(|bt| eval(&tau_g2, bt)).collect();
+
+        // beta * u_i(x) + alpha * v_i(x) + w_i(x)
+        let mut ic = at
+            .iter()
+            .zip(bt.iter())
+            .zip(ct.iter())
+            .map(|((at, bt), ct)| {
+                let ext = eval(&beta_tau_g1, at).to_extended()
+                    + eval(&alpha_tau_g1, bt)
+                    + eval(&tau_g1, ct);
+                ext.into()
+            })
+            .collect::<Vec<_>>();
+        let l = ic.split_off(cs.l());
+
+        let vk = VerifyingKey {
+            alpha_g1: self.alpha_tau_g1[0],
+            beta_g1: self.beta_tau_g1[0],
+            beta_g2: self.beta_g2,
+            gamma_g2: G2Affine::ADDITIVE_GENERATOR,
+            delta_g1: G1Affine::ADDITIVE_GENERATOR,
+            delta_g2: G2Affine::ADDITIVE_GENERATOR,
+            ic,
+        };
+
+        Ok(Parameters {
+            vk,
+            h,
+            l,
+            a,
+            b_g1,
+            b_g2,
+            matrices: ConstraintMatrices::new(&cs),
+        })
+    }
+}
+
+/// Points whose discrete logarithms are the Lagrange basis at tau, from the powers of tau
+fn lagrange<A: BNAffine<Scalar = Fr>>(fft: &Fft<Fr>, powers: &[A]) -> Vec<A>
+where
+    A::Extended: Send + MulAssign<Fr>,
+{
+    let powers = powers.iter().map(|point| point.to_extended()).collect();
+    fft.idft_points(powers)
+        .into_iter()
+        .map(|point| point.into())
+        .collect()
+}
+
+/// Evaluate a QAP polynomial given by its Lagrange coefficients
+fn eval<A: BNAffine<Scalar = Fr>>(lagrange: &[A], p: &[(Fr, usize)]) -> A {
+    p.iter()
+        .fold(A::Extended::ADDITIVE_IDENTITY, |acc, (coeff, index)| {
+            acc + lagrange[*index] * *coeff
+        })
+        .into()
+}
+
+/// Number of points of the evaluation domain of a ptau header
+fn domain_size(power: u32) -> Result<usize, Error> {
+    match power >= 1 && power as usize <= Fr::S {
+        true => Ok(1 << power),
+        false => Err(Error::InvalidEncoding),
+    }
+}
+
+/// Little-endian encoding of the base field modulus
+fn modulus() -> [u8; 32] {
+    // q is odd, so adding one to q - 1 does not carry
+    let mut q = (-Fq::one()).to_bytes();
+    q[0] += 1;
+    q
+}
+
+/// Split `len` bytes off the front of the input
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Result<&'a [u8], Error> {
+    if bytes.len() < len {
+        return Err(Error::InvalidEncoding);
+    }
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    Ok(head)
+}
+
+fn u32_le(bytes: &mut &[u8]) -> Result<u32, Error> {
+    Ok(u32::from_le_bytes(take(bytes, 4)?.try_into().unwrap()))
+}
+
+fn u64_le(bytes: &mut &[u8]) -> Result<u64, Error> {
+    Ok(u64::from_le_bytes(take(bytes, 8)?.try_into().unwrap()))
+}
+
+/// The unique section with the given id
+fn section<'a>(sections: &[(u32, &'a [u8])], id: u32) -> Result<&'a [u8], Error> {
+    let mut matches = sections.iter().filter(|(section, _)| *section == id);
+    match (matches.next(), matches.next()) {
+        (Some((_, bytes)), None) => Ok(bytes),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
+
+/// Decode a sequence of `len` points of `size` bytes filling the bytes
+fn points<A>(
+    bytes: &[u8],
+    len: usize,
+    size: usize,
+    decode: impl Fn(&[u8]) -> Result<A, Error>,
+) -> Result<Vec<A>, Error> {
+    if bytes.len() != len * size {
+        return Err(Error::InvalidEncoding);
+    }
+    bytes.chunks_exact(size).map(decode).collect()
+}
+
+/// An element of the base field in little-endian Montgomery form
+fn fq_montgomery(bytes: &[u8]) -> Result<Fq, Error> {
+    let bytes: [u8; 32] = bytes.try_into().map_err(|_| Error::InvalidEncoding)?;
+    // the canonical decoding checks that the limbs are below the modulus
+    Fq::from_bytes(bytes).ok_or(Error::InvalidEncoding)?;
+    let limbs = [0, 1, 2, 3].map(|i| u64::from_le_bytes(bytes[i * 8..][..8].try_into().unwrap()));
+
+    Ok(Fq::new_unchecked(limbs))
+}
+
+/// x and y in little-endian Montgomery form, the identity is encoded as zeros
+fn g1_montgomery(bytes: &[u8]) -> Result<G1Affine, Error> {
+    if bytes.iter().all(|byte| *byte == 0) {
+        return Ok(G1Affine::ADDITIVE_IDENTITY);
+    }
+    let x = fq_montgomery(&bytes[..32])?;
+    let y = fq_montgomery(&bytes[32..])?;
+    let point = G1Affine::new_unchecked(x, y);
+
+    match point.is_on_curve() {
+        true => Ok(point),
+        false => Err(Error::InvalidEncoding),
+    }
+}
+
+/// x and y in little-endian Montgomery form with c0 first
+fn g2_montgomery(bytes: &[u8]) -> Result<G2Affine, Error> {
+    if bytes.iter().all(|byte| *byte == 0) {
+        return Ok(G2Affine::ADDITIVE_IDENTITY);
+    }
+    let x = Fq2::new_unchecked([fq_montgomery(&bytes[..32])?, fq_montgomery(&bytes[32..64])?]);
+    let y = Fq2::new_unchecked([fq_montgomery(&bytes[64..96])?, fq_montgomery(&bytes[96..])?]);
+    let point = G2Affine::new_unchecked(x, y);
+
+    match point.is_on_curve() && point.is_torsion_free() {
+        true => Ok(point),
+        false => Err(Error::InvalidEncoding),
+    }
+}
+
+/// The compressed encodings of the ceremony differ from `to_compressed` only by the flags,
+/// the most significant bit is the sign of y and the next one is the point at infinity
+fn swap_flags<const N: usize>(bytes: &[u8]) -> Result<[u8; N], Error> {
+    let mut bytes: [u8; N] = bytes.try_into().map_err(|_| Error::InvalidEncoding)?;
+    let (sign, infinity) = (bytes[0] & 0x80, bytes[0] & 0x40);
+    bytes[0] = (bytes[0] & 0x3f) | (sign >> 1) | (infinity << 1);
+    Ok(bytes)
+}
+
+fn g1_ppot(bytes: &[u8]) -> Result<G1Affine, Error> {
+    G1Affine::from_compressed(&swap_flags(bytes)?).ok_or(Error::InvalidEncoding)
+}
+
+fn g2_ppot(bytes: &[u8]) -> Result<G2Affine, Error> {
+    G2Affine::from_compressed(&swap_flags(bytes)?).ok_or(Error::InvalidEncoding)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::mpc::MpcParameters;
+    use crate::tests::DummyCircuit;
+    use crate::zksnark::ZkSnark;
+
+    use zkstd::common::OsRng;
+
+    pub(crate) fn from_trapdoors(power: u32, tau: Fr, alpha: Fr, beta: Fr) -> PowersOfTau {
+        let n = 1 << power;
+        let g1 = G1Affine::ADDITIVE_GENERATOR;
+        let g2 = G2Affine::ADDITIVE_GENERATOR;
+        let taus = (0..2 * n - 1)
+            .scan(Fr::one(), |acc, _| {
+                let tau_i = *acc;
+                *acc *= tau;
+                Some(tau_i)
+            })
+            .collect::<Vec<_>>();
+
+        PowersOfTau {
+            tau_g1: taus.iter().map(|tau_i| (g1 * *tau_i).into()).collect(),
+            tau_g2: taus[..n].iter().map(|tau_i| (g2 * *tau_i).into()).collect(),
+            alpha_tau_g1: taus[..n]
+                .iter()
+                .map(|tau_i| (g1 * (alpha * tau_i)).into())
+                .collect(),
+            beta_tau_g1: taus[..n]
+                .iter()
+                .map(|tau_i| (g1 * (beta * tau_i)).into())
+                .collect(),
+            beta_g2: (g2 * beta).into(),
+        }
+    }
+
+    /// Re-encode big-endian coordinates in little-endian Montgomery form
+    fn montgomery(uncompressed: &[u8], order: &[usize]) -> Vec<u8> {
+        order
+            .iter()
+            .flat_map(|i| {
+                let fq = Fq::from_bytes_be(uncompressed[i * 32..][..32].try_into().unwrap());
+                let limbs = *fq.unwrap().inner();
+                limbs.into_iter().flat_map(|limb| limb.to_le_bytes())
+            })
+            .collect()
+    }
+
+    fn ptau_g1(point: &G1Affine) -> Vec<u8> {
+        montgomery(&point.to_uncompressed(), &[0, 1])
+    }
+
+    fn ptau_g2(point: &G2Affine) -> Vec<u8> {
+        // c1 comes first in the uncompressed encoding
+        montgomery(&point.to_uncompressed(), &[1, 0, 3, 2])
+    }
+
+    fn to_ptau(powers: &PowersOfTau) -> Vec<u8> {
+        let mut header = 32u32.to_le_bytes().to_vec();
+        header.extend(modulus());
+        header.extend(powers.power().to_le_bytes());
+        header.extend(28u32.to_le_bytes());
+        let sections = [
+            (1, header),
+            (2, powers.tau_g1.iter().flat_map(ptau_g1).collect()),
+            (3, powers.tau_g2.iter().flat_map(ptau_g2).collect()),
+            (4, powers.alpha_tau_g1.iter().flat_map(ptau_g1).collect()),
+            (5, powers.beta_tau_g1.iter().flat_map(ptau_g1).collect()),
+            (6, ptau_g2(&powers.beta_g2)),
+            // no contributions
+            (7, 0u32.to_le_bytes().to_vec()),
+        ];
+
+        let mut bytes = b"ptau".to_vec();
+        bytes.extend(1u32.to_le_bytes());
+        bytes.extend((sections.len() as u32).to_le_bytes());
+        for (id, section) in sections.iter() {
+            bytes.extend((*id as u32).to_le_bytes());
+            bytes.extend((section.len() as u64).to_le_bytes());
+            bytes.extend(section);
+        }
+        bytes
+    }
+
+    fn to_ppot_response(powers: &PowersOfTau) -> Vec<u8> {
+        let g1 = |point: &G1Affine| swap_flags::<32>(&point.to_compressed()).unwrap();
+        let g2 = |point: &G2Affine| swap_flags::<64>(&point.to_compressed()).unwrap();
+
+        let mut bytes = vec![0; PPOT_HASH_SIZE];
+        bytes.extend(powers.tau_g1.iter().flat_map(g1));
+        bytes.extend(powers.tau_g2.iter().flat_map(g2));
+        bytes.extend(powers.alpha_tau_g1.iter().flat_map(g1));
+        bytes.extend(powers.beta_tau_g1.iter().flat_map(g1));
+        bytes.extend(g2(&powers.beta_g2));
+        bytes.extend([0; PPOT_PUBLIC_KEY_SIZE]);
+        bytes
+    }
+
+    fn assert_same(a: &PowersOfTau, b: &PowersOfTau) {
+        assert_eq!(a.tau_g1, b.tau_g1);
+        assert_eq!(a.tau_g2, b.tau_g2);
+        assert_eq!(a.alpha_tau_g1, b.alpha_tau_g1);
+        assert_eq!(a.beta_tau_g1, b.beta_tau_g1);
+        assert_eq!(a.beta_g2, b.beta_g2);
+    }
+
+    #[test]
+    fn powers_of_tau_test() {
+        let (tau, alpha, beta) = (
+            Fr::random(&mut OsRng),
+            Fr::random(&mut OsRng),
+            Fr::random(&mut OsRng),
+        );
+        let powers = from_trapdoors(3, tau, alpha, beta);
+
+        let ptau = PowersOfTau::from_ptau(&to_ptau(&powers), &mut OsRng).unwrap();
+        assert_same(&ptau, &powers);
+        assert_eq!(ptau.power(), 3);
+        let response = PowersOfTau::from_ppot_response(&to_ppot_response(&powers), &mut OsRng);
+        assert_same(&response.unwrap(), &powers);
+
+        // the Lagrange basis from the points matches the one from the trapdoors
+        let params =
+            ZkSnark::<Bn254>::parameters::<DummyCircuit>(alpha, beta, Fr::one(), Fr::one(), tau);
+        assert_eq!(
+            ptau.parameters::<DummyCircuit>().unwrap().to_bytes(),
+            params.unwrap().to_bytes()
+        );
+
+        // phase 2 starts from the parameters that anyone can derive
+        let initial = MpcParameters::from_powers_of_tau::<DummyCircuit>(&ptau).unwrap();
+        let mut params = MpcParameters::from_bytes(&initial.to_bytes()).unwrap();
+        let hash = params.contribute(&mut OsRng);
+        assert_eq!(params.verify(&initial, &mut OsRng).unwrap(), [hash]);
+
+        let x = Fr::from(3);
+        let o = Fr::from(35);
+        let (mut prover, verifier) =
+            ZkSnark::<Bn254>::setup_with_powers_of_tau::<DummyCircuit>(&ptau, OsRng).unwrap();
+        let proof = prover
+            .create_proof(&mut OsRng, DummyCircuit::new(x, o))
+            .expect("Failed to prove");
+        verifier
+            .verify(&proof, &[x, o])
+            .expect("Failed to verify the proof");
+
+        // the dummy circuit has four constraints
+        let small = from_trapdoors(1, tau, alpha, beta);
+        assert!(matches!(
+            small.parameters::<DummyCircuit>(),
+            Err(Error::InsufficientPowersOfTau {
+                required: 4,
+                provided: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn invalid_powers_of_tau_test() {
+        let (tau, alpha, beta) = (
+            Fr::random(&mut OsRng),
+            Fr::random(&mut OsRng),
+            Fr::random(&mut OsRng),
+        );
+
+        // a power of another tau
+        let mut forged = from_trapdoors(2, tau, alpha, beta);
+        forged.tau_g1[5] = from_trapdoors(2, tau + Fr::one(), alpha, beta).tau_g1[5];
+        assert!(matches!(
+            PowersOfTau::from_ptau(&to_ptau(&forged), &mut OsRng),
+            Err(Error::InvalidPowersOfTau)
+        ));
+        assert!(matches!(
+            PowersOfTau::from_ppot_response(&to_ppot_response(&forged), &mut OsRng),
+            Err(Error::InvalidPowersOfTau)
+        ));
+
+        // beta in G2 differs from beta in G1
+        let mut forged = from_trapdoors(2, tau, alpha, beta);
+        forged.beta_g2 = from_trapdoors(2, tau, alpha, alpha).beta_g2;
+        assert!(matches!(
+            PowersOfTau::from_ptau(&to_ptau(&forged), &mut OsRng),
+            Err(Error::InvalidPowersOfTau)
+        ));
+
+        // a zero tau
+        let forged = from_trapdoors(2, Fr::zero(), alpha, beta);
+        assert!(matches!(
+            PowersOfTau::from_ptau(&to_ptau(&forged), &mut OsRng),
+            Err(Error::InvalidPowersOfTau)
+        ));
+
+        // malformed files
+        let ptau = to_ptau(&from_trapdoors(2, tau, alpha, beta));
+        assert!(matches!(
+            PowersOfTau::from_ptau(&ptau[..ptau.len() - 1], &mut OsRng),
+            Err(Error::InvalidEncoding)
+        ));
+        let mut wrong_magic = ptau.clone();
+        wrong_magic[0] = b'x';
+        assert!(matches!(
+            PowersOfTau::from_ptau(&wrong_magic, &mut OsRng),
+            Err(Error::InvalidEncoding)
+        ));
+        // x of the second power of tau in G1 is moved off the curve
+        let mut off_curve = ptau.clone();
+        off_curve[12 + 12 + 44 + 12 + 64] ^= 1;
+        assert!(matches!(
+            PowersOfTau::from_ptau(&off_curve, &mut OsRng),
+            Err(Error::InvalidEncoding)
+        ));
+        let response = to_ppot_response(&from_trapdoors(2, tau, alpha, beta));
+        assert!(matches!(
+            PowersOfTau::from_ppot_response(&response[1..], &mut OsRng),
+            Err(Error::InvalidEncoding)
+        ));
+    }
+
+    // requires a `.ptau` file of the Hermez ceremony prepared by snarkjs, run with
+    // `PTAU=powersOfTau28_hez_final_08.ptau cargo test -- --ignored`
+    #[test]
+    #[ignore]
+    fn hermez_powers_of_tau_test() {
+        let path = std::env::var("PTAU").expect("Set PTAU to the path of the powers of tau");
+        let bytes = std::fs::read(path).expect("Failed to read the powers of tau");
+        let ptau = PowersOfTau::from_ptau(&bytes, &mut OsRng).expect("Failed to decode");
+        let n = 1 << ptau.power();
+        assert_eq!(ptau.tau_g1.len(), 2 * n - 1);
+        assert_eq!(ptau.alpha_tau_g1.len(), n);
+        assert_eq!(ptau.tau_g1[0], G1Affine::ADDITIVE_GENERATOR);
+        assert_eq!(ptau.tau_g2[0], G2Affine::ADDITIVE_GENERATOR);
+
+        // the parameters derived from the ceremony prove and verify
+        let params = ptau.parameters::<DummyCircuit>().unwrap();
+        assert_eq!(params.h.len(), 3);
+        let x = Fr::from(3);
+        let o = Fr::from(35);
+        let (mut prover, verifier) =
+            ZkSnark::<Bn254>::setup_with_powers_of_tau::<DummyCircuit>(&ptau, OsRng).unwrap();
+        let proof = prover
+            .create_proof(&mut OsRng, DummyCircuit::new(x, o))
+            .expect("Failed to prove");
+        verifier
+            .verify(&proof, &[x, o])
+            .expect("Failed to verify the proof");
+
+        // and phase 2 starts from them
+        let initial = MpcParameters::from_powers_of_tau::<DummyCircuit>(&ptau).unwrap();
+        assert_eq!(initial.params().to_bytes(), params.to_bytes());
+        let mut params = MpcParameters::from_bytes(&initial.to_bytes()).unwrap();
+        let hash = params.contribute(&mut OsRng);
+        assert_eq!(params.verify(&initial, &mut OsRng).unwrap(), [hash]);
+
+        // a point of another power
+        let mut forged = bytes;
+        let tau_g1 = 12 + 12 + 44 + 12;
+        forged.copy_within(tau_g1 + 128..tau_g1 + 192, tau_g1 + 64);
+        assert!(PowersOfTau::from_ptau(&forged, &mut OsRng).is_err());
+    }
+}
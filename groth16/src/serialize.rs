@@ -0,0 +1,524 @@
+//! Canonical byte encodings of proofs, keys, phase 2 contributions and aggregate proofs.
+//!
+//! Points are encoded by `G1Affine::to_compressed` and `G2Affine::to_compressed`, or by their
+//! uncompressed forms which follow EIP-196 and EIP-197, in the order of the struct fields.
+//! Sequences of points are prefixed by their length as a big-endian `u32`. Decoding checks
+//! that every point is on the curve and in the prime order subgroup. Elements of `Gt` are
+//! encoded by `Gt::to_bytes` in both forms. The constraint matrices of `Parameters` are
+//! sequences of rows, whose terms are a byte for the kind of the wire, its index as a
+//! big-endian `u32` and the coefficient as 32 big-endian bytes. The SCALE codec and
+//! serde use the compressed encoding.
+
+use crate::aggregate::{AggregateProof, AggregationKey, AggregationVerifyingKey, Round};
+use crate::error::Error;
+use crate::mpc::{MpcParameters, PublicKey};
+use crate::params::{ConstraintMatrices, Parameters};
+use crate::proof::Proof;
+use crate::verifier::VerifyingKey;
+
+use bn_254::{Bn254, Fr, G1Affine, G2Affine, Gt};
+use core::fmt;
+use parity_scale_codec::{Decode, Encode, Input, Output};
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zkstd::common::{SigUtils, Vec};
+use zkstd::matrix::{SparseMatrix, SparseRow};
+use zkstd::r1cs::Wire;
+
+pub(crate) struct Writer<'a, O: Output + ?Sized> {
+    output: &'a mut O,
+    compressed: bool,
+}
+
+impl<'a, O: Output + ?Sized> Writer<'a, O> {
+    pub(crate) fn new(output: &'a mut O, compressed: bool) -> Self {
+        Self { output, compressed }
+    }
+
+    fn g1(&mut self, point: &G1Affine) {
+        if self.compressed {
+            self.output.write(&point.to_compressed())
+        } else {
+            self.output.write(&point.to_uncompressed())
+        }
+    }
+
+    fn g2(&mut self, point: &G2Affine) {
+        if self.compressed {
+            self.output.write(&point.to_compressed())
+        } else {
+            self.output.write(&point.to_uncompressed())
+        }
+    }
+
+    fn gt(&mut self, element: &Gt) {
+        self.output.write(&element.to_bytes())
+    }
+
+    fn len(&mut self, len: usize) {
+        self.output.write(&(len as u32).to_be_bytes())
+    }
+
+    fn raw(&mut self, bytes: &[u8]) {
+        self.output.write(bytes)
+    }
+
+    fn g1_vec(&mut self, points: &[G1Affine]) {
+        self.len(points.len());
+        points.iter().for_each(|point| self.g1(point))
+    }
+
+    fn g2_vec(&mut self, points: &[G2Affine]) {
+        self.len(points.len());
+        points.iter().for_each(|point| self.g2(point))
+    }
+
+    fn scalar(&mut self, scalar: &Fr) {
+        let mut bytes = scalar.to_bytes();
+        bytes.reverse();
+        self.output.write(&bytes)
+    }
+
+    fn matrix(&mut self, matrix: &SparseMatrix<Fr>) {
+        self.len(matrix.rows().len());
+        for row in matrix.rows() {
+            self.len(row.terms().len());
+            for (wire, coeff) in row.terms() {
+                let (tag, index) = match wire {
+                    Wire::Instance(index) => (0, index),
+                    Wire::Witness(index) => (1, index),
+                };
+                self.output.push_byte(tag);
+                self.len(*index as usize);
+                self.scalar(coeff);
+            }
+        }
+    }
+}
+
+pub(crate) struct Reader<'a, I: Input> {
+    input: &'a mut I,
+    compressed: bool,
+}
+
+impl<'a, I: Input> Reader<'a, I> {
+    pub(crate) fn new(input: &'a mut I, compressed: bool) -> Self {
+        Self { input, compressed }
+    }
+
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let mut bytes = [0; N];
+        self.input
+            .read(&mut bytes)
+            .map_err(|_| Error::InvalidEncoding)?;
+        Ok(bytes)
+    }
+
+    fn g1(&mut self) -> Result<G1Affine, Error> {
+        let point = if self.compressed {
+            G1Affine::from_compressed(&self.bytes()?)
+        } else {
+            G1Affine::from_uncompressed(&self.bytes()?)
+        };
+        point.ok_or(Error::InvalidEncoding)
+    }
+
+    fn g2(&mut self) -> Result<G2Affine, Error> {
+        let point = if self.compressed {
+            G2Affine::from_compressed(&self.bytes()?)
+        } else {
+            G2Affine::from_uncompressed(&self.bytes()?)
+        };
+        point.ok_or(Error::InvalidEncoding)
+    }
+
+    fn gt(&mut self) -> Result<Gt, Error> {
+        Gt::from_bytes(&self.bytes()?).ok_or(Error::InvalidEncoding)
+    }
+
+    fn len(&mut self) -> Result<usize, Error> {
+        Ok(u32::from_be_bytes(self.bytes()?) as usize)
+    }
+
+    // the length is not trusted for allocation, a short input fails while reading
+    fn g1_vec(&mut self) -> Result<Vec<G1Affine>, Error> {
+        (0..self.len()?).map(|_| self.g1()).collect()
+    }
+
+    fn g2_vec(&mut self) -> Result<Vec<G2Affine>, Error> {
+        (0..self.len()?).map(|_| self.g2()).collect()
+    }
+
+    fn scalar(&mut self) -> Result<Fr, Error> {
+        let mut bytes: [u8; 32] = self.bytes()?;
+        bytes.reverse();
+        Fr::from_bytes(bytes).ok_or(Error::InvalidEncoding)
+    }
+
+    // every wire must be a variable of the assignment
+    fn matrix(
+        &mut self,
+        num_instance: usize,
+        num_witness: usize,
+    ) -> Result<SparseMatrix<Fr>, Error> {
+        let rows = (0..self.len()?)
+            .map(|_| {
+                let terms = (0..self.len()?)
+                    .map(|_| {
+                        let [tag] = self.bytes()?;
+                        let index = self.len()?;
+                        let wire = match tag {
+                            0 if index < num_instance => Wire::Instance(index as u64),
+                            1 if index < num_witness => Wire::Witness(index as u64),
+                            _ => return Err(Error::InvalidEncoding),
+                        };
+                        Ok((wire, self.scalar()?))
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok(SparseRow::new(terms))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(SparseMatrix::new(rows))
+    }
+}
+
+pub(crate) trait Canonical: Sized {
+    fn write<O: Output + ?Sized>(&self, writer: &mut Writer<O>);
+
+    fn read<I: Input>(reader: &mut Reader<I>) -> Result<Self, Error>;
+}
+
+impl Canonical for Proof<Bn254> {
+    fn write<O: Output + ?Sized>(&self, writer: &mut Writer<O>) {
+        writer.g1(&self.a);
+        writer.g2(&self.b);
+        writer.g1(&self.c);
+    }
+
+    fn read<I: Input>(reader: &mut Reader<I>) -> Result<Self, Error> {
+        Ok(Self {
+            a: reader.g1()?,
+            b: reader.g2()?,
+            c: reader.g1()?,
+        })
+    }
+}
+
+impl Canonical for VerifyingKey<Bn254> {
+    fn write<O: Output + ?Sized>(&self, writer: &mut Writer<O>) {
+        writer.g1(&self.alpha_g1);
+        writer.g1(&self.beta_g1);
+        writer.g2(&self.beta_g2);
+        writer.g2(&self.gamma_g2);
+        writer.g1(&self.delta_g1);
+        writer.g2(&self.delta_g2);
+        writer.g1_vec(&self.ic);
+    }
+
+    fn read<I: Input>(reader: &mut Reader<I>) -> Result<Self, Error> {
+        let vk = Self {
+            alpha_g1: reader.g1()?,
+            beta_g1: reader.g1()?,
+            beta_g2: reader.g2()?,
+            gamma_g2: reader.g2()?,
+            delta_g1: reader.g1()?,
+            delta_g2: reader.g2()?,
+            ic: reader.g1_vec()?,
+        };
+        // the first query is the constant one
+        if vk.ic.is_empty() {
+            return Err(Error::InvalidEncoding);
+        }
+
+        Ok(vk)
+    }
+}
+
+impl Canonical for PublicKey {
+    fn write<O: Output + ?Sized>(&self, writer: &mut Writer<O>) {
+        writer.g1(&self.delta_after);
+        writer.g1(&self.s);
+        writer.g1(&self.s_delta);
+        writer.g2(&self.r_delta);
+        writer.raw(&self.transcript);
+    }
+
+    fn read<I: Input>(reader: &mut Reader<I>) -> Result<Self, Error> {
+        Ok(Self {
+            delta_after: reader.g1()?,
+            s: reader.g1()?,
+            s_delta: reader.g1()?,
+            r_delta: reader.g2()?,
+            transcript: reader.bytes()?,
+        })
+    }
+}
+
+impl Canonical for MpcParameters {
+    fn write<O: Output + ?Sized>(&self, writer: &mut Writer<O>) {
+        self.params.write(writer);
+        writer.raw(&self.cs_hash);
+        writer.len(self.contributions.len());
+        for public_key in self.contributions.iter() {
+            public_key.write(writer);
+        }
+    }
+
+    fn read<I: Input>(reader: &mut Reader<I>) -> Result<Self, Error> {
+        Ok(Self {
+            params: Parameters::read(reader)?,
+            cs_hash: reader.bytes()?,
+            contributions: (0..reader.len()?)
+                .map(|_| PublicKey::read(reader))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Canonical for Parameters<Bn254> {
+    fn write<O: Output + ?Sized>(&self, writer: &mut Writer<O>) {
+        self.vk.write(writer);
+        writer.g1_vec(&self.h);
+        writer.g1_vec(&self.l);
+        writer.g1_vec(&self.a);
+        writer.g1_vec(&self.b_g1);
+        writer.g2_vec(&self.b_g2);
+        self.matrices.write(writer);
+    }
+
+    fn read<I: Input>(reader: &mut Reader<I>) -> Result<Self, Error> {
+        Ok(Self {
+            vk: VerifyingKey::read(reader)?,
+            h: reader.g1_vec()?,
+            l: reader.g1_vec()?,
+            a: reader.g1_vec()?,
+            b_g1: reader.g1_vec()?,
+            b_g2: reader.g2_vec()?,
+            matrices: ConstraintMatrices::read(reader)?,
+        })
+    }
+}
+
+impl Canonical for ConstraintMatrices<Fr> {
+    fn write<O: Output + ?Sized>(&self, writer: &mut Writer<O>) {
+        writer.len(self.num_instance);
+        writer.len(self.num_witness);
+        writer.matrix(&self.a);
+        writer.matrix(&self.b);
+        writer.matrix(&self.c);
+    }
+
+    fn read<I: Input>(reader: &mut Reader<I>) -> Result<Self, Error> {
+        let num_instance = reader.len()?;
+        let num_witness = reader.len()?;
+        let a = reader.matrix(num_instance, num_witness)?;
+        let b = reader.matrix(num_instance, num_witness)?;
+        let c = reader.matrix(num_instance, num_witness)?;
+        let num_constraints = a.rows().len();
+        if num_instance == 0
+            || b.rows().len() != num_constraints
+            || c.rows().len() != num_constraints
+        {
+            return Err(Error::InvalidEncoding);
+        }
+
+        Ok(Self {
+            num_instance,
+            num_witness,
+            num_constraints,
+            a,
+            b,
+            c,
+        })
+    }
+}
+
+impl Canonical for AggregationKey {
+    fn write<O: Output + ?Sized>(&self, writer: &mut Writer<O>) {
+        writer.g1_vec(&self.g_a);
+        writer.g1_vec(&self.g_b);
+        writer.g2_vec(&self.h_a);
+        writer.g2_vec(&self.h_b);
+    }
+
+    fn read<I: Input>(reader: &mut Reader<I>) -> Result<Self, Error> {
+        Ok(Self {
+            g_a: reader.g1_vec()?,
+            g_b: reader.g1_vec()?,
+            h_a: reader.g2_vec()?,
+            h_b: reader.g2_vec()?,
+        })
+    }
+}
+
+impl Canonical for AggregationVerifyingKey {
+    fn write<O: Output + ?Sized>(&self, writer: &mut Writer<O>) {
+        writer.g1(&self.g_a);
+        writer.g1(&self.g_b);
+        writer.g2(&self.h_a);
+        writer.g2(&self.h_b);
+    }
+
+    fn read<I: Input>(reader: &mut Reader<I>) -> Result<Self, Error> {
+        Ok(Self {
+            g_a: reader.g1()?,
+            g_b: reader.g1()?,
+            h_a: reader.g2()?,
+            h_b: reader.g2()?,
+        })
+    }
+}
+
+impl Canonical for Round {
+    fn write<O: Output + ?Sized>(&self, writer: &mut Writer<O>) {
+        for element in self.com_ab.iter().chain(self.com_c.iter()).flatten() {
+            writer.gt(element);
+        }
+        self.z_ab.iter().for_each(|element| writer.gt(element));
+        self.z_c.iter().for_each(|point| writer.g1(point));
+    }
+
+    fn read<I: Input>(reader: &mut Reader<I>) -> Result<Self, Error> {
+        Ok(Self {
+            com_ab: [[reader.gt()?, reader.gt()?], [reader.gt()?, reader.gt()?]],
+            com_c: [[reader.gt()?, reader.gt()?], [reader.gt()?, reader.gt()?]],
+            z_ab: [reader.gt()?, reader.gt()?],
+            z_c: [reader.g1()?, reader.g1()?],
+        })
+    }
+}
+
+impl Canonical for AggregateProof {
+    fn write<O: Output + ?Sized>(&self, writer: &mut Writer<O>) {
+        for element in self.com_ab.iter().chain(self.com_c.iter()) {
+            writer.gt(element);
+        }
+        writer.gt(&self.ip_ab);
+        writer.g1(&self.agg_c);
+        writer.len(self.rounds.len());
+        for round in self.rounds.iter() {
+            round.write(writer);
+        }
+        writer.g1(&self.final_a);
+        writer.g2(&self.final_b);
+        writer.g1(&self.final_c);
+        self.final_v.iter().for_each(|point| writer.g2(point));
+        self.final_w.iter().for_each(|point| writer.g1(point));
+        self.v_opening.iter().for_each(|point| writer.g2(point));
+        self.w_opening.iter().for_each(|point| writer.g1(point));
+    }
+
+    fn read<I: Input>(reader: &mut Reader<I>) -> Result<Self, Error> {
+        Ok(Self {
+            com_ab: [reader.gt()?, reader.gt()?],
+            com_c: [reader.gt()?, reader.gt()?],
+            ip_ab: reader.gt()?,
+            agg_c: reader.g1()?,
+            rounds: (0..reader.len()?)
+                .map(|_| Round::read(reader))
+                .collect::<Result<_, _>>()?,
+            final_a: reader.g1()?,
+            final_b: reader.g2()?,
+            final_c: reader.g1()?,
+            final_v: [reader.g2()?, reader.g2()?],
+            final_w: [reader.g1()?, reader.g1()?],
+            v_opening: [reader.g2()?, reader.g2()?],
+            w_opening: [reader.g1()?, reader.g1()?],
+        })
+    }
+}
+
+/// Accept the bytes of any serde data format, including formats without a bytes type
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a byte array")
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::new();
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+macro_rules! canonical_encoding {
+    ($name:ident $(<$engine:ty>)?) => {
+        impl $name$(<$engine>)? {
+            /// Canonical encoding with compressed points
+            pub fn to_bytes(&self) -> Vec<u8> {
+                let mut bytes = Vec::new();
+                self.write(&mut Writer::new(&mut bytes, true));
+                bytes
+            }
+
+            /// Canonical encoding with uncompressed points
+            pub fn to_uncompressed_bytes(&self) -> Vec<u8> {
+                let mut bytes = Vec::new();
+                self.write(&mut Writer::new(&mut bytes, false));
+                bytes
+            }
+
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+                Self::decode_exact(bytes, true)
+            }
+
+            pub fn from_uncompressed_bytes(bytes: &[u8]) -> Result<Self, Error> {
+                Self::decode_exact(bytes, false)
+            }
+
+            fn decode_exact(mut bytes: &[u8], compressed: bool) -> Result<Self, Error> {
+                let decoded = Self::read(&mut Reader::new(&mut bytes, compressed))?;
+                match bytes.is_empty() {
+                    true => Ok(decoded),
+                    false => Err(Error::InvalidEncoding),
+                }
+            }
+        }
+
+        impl Encode for $name$(<$engine>)? {
+            fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+                self.write(&mut Writer::new(dest, true))
+            }
+        }
+
+        impl Decode for $name$(<$engine>)? {
+            fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+                Self::read(&mut Reader::new(input, true))
+                    .map_err(|_| concat!("invalid ", stringify!($name)).into())
+            }
+        }
+
+        impl Serialize for $name$(<$engine>)? {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(&self.to_bytes())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name$(<$engine>)? {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+                Self::from_bytes(&bytes)
+                    .map_err(|_| de::Error::custom(concat!("invalid ", stringify!($name))))
+            }
+        }
+    };
+}
+
+canonical_encoding!(Proof<Bn254>);
+canonical_encoding!(VerifyingKey<Bn254>);
+canonical_encoding!(Parameters<Bn254>);
+canonical_encoding!(PublicKey);
+canonical_encoding!(MpcParameters);
+canonical_encoding!(AggregationKey);
+canonical_encoding!(AggregationVerifyingKey);
+canonical_encoding!(AggregateProof);
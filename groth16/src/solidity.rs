@@ -0,0 +1,401 @@
+//! Verifier contract for EVM chains using the BN254 precompiles.
+//!
+//! The contract exposes `verifyProof(uint256[2],uint256[2][2],uint256[2],uint256[])`, where the
+//! points are encoded as in EIP-196 and EIP-197 and the public inputs are canonical scalars.
+//! It returns false for an invalid proof and reverts only for an unknown selector or a call
+//! with value. The verification equation is checked with a single call to the pairing
+//! precompile, with alpha, gamma and delta negated when the contract is generated:
+//!
+//! e(A, B) * e(-alpha, beta) * e(ic, -gamma) * e(C, -delta) = 1
+//!
+//! `VerifyingKey::solidity_verifier` returns the Solidity source, to be compiled with `solc`
+//! 0.8 and deployed as is.
+
+use crate::proof::Proof;
+use crate::verifier::VerifyingKey;
+
+use bn_254::{Bn254, Fr, G1Affine, G2Affine};
+use zkstd::common::{SigUtils, Vec};
+
+/// Selector of `verifyProof(uint256[2],uint256[2][2],uint256[2],uint256[])`
+const SELECTOR: [u8; 4] = [0xc3, 0x2e, 0x37, 0x0e];
+
+/// Order of the scalar field, public inputs must be below it
+const R: &str = "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+
+/// Memory layout: the product of `ecMul` at 0x00, the sum of the public inputs at 0x40,
+/// the input of `ecMul` at 0x80 and the four pairs of the pairing check at 0x100
+const SUM: u16 = 0x40;
+const MUL_INPUT: u16 = 0x80;
+const PAIRS: u16 = 0x100;
+const PAIR_SIZE: u16 = 0xc0;
+
+impl VerifyingKey<Bn254> {
+    /// Solidity source of a contract verifying proofs with this key
+    pub fn solidity_verifier(&self) -> String {
+        let mut constants = String::new();
+        let mut constant = |name: &str, words: &[[u8; 32]]| {
+            let suffixes: &[&str] = match words.len() {
+                2 => &["X", "Y"],
+                _ => &["X1", "X0", "Y1", "Y0"],
+            };
+            for (suffix, word) in suffixes.iter().zip(words) {
+                constants.push_str(&format!(
+                    "    uint256 constant {}_{} = {};\n",
+                    name,
+                    suffix,
+                    hex(word)
+                ));
+            }
+        };
+        constant("NEG_ALPHA", &g1_words(&-self.alpha_g1));
+        constant("BETA", &g2_words(&self.beta_g2));
+        constant("NEG_GAMMA", &g2_words(&-self.gamma_g2));
+        constant("NEG_DELTA", &g2_words(&-self.delta_g2));
+        for (i, ic) in self.ic.iter().enumerate() {
+            constant(&format!("IC{}", i), &g1_words(ic));
+        }
+
+        let mut inputs = String::new();
+        for i in 1..self.ic.len() {
+            inputs.push_str(&format!(
+                "
+            // sum += input[{i}] * ic[{next}]
+            mstore({mul_input:#x}, IC{next}_X)
+            mstore({mul_input_y:#x}, IC{next}_Y)
+            mstore({scalar:#x}, calldataload(add(input.offset, {offset:#x})))
+            if iszero(lt(mload({scalar:#x}), R)) {{ fail() }}
+            if iszero(staticcall(gas(), 0x07, {mul_input:#x}, 0x60, 0x00, 0x40)) {{ fail() }}
+            if iszero(staticcall(gas(), 0x06, 0x00, 0x80, {sum:#x}, 0x40)) {{ fail() }}
+",
+                i = i - 1,
+                next = i,
+                mul_input = MUL_INPUT,
+                mul_input_y = MUL_INPUT + 0x20,
+                scalar = MUL_INPUT + 0x40,
+                offset = (i - 1) * 0x20,
+                sum = SUM,
+            ));
+        }
+
+        format!(
+            "// SPDX-License-Identifier: Apache-2.0
+pragma solidity ^0.8.0;
+
+/// @notice Groth16 verifier over BN254 generated by zkgroth16
+contract Groth16Verifier {{
+    uint256 constant R = 0x{r};
+
+{constants}
+    function verifyProof(
+        uint256[2] calldata a,
+        uint256[2][2] calldata b,
+        uint256[2] calldata c,
+        uint256[] calldata input
+    ) external view returns (bool) {{
+        assembly {{
+            function fail() {{
+                mstore(0x00, 0)
+                return(0x00, 0x20)
+            }}
+
+            if iszero(eq(input.length, {len})) {{ fail() }}
+
+            // sum = ic[0]
+            mstore({sum:#x}, IC0_X)
+            mstore({sum_y:#x}, IC0_Y)
+{inputs}
+            // e(A, B) * e(-alpha, beta) * e(sum, -gamma) * e(C, -delta) = 1
+            calldatacopy({pair0:#x}, a, 0x40)
+            calldatacopy({pair0_g2:#x}, b, 0x80)
+            mstore({pair1:#x}, NEG_ALPHA_X)
+            mstore({pair1_y:#x}, NEG_ALPHA_Y)
+            mstore({pair1_g2:#x}, BETA_X1)
+            mstore({pair1_g2_1:#x}, BETA_X0)
+            mstore({pair1_g2_2:#x}, BETA_Y1)
+            mstore({pair1_g2_3:#x}, BETA_Y0)
+            mstore({pair2:#x}, mload({sum:#x}))
+            mstore({pair2_y:#x}, mload({sum_y:#x}))
+            mstore({pair2_g2:#x}, NEG_GAMMA_X1)
+            mstore({pair2_g2_1:#x}, NEG_GAMMA_X0)
+            mstore({pair2_g2_2:#x}, NEG_GAMMA_Y1)
+            mstore({pair2_g2_3:#x}, NEG_GAMMA_Y0)
+            calldatacopy({pair3:#x}, c, 0x40)
+            mstore({pair3_g2:#x}, NEG_DELTA_X1)
+            mstore({pair3_g2_1:#x}, NEG_DELTA_X0)
+            mstore({pair3_g2_2:#x}, NEG_DELTA_Y1)
+            mstore({pair3_g2_3:#x}, NEG_DELTA_Y0)
+
+            let success := staticcall(gas(), 0x08, {pair0:#x}, {pairs_len:#x}, 0x00, 0x20)
+            mstore(0x00, and(success, mload(0x00)))
+            return(0x00, 0x20)
+        }}
+    }}
+}}
+",
+            r = R,
+            constants = constants,
+            len = self.ic.len() - 1,
+            inputs = inputs,
+            sum = SUM,
+            sum_y = SUM + 0x20,
+            pair0 = pair(0, 0),
+            pair0_g2 = pair(0, 0x40),
+            pair1 = pair(1, 0),
+            pair1_y = pair(1, 0x20),
+            pair1_g2 = pair(1, 0x40),
+            pair1_g2_1 = pair(1, 0x60),
+            pair1_g2_2 = pair(1, 0x80),
+            pair1_g2_3 = pair(1, 0xa0),
+            pair2 = pair(2, 0),
+            pair2_y = pair(2, 0x20),
+            pair2_g2 = pair(2, 0x40),
+            pair2_g2_1 = pair(2, 0x60),
+            pair2_g2_2 = pair(2, 0x80),
+            pair2_g2_3 = pair(2, 0xa0),
+            pair3 = pair(3, 0),
+            pair3_g2 = pair(3, 0x40),
+            pair3_g2_1 = pair(3, 0x60),
+            pair3_g2_2 = pair(3, 0x80),
+            pair3_g2_3 = pair(3, 0xa0),
+            pairs_len = 4 * PAIR_SIZE,
+        )
+    }
+}
+
+impl Proof<Bn254> {
+    /// Calldata of `verifyProof` on the contract generated from the verifying key
+    pub fn to_calldata(&self, public_inputs: &[Fr]) -> Vec<u8> {
+        let mut calldata = Vec::with_capacity(4 + 0x140 + public_inputs.len() * 0x20);
+        calldata.extend_from_slice(&SELECTOR);
+        calldata.extend_from_slice(&self.a.to_uncompressed());
+        calldata.extend_from_slice(&self.b.to_uncompressed());
+        calldata.extend_from_slice(&self.c.to_uncompressed());
+
+        // offset and length of the public inputs
+        calldata.extend_from_slice(&u256(0x120));
+        calldata.extend_from_slice(&u256(public_inputs.len()));
+        for input in public_inputs {
+            let mut bytes = input.to_bytes();
+            bytes.reverse();
+            calldata.extend_from_slice(&bytes);
+        }
+        calldata
+    }
+}
+
+/// Memory offset of a field of a pair of the pairing check
+fn pair(index: u16, offset: u16) -> u16 {
+    PAIRS + index * PAIR_SIZE + offset
+}
+
+fn g1_words(point: &G1Affine) -> Vec<[u8; 32]> {
+    words(&point.to_uncompressed())
+}
+
+fn g2_words(point: &G2Affine) -> Vec<[u8; 32]> {
+    words(&point.to_uncompressed())
+}
+
+fn words(bytes: &[u8]) -> Vec<[u8; 32]> {
+    bytes
+        .chunks_exact(32)
+        .map(|chunk| chunk.try_into().unwrap())
+        .collect()
+}
+
+fn u256(value: usize) -> [u8; 32] {
+    let mut word = [0; 32];
+    word[24..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let digits = bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+    format!("0x{}", digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::DummyCircuit;
+    use crate::zksnark::ZkSnark;
+
+    use revm::{return_ok, ExecutionResult, InMemoryDB, Return, TransactOut, TransactTo, EVM};
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+    use zkstd::common::{Group, OsRng};
+
+    fn unhex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Runs `solc` on the source and returns the lines following `header`
+    fn solc(source: &str, args: &[&str], header: &str) -> Vec<String> {
+        let mut solc = Command::new("solc")
+            .args(args)
+            .arg("-")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("Failed to run solc");
+        solc.stdin
+            .take()
+            .unwrap()
+            .write_all(source.as_bytes())
+            .unwrap();
+        let output = solc.wait_with_output().unwrap();
+        assert!(output.status.success(), "Failed to compile the verifier");
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .lines()
+            .skip_while(|line| !line.starts_with(header))
+            .skip(1)
+            .take_while(|line| !line.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    fn transact(evm: &mut EVM<InMemoryDB>, to: TransactTo, data: Vec<u8>) -> ExecutionResult {
+        evm.env.tx.transact_to = to;
+        evm.env.tx.data = data.into();
+        evm.env.tx.gas_limit = 10_000_000;
+        evm.transact_commit()
+    }
+
+    fn returned(result: ExecutionResult) -> Option<bool> {
+        match (result.exit_reason, result.out) {
+            (return_ok!(), TransactOut::Call(output)) => Some(output[..] == u256(1)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn calldata_test() {
+        // generators of EIP-197, the G2 coordinates are encoded imaginary part first
+        let proof = Proof::<Bn254> {
+            a: G1Affine::ADDITIVE_GENERATOR,
+            b: G2Affine::ADDITIVE_GENERATOR,
+            c: -G1Affine::ADDITIVE_GENERATOR,
+        };
+        let expected = [
+            "c32e370e",
+            // a = (1, 2)
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            // b
+            "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+            "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+            "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+            "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            // c = (1, q - 2)
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45",
+            // offset and length of the public inputs
+            "0000000000000000000000000000000000000000000000000000000000000120",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            // 3 and r - 1
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000",
+        ]
+        .concat();
+
+        let calldata = proof.to_calldata(&[Fr::from(3), -Fr::one()]);
+        assert_eq!(hex(&calldata), format!("0x{}", expected));
+        assert_eq!(calldata.len(), 4 + 0x140 + 2 * 0x20);
+    }
+
+    // requires `solc` 0.8 in the path, run with `cargo test -- --ignored`.
+    // The contract embeds a verifying key sampled on every run, so there is no fixed bytecode
+    // to check in, and `solc` is not a crate that cargo could build for the default test run.
+    #[test]
+    #[ignore]
+    fn solidity_verifier_test() {
+        let x = Fr::from(3);
+        let o = Fr::from(35);
+        let (mut prover, _) =
+            ZkSnark::<Bn254>::setup::<DummyCircuit>(OsRng).expect("Failed to compile circuit");
+        let proof = prover
+            .create_proof(&mut OsRng, DummyCircuit::new(x, o))
+            .expect("Failed to prove");
+
+        let source = prover.params.vk.solidity_verifier();
+        let hashes = solc(&source, &["--hashes"], "Function signatures:");
+        assert_eq!(
+            hashes,
+            [format!(
+                "{}: verifyProof(uint256[2],uint256[2][2],uint256[2],uint256[])",
+                &hex(&SELECTOR)[2..]
+            )]
+        );
+        let code = solc(&source, &["--bin", "--evm-version", "london"], "Binary:");
+        let code = unhex(&code[0]);
+
+        // deploy the contract in an in-memory chain
+        let mut evm = revm::new();
+        evm.database(InMemoryDB::default());
+        let verifier = match transact(&mut evm, TransactTo::create(), code) {
+            ExecutionResult {
+                exit_reason: return_ok!(),
+                out: TransactOut::Create(_, Some(address)),
+                ..
+            } => address,
+            result => panic!("Failed to deploy the verifier: {:?}", result),
+        };
+        let call = TransactTo::Call(verifier);
+
+        let calldata = proof.to_calldata(&[x, o]);
+        assert_eq!(
+            returned(transact(&mut evm, call.clone(), calldata.clone())),
+            Some(true)
+        );
+
+        // wrong public inputs, scalars above the modulus and a wrong number of inputs
+        let wrong = proof.to_calldata(&[x, o + Fr::one()]);
+        assert_eq!(
+            returned(transact(&mut evm, call.clone(), wrong)),
+            Some(false)
+        );
+        let mut above = calldata.clone();
+        above[4 + 0x160..].copy_from_slice(&unhex(R));
+        assert_eq!(
+            returned(transact(&mut evm, call.clone(), above)),
+            Some(false)
+        );
+        let short = proof.to_calldata(&[x]);
+        assert_eq!(
+            returned(transact(&mut evm, call.clone(), short)),
+            Some(false)
+        );
+
+        // points off the curve are rejected by the precompiles
+        let mut invalid = calldata.clone();
+        invalid[4 + 0x3f] ^= 1;
+        assert_eq!(
+            returned(transact(&mut evm, call.clone(), invalid)),
+            Some(false)
+        );
+
+        // another proof of the same statement
+        let other = prover
+            .create_proof(&mut OsRng, DummyCircuit::new(x, o))
+            .expect("Failed to prove");
+        let calldata = other.to_calldata(&[x, o]);
+        assert_eq!(
+            returned(transact(&mut evm, call.clone(), calldata.clone())),
+            Some(true)
+        );
+
+        // unknown selectors revert
+        let mut unknown = calldata;
+        unknown[0] ^= 1;
+        assert_eq!(returned(transact(&mut evm, call, unknown)), None);
+    }
+}
@@ -1,75 +1,155 @@
 use crate::error::Error;
 use crate::proof::Proof;
 
-use bn_254::{AteParing, Fr, G1Affine, G2Affine, G2PairingAffine, Gt};
-use zkstd::common::Vec;
+use zkstd::common::{BNAffine, BNProjective, Group, IntGroup, PairingEngine, Ring, RngCore, Vec};
 
 // Verify proofs of a given circuit
-pub struct Verifier {
-    pub(crate) vk: PreparedVerifyingKey,
+pub struct Verifier<P: PairingEngine> {
+    pub(crate) vk: PreparedVerifyingKey<P>,
 }
 
-impl Verifier {
+impl<P: PairingEngine> Verifier<P> {
+    /// Prepare a verifier from a key received from the setup
+    pub fn from_verifying_key(vk: &VerifyingKey<P>) -> Self {
+        Self { vk: vk.prepare() }
+    }
+
     /// Verify a generated proof
-    pub fn verify(&self, proof: &Proof, public_inputs: &[Fr]) -> Result<(), Error> {
+    pub fn verify(&self, proof: &Proof<P>, public_inputs: &[P::Fr]) -> Result<(), Error> {
         proof.verify(&self.vk, public_inputs)
     }
+
+    /// Verify many proofs of the circuit with a single multi Miller loop and final
+    /// exponentiation. The verification equations are combined with random scalars,
+    /// so that a batch containing an invalid proof passes with negligible probability.
+    /// If the batch fails, the proofs are verified one by one to find the first invalid one,
+    /// and `InconsistentBatchVerification` is returned when all of them are valid.
+    pub fn verify_batch<R: RngCore>(
+        &self,
+        proofs: &[(Proof<P>, Vec<P::Fr>)],
+        rng: &mut R,
+    ) -> Result<(), Error> {
+        if proofs.is_empty() || self.batch_holds(proofs, rng) {
+            return Ok(());
+        }
+
+        match proofs
+            .iter()
+            .position(|(proof, public_inputs)| self.verify(proof, public_inputs).is_err())
+        {
+            Some(index) => Err(Error::BatchVerificationError { index }),
+            None => Err(Error::InconsistentBatchVerification),
+        }
+    }
+
+    fn batch_holds<R: RngCore>(&self, proofs: &[(Proof<P>, Vec<P::Fr>)], rng: &mut R) -> bool {
+        // sum r_i (A_i * B_i - inputs_i * gamma - C_i * delta - alpha * beta) = 0
+        let mut pairs = Vec::with_capacity(proofs.len() + 3);
+        let mut inputs = P::G1Projective::ADDITIVE_IDENTITY;
+        let mut c = P::G1Projective::ADDITIVE_IDENTITY;
+        let mut sum = P::Fr::zero();
+        for (i, (proof, public_inputs)) in proofs.iter().enumerate() {
+            let acc = match self.vk.prepare_inputs(public_inputs) {
+                Ok(acc) => acc,
+                Err(_) => return false,
+            };
+            // the first scalar can be one without loss of soundness
+            let r = if i == 0 {
+                P::Fr::one()
+            } else {
+                P::Fr::random(&mut *rng)
+            };
+            pairs.push(((proof.a * r).to_affine(), P::G2Prepared::from(proof.b)));
+            inputs += acc * r;
+            c += proof.c * r;
+            sum += r;
+        }
+        pairs.push((inputs.to_affine(), self.vk.neg_gamma_g2.clone()));
+        pairs.push((c.to_affine(), self.vk.neg_delta_g2.clone()));
+        pairs.push((
+            (self.vk.neg_alpha_g1 * sum).to_affine(),
+            self.vk.beta_g2.clone(),
+        ));
+
+        P::final_exponentiation(P::multi_miller_loop(&pairs)) == P::Gt::ADDITIVE_IDENTITY
+    }
 }
 
 #[derive(Clone, Debug)]
-pub struct VerifyingKey {
+pub struct VerifyingKey<P: PairingEngine> {
     // alpha in g1 for verifying and for creating A/C elements of
     // proof. Never the point at infinity.
-    pub alpha_g1: G1Affine,
+    pub alpha_g1: P::G1Affine,
 
     // beta in g1 and g2 for verifying and for creating B/C elements
     // of proof. Never the point at infinity.
-    pub beta_g1: G1Affine,
-    pub beta_g2: G2Affine,
+    pub beta_g1: P::G1Affine,
+    pub beta_g2: P::G2Affine,
 
     // gamma in g2 for verifying. Never the point at infinity.
-    pub gamma_g2: G2Affine,
+    pub gamma_g2: P::G2Affine,
 
     // delta in g1/g2 for verifying and proving, essentially the magic
     // trapdoor that forces the prover to evaluate the C element of the
     // proof with only components from the CRS. Never the point at
     // infinity.
-    pub delta_g1: G1Affine,
-    pub delta_g2: G2Affine,
+    pub delta_g1: P::G1Affine,
+    pub delta_g2: P::G2Affine,
 
     // Elements of the form (beta * u_i(tau) + alpha v_i(tau) + w_i(tau)) / gamma
     // for all public inputs. Because all public inputs have a dummy constraint,
     // this is the same size as the number of inputs, and never contains points
     // at infinity.
-    pub ic: Vec<G1Affine>,
+    pub ic: Vec<P::G1Affine>,
 }
 
-impl VerifyingKey {
-    pub(crate) fn prepare(&self) -> PreparedVerifyingKey {
+impl<P: PairingEngine> VerifyingKey<P> {
+    pub(crate) fn prepare(&self) -> PreparedVerifyingKey<P> {
         let gamma = -self.gamma_g2;
         let delta = -self.delta_g2;
 
         PreparedVerifyingKey {
-            alpha_g1_beta_g2: AteParing::multi_miller_loop(&[(
-                self.alpha_g1,
-                G2PairingAffine::from(self.beta_g2),
-            )])
-            .final_exp(),
-            neg_gamma_g2: G2PairingAffine::from(gamma),
-            neg_delta_g2: G2PairingAffine::from(delta),
+            alpha_g1_beta_g2: P::pairing(self.alpha_g1, self.beta_g2),
+            neg_alpha_g1: -self.alpha_g1,
+            beta_g2: P::G2Prepared::from(self.beta_g2),
+            neg_gamma_g2: P::G2Prepared::from(gamma),
+            neg_delta_g2: P::G2Prepared::from(delta),
             ic: self.ic.clone(),
         }
     }
 }
 
 #[derive(Debug)]
-pub struct PreparedVerifyingKey {
+pub struct PreparedVerifyingKey<P: PairingEngine> {
     /// Pairing result of alpha*beta
-    pub(crate) alpha_g1_beta_g2: Gt,
+    pub(crate) alpha_g1_beta_g2: P::Gt,
+    /// -alpha in G1 for batch verification
+    pub(crate) neg_alpha_g1: P::G1Affine,
+    /// beta in G2 for batch verification
+    pub(crate) beta_g2: P::G2Prepared,
     /// -gamma in G2
-    pub(crate) neg_gamma_g2: G2PairingAffine,
+    pub(crate) neg_gamma_g2: P::G2Prepared,
     /// -delta in G2
-    pub(crate) neg_delta_g2: G2PairingAffine,
+    pub(crate) neg_delta_g2: P::G2Prepared,
     /// Copy of IC from `VerifiyingKey`.
-    pub(crate) ic: Vec<G1Affine>,
+    pub(crate) ic: Vec<P::G1Affine>,
+}
+
+impl<P: PairingEngine> PreparedVerifyingKey<P> {
+    /// Combine the public inputs with the IC elements
+    pub(crate) fn prepare_inputs(&self, public_inputs: &[P::Fr]) -> Result<P::G1Projective, Error> {
+        if (public_inputs.len() + 1) != self.ic.len() {
+            return Err(Error::InconsistentPublicInputsLen {
+                expected: self.ic.len() - 1,
+                provided: public_inputs.len(),
+            });
+        }
+        let mut acc = self.ic[0].to_extended();
+
+        for (&i, &b) in public_inputs.iter().zip(self.ic.iter().skip(1)) {
+            acc += b * i;
+        }
+
+        Ok(acc)
+    }
 }
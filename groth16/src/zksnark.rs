@@ -1,20 +1,55 @@
 use crate::circuit::Circuit;
 use crate::error::Error;
 use crate::fft::Fft;
-use crate::params::Parameters;
+use crate::mpc::MpcParameters;
+use crate::params::{ConstraintMatrices, Parameters};
 use crate::poly::{Coefficients, PointsValue};
 use crate::prover::Prover;
+use crate::ptau::PowersOfTau;
 use crate::verifier::{Verifier, VerifyingKey};
 
-use bn_254::{Fr, G1Affine, G2Affine};
-use zkstd::common::{vec, Group, MulAssign, PrimeField, RngCore, Vec};
+use bn_254::Bn254;
+use core::marker::PhantomData;
+use zkstd::circuit::CircuitDriver;
+use zkstd::common::{
+    vec, Group, IntGroup, MulAssign, PairingEngine, PrimeField, Ring, RngCore, Vec,
+};
 use zkstd::r1cs::R1cs;
 
-/// Generate the arguments to prove and verify a circuit
-pub struct ZkSnark {}
+/// Generate the arguments to prove and verify a circuit over the pairing engine
+pub struct ZkSnark<P: PairingEngine>(PhantomData<P>);
 
-impl ZkSnark {
-    pub fn setup<C: Circuit>(mut r: impl RngCore) -> Result<(Prover, Verifier), Error> {
+impl<P: PairingEngine> ZkSnark<P> {
+    pub fn setup<C>(mut r: impl RngCore) -> Result<(Prover<P>, Verifier<P>), Error>
+    where
+        C: Circuit,
+        C::Driver: CircuitDriver<Scalar = P::Fr>,
+    {
+        // toxic waste
+        let alpha = P::Fr::random(&mut r);
+        let beta = P::Fr::random(&mut r);
+        let gamma = P::Fr::random(&mut r);
+        let delta = P::Fr::random(&mut r);
+        let tau = P::Fr::random(&mut r);
+
+        let params = Self::parameters::<C>(alpha, beta, gamma, delta, tau)?;
+        let pvk = params.vk.prepare();
+
+        Ok((Prover { params }, Verifier { vk: pvk }))
+    }
+
+    /// Evaluate the circuit at the trapdoors
+    pub(crate) fn parameters<C>(
+        alpha: P::Fr,
+        beta: P::Fr,
+        gamma: P::Fr,
+        delta: P::Fr,
+        tau: P::Fr,
+    ) -> Result<Parameters<P>, Error>
+    where
+        C: Circuit,
+        C::Driver: CircuitDriver<Scalar = P::Fr>,
+    {
         let circuit = C::default();
         let mut cs = R1cs::default();
 
@@ -22,26 +57,20 @@ impl ZkSnark {
 
         let size = cs.m().next_power_of_two();
         let k = size.trailing_zeros();
-        let fft = Fft::<Fr>::new(k as usize);
+        let fft = Fft::<P::Fr>::new(k as usize);
 
-        // toxic waste
-        let alpha = Fr::random(&mut r);
-        let beta = Fr::random(&mut r);
-        let gamma = Fr::random(&mut r);
-        let delta = Fr::random(&mut r);
-        let tau = Fr::random(&mut r);
-
-        let g1 = G1Affine::ADDITIVE_GENERATOR;
-        let g2 = G2Affine::ADDITIVE_GENERATOR;
+        let g1 = P::G1Affine::ADDITIVE_GENERATOR;
+        let g2 = P::G2Affine::ADDITIVE_GENERATOR;
 
         let gamma_inverse = gamma.invert().ok_or(Error::ProverInversionFailed)?;
         let delta_inverse = delta.invert().ok_or(Error::ProverInversionFailed)?;
 
-        let mut h = vec![G1Affine::ADDITIVE_IDENTITY; (cs.m() - 1) as usize];
+        // h(x) = (a(x) * b(x) - c(x)) / t(x) over the padded domain has degree size - 2
+        let mut h = vec![P::G1Affine::ADDITIVE_IDENTITY; (size - 1) as usize];
 
-        // Compute (1, tau, tau^2, ...)
-        let mut powers_of_tau = PointsValue(vec![Fr::zero(); cs.m() as usize]);
-        let mut current_pow_of_tau = Fr::one();
+        // Compute (1, tau, tau^2, ...) over the whole domain
+        let mut powers_of_tau = PointsValue(vec![P::Fr::zero(); size as usize]);
+        let mut current_pow_of_tau = P::Fr::one();
         for x in powers_of_tau.0.iter_mut() {
             *x = current_pow_of_tau;
             current_pow_of_tau *= tau;
@@ -59,17 +88,17 @@ impl ZkSnark {
         // Use inverse FFT to convert powers of tau to Lagrange coefficients
         let powers_of_tau = fft.idft(powers_of_tau);
 
-        let mut a = vec![G1Affine::ADDITIVE_IDENTITY; cs.l() + cs.m_l_1()];
-        let mut b_g1 = vec![G1Affine::ADDITIVE_IDENTITY; cs.l() + cs.m_l_1()];
-        let mut b_g2: Vec<G2Affine> = vec![G2Affine::ADDITIVE_IDENTITY; cs.l() + cs.m_l_1()];
-        let mut ic = vec![G1Affine::ADDITIVE_IDENTITY; cs.l()];
-        let mut l = vec![G1Affine::ADDITIVE_IDENTITY; cs.m_l_1()];
+        let mut a = vec![P::G1Affine::ADDITIVE_IDENTITY; cs.l() + cs.m_l_1()];
+        let mut b_g1 = vec![P::G1Affine::ADDITIVE_IDENTITY; cs.l() + cs.m_l_1()];
+        let mut b_g2: Vec<P::G2Affine> = vec![P::G2Affine::ADDITIVE_IDENTITY; cs.l() + cs.m_l_1()];
+        let mut ic = vec![P::G1Affine::ADDITIVE_IDENTITY; cs.l()];
+        let mut l = vec![P::G1Affine::ADDITIVE_IDENTITY; cs.m_l_1()];
 
         let ((at_inputs, bt_inputs, ct_inputs), (at_aux, bt_aux, ct_aux)) =
             cs.z_vectors(cs.l(), cs.m_l_1());
 
         // Evaluate for inputs.
-        eval(
+        eval::<P>(
             g1,
             g2,
             &powers_of_tau,
@@ -86,7 +115,7 @@ impl ZkSnark {
         );
 
         // Evaluate for auxiliary variables.
-        eval(
+        eval::<P>(
             g1,
             g2,
             &powers_of_tau,
@@ -103,55 +132,71 @@ impl ZkSnark {
         );
 
         let vk = VerifyingKey {
-            alpha_g1: (G1Affine::ADDITIVE_GENERATOR * alpha).into(),
-            beta_g1: (G1Affine::ADDITIVE_GENERATOR * beta).into(),
-            beta_g2: (G2Affine::ADDITIVE_GENERATOR * beta).into(),
-            gamma_g2: (G2Affine::ADDITIVE_GENERATOR * gamma).into(),
-            delta_g1: (G1Affine::ADDITIVE_GENERATOR * delta).into(),
-            delta_g2: (G2Affine::ADDITIVE_GENERATOR * delta).into(),
+            alpha_g1: (g1 * alpha).into(),
+            beta_g1: (g1 * beta).into(),
+            beta_g2: (g2 * beta).into(),
+            gamma_g2: (g2 * gamma).into(),
+            delta_g1: (g1 * delta).into(),
+            delta_g2: (g2 * delta).into(),
             ic,
         };
 
-        let params = Parameters {
+        Ok(Parameters {
             vk,
             h,
             l,
             a,
             b_g1,
             b_g2,
-        };
+            matrices: ConstraintMatrices::new(&cs),
+        })
+    }
+}
 
-        let pvk = params.vk.prepare();
+impl ZkSnark<Bn254> {
+    /// Generate the arguments from the powers of tau of a phase 1 ceremony, so that only
+    /// `delta` is sampled here. `MpcParameters::from_powers_of_tau` lets several parties
+    /// sample it instead.
+    pub fn setup_with_powers_of_tau<C>(
+        powers: &PowersOfTau,
+        mut r: impl RngCore,
+    ) -> Result<(Prover<Bn254>, Verifier<Bn254>), Error>
+    where
+        C: Circuit,
+        C::Driver: CircuitDriver<Scalar = <Bn254 as PairingEngine>::Fr>,
+    {
+        let mut params = MpcParameters::from_powers_of_tau::<C>(powers)?;
+        params.contribute(&mut r);
 
-        Ok((Prover { params }, Verifier { vk: pvk }))
+        Ok(params.keys())
     }
 }
 
 #[allow(clippy::too_many_arguments)]
-fn eval(
-    g1: G1Affine,
-    g2: G2Affine,
+fn eval<P: PairingEngine>(
+    g1: P::G1Affine,
+    g2: P::G2Affine,
 
     // Lagrange coefficients for tau
-    powers_of_tau: &Coefficients<Fr>,
+    powers_of_tau: &Coefficients<P::Fr>,
 
     // QAP polynomials
-    at: &[Vec<(Fr, usize)>],
-    bt: &[Vec<(Fr, usize)>],
-    ct: &[Vec<(Fr, usize)>],
+    at: &[Vec<(P::Fr, usize)>],
+    bt: &[Vec<(P::Fr, usize)>],
+    ct: &[Vec<(P::Fr, usize)>],
 
     // Resulting evaluated QAP polynomials
-    a: &mut [G1Affine],
-    b_g1: &mut [G1Affine],
-    b_g2: &mut [G2Affine],
-    ext: &mut [G1Affine],
+    a: &mut [P::G1Affine],
+    b_g1: &mut [P::G1Affine],
+    b_g2: &mut [P::G2Affine],
+    ext: &mut [P::G1Affine],
 
     // Inverse coefficient for ext elements
-    inv: &Fr,
+    inv: &P::Fr,
 
     // Trapdoors
-    alpha: &Fr,
-    beta: &Fr,
+    alpha: &P::Fr,
+    beta: &P::Fr,
 ) {
     assert_eq!(a.len(), at.len());
     assert_eq!(a.len(), bt.len());
@@ -4,15 +4,20 @@ use crate::function::FunctionCircuit;
 use crate::gadget::{R1csInstanceAssignment, RelaxedR1csInstanceAssignment};
 use crate::hash::{CHALLENGE_BITS, MIMC_ROUNDS};
 use crate::relaxed_r1cs::{R1csInstance, RelaxedR1csInstance};
-use std::marker::PhantomData;
 use zkstd::circuit::prelude::{FieldAssignment, PointAssignment};
 use zkstd::circuit::CircuitDriver;
 use zkstd::common::{CurveGroup, Group, IntGroup, Ring};
 use zkstd::matrix::DenseVectors;
 use zkstd::r1cs::R1cs;
 
+/// Step circuit folding the previous instance, the commitments are folded with the
+/// incomplete affine formulas when `AFFINE` is set
 #[derive(Debug, Clone)]
-pub struct AugmentedFCircuit<C: CircuitDriver, FC: FunctionCircuit<C::Base>> {
+pub struct AugmentedFCircuit<
+    C: CircuitDriver,
+    FC: FunctionCircuit<C::Base>,
+    const AFFINE: bool = false,
+> {
     pub is_primary: bool,
     pub i: u64,
     pub z_0: DenseVectors<C::Base>,
@@ -20,16 +25,18 @@ pub struct AugmentedFCircuit<C: CircuitDriver, FC: FunctionCircuit<C::Base>> {
     pub u_single: Option<R1csInstance<C>>,
     pub u_range: Option<RelaxedR1csInstance<C>>,
     pub commit_t: Option<C::Affine>,
-    pub f: PhantomData<FC>,
+    pub f: FC,
 }
 
-impl<C: CircuitDriver, FC: FunctionCircuit<C::Base>> Default for AugmentedFCircuit<C, FC> {
+impl<C: CircuitDriver, FC: FunctionCircuit<C::Base>, const AFFINE: bool> Default
+    for AugmentedFCircuit<C, FC, AFFINE>
+{
     fn default() -> Self {
         Self {
             is_primary: true,
             i: 0,
-            z_0: DenseVectors::zero(1),
-            z_i: Some(DenseVectors::zero(1)),
+            z_0: DenseVectors::zero(FC::ARITY),
+            z_i: Some(DenseVectors::zero(FC::ARITY)),
             u_single: Some(R1csInstance::dummy(2)),
             u_range: Some(RelaxedR1csInstance::dummy(2)),
             commit_t: Some(C::Affine::ADDITIVE_IDENTITY),
@@ -38,7 +45,9 @@ impl<C: CircuitDriver, FC: FunctionCircuit<C::Base>> Default for AugmentedFCircu
     }
 }
 
-impl<C: CircuitDriver, FC: FunctionCircuit<C::Base>> AugmentedFCircuit<C, FC> {
+impl<C: CircuitDriver, FC: FunctionCircuit<C::Base>, const AFFINE: bool>
+    AugmentedFCircuit<C, FC, AFFINE>
+{
     pub(crate) fn generate<CS: CircuitDriver<Scalar = C::Base>>(
         &self,
         cs: &mut R1cs<CS>,
@@ -100,7 +109,7 @@ impl<C: CircuitDriver, FC: FunctionCircuit<C::Base>> AugmentedFCircuit<C, FC> {
 
         let r = Self::get_challenge(cs, &u_range, commit_t.clone());
         let u_range_next_non_base =
-            NifsCircuit::verify(cs, r, u_range.clone(), u_single.clone(), commit_t);
+            NifsCircuit::<C, AFFINE>::verify(cs, r, u_range.clone(), u_single.clone(), commit_t);
 
         let u_range_next = RelaxedR1csInstanceAssignment::conditional_select(
             cs,
@@ -109,7 +118,7 @@ impl<C: CircuitDriver, FC: FunctionCircuit<C::Base>> AugmentedFCircuit<C, FC> {
             &base_case,
         );
 
-        let z_next = FC::invoke_cs(cs, z_i);
+        let z_next = self.f.invoke_cs_with(cs, z_i);
 
         let u_next_x = u_range_next.hash(
             cs,
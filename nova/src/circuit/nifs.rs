@@ -1,17 +1,20 @@
 use core::marker::PhantomData;
-use num_bigint::BigInt;
-use num_traits::Num;
 
-use crate::gadget::{f_to_nat, BigNatAssignment, BN_LIMB_WIDTH, BN_N_LIMBS};
 use crate::gadget::{R1csInstanceAssignment, RelaxedR1csInstanceAssignment};
-use zkstd::circuit::prelude::{CircuitDriver, FieldAssignment, PointAssignment, R1cs};
+use crate::hash::CHALLENGE_BITS;
+use zkstd::circuit::prelude::{
+    AffinePointAssignment, BinaryAssignment, CircuitDriver, FieldAssignment,
+    NonNativeFieldAssignment, PointAssignment, R1cs,
+};
 use zkstd::common::{Group, IntGroup};
 
-pub(crate) struct NifsCircuit<C: CircuitDriver> {
+/// Folding verifier circuit, the commitments are multiplied by the challenge with
+/// the incomplete affine formulas when `AFFINE` is set
+pub(crate) struct NifsCircuit<C: CircuitDriver, const AFFINE: bool = false> {
     p: PhantomData<C>,
 }
 
-impl<C: CircuitDriver> NifsCircuit<C> {
+impl<C: CircuitDriver, const AFFINE: bool> NifsCircuit<C, AFFINE> {
     pub(crate) fn verify<CS: CircuitDriver<Scalar = C::Base>>(
         cs: &mut R1cs<CS>,
         r: FieldAssignment<C::Base>,
@@ -20,7 +23,7 @@ impl<C: CircuitDriver> NifsCircuit<C> {
         commit_t: PointAssignment<C::Base>,
     ) -> RelaxedR1csInstanceAssignment<C> {
         // W_fold = U.W + r * u.W
-        let r_w = u_single.commit_w.scalar_point(cs, &r);
+        let r_w = Self::scalar_point(cs, &u_single.commit_w, &r);
         let w_fold = u_range.commit_w.add(&r_w, cs);
         let z_inv = w_fold
             .get_z()
@@ -29,38 +32,25 @@ impl<C: CircuitDriver> NifsCircuit<C> {
             .unwrap_or_else(C::Base::zero);
 
         // E_fold = U.E + r * T
-        let r_t = commit_t.scalar_point(cs, &r);
+        let r_t = Self::scalar_point(cs, &commit_t, &r);
         let e_fold = u_range.commit_e.add(&r_t, cs);
 
-        let r_bn = f_to_nat(&r.value(cs));
-        let m_bn = BigInt::from_str_radix(C::ORDER_STR, 16).unwrap();
-        let r_bn_ass =
-            BigNatAssignment::witness_from_field_assignment(cs, &r, BN_LIMB_WIDTH, BN_N_LIMBS);
-        let m_bn_ass = BigNatAssignment::witness_from_big_int(cs, m_bn, BN_LIMB_WIDTH, BN_N_LIMBS);
-
         // u_fold = U.u + r
         let u_fold = FieldAssignment::witness(cs, u_range.u.value(cs) + r.value(cs));
         FieldAssignment::enforce_eq_constant(cs, &(&(&u_fold - &u_range.u) - &r), &C::Base::zero());
 
+        // the public inputs are folded in the scalar field emulated over the base field
+        let r_nn = NonNativeFieldAssignment::from_field(cs, &r);
+
         // Fold U.x0 + r * x0
-        let x0_single_bn = BigNatAssignment::witness_from_big_int(
-            cs,
-            f_to_nat(&u_single.x0.value(cs)),
-            BN_LIMB_WIDTH,
-            BN_N_LIMBS,
-        );
-        let r_x0 = x0_single_bn.mult_mod(cs, &r_bn_ass, &m_bn_ass);
-        let x0_fold = u_range.x0.add(&r_x0).red_mod(cs, &m_bn_ass);
+        let x0_single = NonNativeFieldAssignment::from_field(cs, &u_single.x0);
+        let r_x0 = NonNativeFieldAssignment::mul(cs, &r_nn, &x0_single);
+        let x0_fold = NonNativeFieldAssignment::canonical(cs, &(&u_range.x0 + &r_x0));
 
         // Fold U.x1 + r * x1
-        let x1_single_bn = BigNatAssignment::witness_from_big_int(
-            cs,
-            f_to_nat(&u_single.x1.value(cs)),
-            BN_LIMB_WIDTH,
-            BN_N_LIMBS,
-        );
-        let r_x1 = x1_single_bn.mult_mod(cs, &r_bn_ass, &m_bn_ass);
-        let x1_fold = u_range.x1.add(&r_x1).red_mod(cs, &m_bn_ass);
+        let x1_single = NonNativeFieldAssignment::from_field(cs, &u_single.x1);
+        let r_x1 = NonNativeFieldAssignment::mul(cs, &r_nn, &x1_single);
+        let x1_fold = NonNativeFieldAssignment::canonical(cs, &(&u_range.x1 + &r_x1));
 
         RelaxedR1csInstanceAssignment {
             commit_w: w_fold,
@@ -70,4 +60,108 @@ impl<C: CircuitDriver> NifsCircuit<C> {
             x1: x1_fold,
         }
     }
+
+    fn scalar_point<CS: CircuitDriver<Scalar = C::Base>>(
+        cs: &mut R1cs<CS>,
+        point: &PointAssignment<C::Base>,
+        r: &FieldAssignment<C::Base>,
+    ) -> PointAssignment<C::Base> {
+        if !AFFINE {
+            return point.scalar_point(cs, r);
+        }
+
+        // the challenge is squeezed with CHALLENGE_BITS bits
+        let bits = FieldAssignment::to_bits(cs, r, CHALLENGE_BITS);
+        FieldAssignment::enforce_eq_bits(cs, r, &bits);
+        // the identity commitment of a zero vector is multiplied as the generator and restored
+        let (point, is_identity) = AffinePointAssignment::<C::Affine>::from_projective_or(
+            cs,
+            point,
+            C::Affine::ADDITIVE_GENERATOR,
+        );
+        let not_identity = BinaryAssignment::not(cs, &is_identity);
+        point
+            .scalar_point_bits(cs, &bits)
+            .to_projective()
+            .select_identity(cs, &not_identity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::driver::{base_as_scalar, Bn254Driver, GrumpkinDriver};
+    use crate::relaxed_r1cs::{R1csInstance, RelaxedR1csInstance};
+
+    use bn_254::Fr;
+    use grumpkin::Affine;
+    use rand_core::{OsRng, RngCore};
+    use zkstd::common::{BNAffine, CurveGroup};
+    use zkstd::matrix::DenseVectors;
+
+    fn fold<const AFFINE: bool>(
+        u_range: &RelaxedR1csInstance<GrumpkinDriver>,
+        u_single: &R1csInstance<GrumpkinDriver>,
+        commit_t: Affine,
+        r: Fr,
+    ) -> u64 {
+        let mut cs: R1cs<Bn254Driver> = R1cs::default();
+        let u_range_assignment = RelaxedR1csInstanceAssignment::witness(&mut cs, u_range);
+        let u_single_assignment = R1csInstanceAssignment::witness(&mut cs, u_single);
+        let commit_t_assignment = PointAssignment::witness(
+            &mut cs,
+            commit_t.get_x(),
+            commit_t.get_y(),
+            commit_t.is_identity(),
+        );
+        let r_assignment = FieldAssignment::witness(&mut cs, r);
+
+        let m = cs.m();
+        let folded = NifsCircuit::<GrumpkinDriver, AFFINE>::verify(
+            &mut cs,
+            r_assignment,
+            u_range_assignment,
+            u_single_assignment,
+            commit_t_assignment,
+        );
+        let constraints = cs.m() - m;
+
+        let expected = u_range.fold(u_single, base_as_scalar::<GrumpkinDriver>(r), commit_t);
+        folded
+            .commit_w
+            .assert_equal_public_point(&mut cs, expected.commit_w.to_extended());
+        folded
+            .commit_e
+            .assert_equal_public_point(&mut cs, expected.commit_e.to_extended());
+        assert!(cs.is_sat());
+
+        constraints
+    }
+
+    #[test]
+    fn nifs_circuit_affine_test() {
+        let mut rng = OsRng;
+        let r = Fr::from(rng.next_u64()) * Fr::from(rng.next_u64());
+        let u_range = RelaxedR1csInstance {
+            commit_w: Affine::random(&mut rng),
+            commit_e: Affine::random(&mut rng),
+            u: Fr::random(&mut rng).into(),
+            x: DenseVectors::new(vec![Fr::random(&mut rng).into(); 2]),
+        };
+        let u_single = R1csInstance {
+            commit_w: Affine::random(&mut rng),
+            x: DenseVectors::new(vec![Fr::random(&mut rng).into(); 2]),
+        };
+        let commit_t = Affine::random(&mut rng);
+
+        let projective = fold::<false>(&u_range, &u_single, commit_t, r);
+        let affine = fold::<true>(&u_range, &u_single, commit_t, r);
+        assert!(affine < projective);
+
+        // the dummy instances of the base case commit to zero vectors
+        let u_dummy = RelaxedR1csInstance::dummy(2);
+        let u_single = R1csInstance::dummy(2);
+        fold::<true>(&u_dummy, &u_single, Affine::ADDITIVE_IDENTITY, r);
+        fold::<true>(&u_range, &u_single, commit_t, r);
+    }
 }
@@ -72,10 +72,11 @@ pub fn base_as_scalar<C: CircuitDriver>(input: C::Base) -> C::Scalar {
 #[cfg(test)]
 mod grumpkin_gadget_tests {
     use super::{Fq as Scalar, Fr as Base, GrumpkinDriver};
+    use crate::test::tamper;
 
     use bn_254::G1Affine;
     use rand_core::OsRng;
-    use zkstd::circuit::prelude::{FieldAssignment, PointAssignment, R1cs};
+    use zkstd::circuit::prelude::{AffinePointAssignment, FieldAssignment, PointAssignment, R1cs};
     use zkstd::common::{BNAffine, BNProjective, Group};
 
     #[test]
@@ -280,4 +281,289 @@ mod grumpkin_gadget_tests {
             assert!(cs.is_sat());
         }
     }
+
+    #[test]
+    fn curve_scalar_mul_tamper_test() {
+        let mut rng = OsRng;
+        let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+        let x = Scalar::random(&mut rng);
+        let p = G1Affine::random(&mut rng);
+
+        let x_assignment = FieldAssignment::witness(&mut cs, x);
+        let p_assignment = PointAssignment::instance(&mut cs, p);
+        let product = p_assignment.scalar_point(&mut cs, &x_assignment);
+        product.assert_equal_public_point(&mut cs, p * Base::from(x));
+        assert!(cs.is_sat());
+
+        // the bits are bound to the scalar
+        let wire = x_assignment.inner().terms()[0].0;
+        assert!(!tamper(&cs, &wire, x + Scalar::one()).is_sat());
+    }
+
+    #[test]
+    fn curve_fixed_base_scalar_mul_test() {
+        let mut rng = OsRng;
+        for _ in 0..10 {
+            let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+            let x = Scalar::random(&mut rng);
+            let p = G1Affine::random(&mut rng);
+
+            let x_assignment = FieldAssignment::instance(&mut cs, x);
+            let bits = FieldAssignment::to_bits(&mut cs, &x_assignment, 256);
+            let expected = p * Base::from(x);
+
+            let m = cs.m();
+            let fixed = PointAssignment::fixed_base_scalar_point(&mut cs, p, &bits);
+            let fixed_constraints = cs.m() - m;
+            fixed.assert_equal_public_point(&mut cs, expected);
+
+            let m = cs.m();
+            let windowed = PointAssignment::instance(&mut cs, p).scalar_point_bits(&mut cs, &bits);
+            let windowed_constraints = cs.m() - m;
+            windowed.assert_equal_public_point(&mut cs, expected);
+
+            assert!(fixed_constraints * 3 < windowed_constraints);
+            assert!(cs.is_sat());
+        }
+    }
+
+    #[test]
+    fn curve_affine_test() {
+        let mut rng = OsRng;
+        let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+        let a = G1Affine::random(&mut rng);
+        let b = G1Affine::random(&mut rng);
+        let x = Scalar::random(&mut rng);
+
+        let a_assignment = AffinePointAssignment::witness(&mut cs, a);
+        let b_assignment = AffinePointAssignment::witness(&mut cs, b);
+        a_assignment.enforce_on_curve(&mut cs);
+        let sum = a_assignment.add_incomplete(&b_assignment, &mut cs);
+        let double = a_assignment.double(&mut cs);
+        assert_eq!(sum.value(&cs), G1Affine::from(a + b));
+        assert_eq!(double.value(&cs), G1Affine::from(a.double()));
+
+        let projective = PointAssignment::instance(&mut cs, b);
+        let affine = AffinePointAssignment::from_projective(&mut cs, &projective);
+        AffinePointAssignment::enforce_equal(&mut cs, &affine, &b_assignment);
+        let (fallback, is_identity) =
+            AffinePointAssignment::from_projective_or(&mut cs, &PointAssignment::identity(), a);
+        AffinePointAssignment::enforce_equal(&mut cs, &fallback, &a_assignment);
+        assert_eq!(
+            FieldAssignment::from(&is_identity).value(&cs),
+            Scalar::one()
+        );
+
+        let x_assignment = FieldAssignment::witness(&mut cs, x);
+        let bits = FieldAssignment::to_bits(&mut cs, &x_assignment, 256);
+        let m = cs.m();
+        let product = a_assignment.scalar_point_bits(&mut cs, &bits);
+        let affine_constraints = cs.m() - m;
+        let m = cs.m();
+        PointAssignment::instance(&mut cs, a).scalar_point_bits(&mut cs, &bits);
+        let projective_constraints = cs.m() - m;
+        assert_eq!(product.value(&cs), G1Affine::from(a * Base::from(x)));
+        product
+            .to_projective()
+            .assert_equal_public_point(&mut cs, a * Base::from(x));
+        assert!(affine_constraints * 3 < projective_constraints * 2);
+        assert!(cs.is_sat());
+    }
+
+    #[test]
+    fn curve_affine_generator_test() {
+        // the offset point differs from the generator, whose x coordinate is the least one
+        let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+        let g = G1Affine::ADDITIVE_GENERATOR;
+        let x = Scalar::from(5);
+
+        let g_assignment = AffinePointAssignment::witness(&mut cs, g);
+        let x_assignment = FieldAssignment::witness(&mut cs, x);
+        let bits = FieldAssignment::to_bits(&mut cs, &x_assignment, 8);
+        let product = g_assignment.scalar_point_bits(&mut cs, &bits);
+        assert_eq!(product.value(&cs), G1Affine::from(g * Base::from(x)));
+        assert!(cs.is_sat());
+    }
+
+    #[test]
+    fn curve_affine_exceptional_test() {
+        let mut rng = OsRng;
+        let a = G1Affine::random(&mut rng);
+
+        // the chord through equal points leaves the slope free
+        let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+        let a_assignment = AffinePointAssignment::witness(&mut cs, a);
+        a_assignment.add_incomplete(&a_assignment, &mut cs);
+        assert!(!cs.is_sat());
+
+        let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+        let a_assignment = AffinePointAssignment::witness(&mut cs, a);
+        a_assignment.add_incomplete(&a_assignment.neg(), &mut cs);
+        assert!(!cs.is_sat());
+
+        let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+        let identity = PointAssignment::witness(&mut cs, Scalar::zero(), Scalar::one(), true);
+        AffinePointAssignment::<G1Affine>::from_projective(&mut cs, &identity);
+        assert!(!cs.is_sat());
+
+        // a result at the identity
+        let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+        let a_assignment = AffinePointAssignment::witness(&mut cs, a);
+        let zero = FieldAssignment::witness(&mut cs, Scalar::zero());
+        let bits = FieldAssignment::to_bits(&mut cs, &zero, 8);
+        a_assignment.scalar_point_bits(&mut cs, &bits);
+        assert!(!cs.is_sat());
+    }
+
+    #[test]
+    fn curve_partial_window_scalar_mul_test() {
+        let mut rng = OsRng;
+        let p = G1Affine::random(&mut rng);
+        for x in [0, 1, 2, 5, 100, 1023] {
+            let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+            let x_assignment = FieldAssignment::witness(&mut cs, Scalar::from(x));
+            let bits = FieldAssignment::to_bits(&mut cs, &x_assignment, 10);
+            let expected = p * Base::from(x);
+
+            let fixed = PointAssignment::fixed_base_scalar_point(&mut cs, p, &bits);
+            fixed.assert_equal_public_point(&mut cs, expected);
+            let windowed = PointAssignment::instance(&mut cs, p).scalar_point_bits(&mut cs, &bits);
+            windowed.assert_equal_public_point(&mut cs, expected);
+
+            assert!(cs.is_sat());
+        }
+    }
+
+    #[test]
+    fn curve_multi_scalar_mul_test() {
+        let mut rng = OsRng;
+        let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+        let terms = (0..4)
+            .map(|_| (G1Affine::random(&mut rng), Scalar::random(&mut rng)))
+            .collect::<Vec<_>>();
+        let fixed_terms = (0..2)
+            .map(|_| (G1Affine::random(&mut rng), Scalar::random(&mut rng)))
+            .collect::<Vec<_>>();
+        let expected = terms
+            .iter()
+            .chain(fixed_terms.iter())
+            .fold(G1Affine::ADDITIVE_IDENTITY.to_extended(), |acc, (p, x)| {
+                acc + *p * Base::from(*x)
+            });
+
+        let variable = terms
+            .iter()
+            .map(|(p, x)| {
+                let x = FieldAssignment::witness(&mut cs, *x);
+                (
+                    PointAssignment::instance(&mut cs, *p),
+                    FieldAssignment::to_bits(&mut cs, &x, 256),
+                )
+            })
+            .collect::<Vec<_>>();
+        let fixed = fixed_terms
+            .iter()
+            .map(|(p, x)| {
+                let x = FieldAssignment::witness(&mut cs, *x);
+                (*p, FieldAssignment::to_bits(&mut cs, &x, 256))
+            })
+            .collect::<Vec<_>>();
+
+        let sum = PointAssignment::multi_scalar_point_bits(&mut cs, &fixed, &variable);
+        sum.assert_equal_public_point(&mut cs, expected);
+
+        let m = cs.m();
+        PointAssignment::multi_scalar_point_bits::<_, G1Affine>(&mut cs, &[], &variable);
+        let msm_constraints = cs.m() - m;
+        let m = cs.m();
+        let mut chained = PointAssignment::identity();
+        for (p, bits) in variable.iter() {
+            let term = p.scalar_point_bits(&mut cs, bits);
+            chained = chained.add(&term, &mut cs);
+        }
+        let chained_constraints = cs.m() - m;
+
+        assert!(msm_constraints * 4 < chained_constraints * 3);
+        assert!(cs.is_sat());
+    }
+
+    #[test]
+    fn curve_multi_scalar_mul_scalars_test() {
+        let mut rng = OsRng;
+        let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+        let terms = (0..4)
+            .map(|_| (G1Affine::random(&mut rng), Scalar::random(&mut rng)))
+            .collect::<Vec<_>>();
+        let expected = terms
+            .iter()
+            .fold(G1Affine::ADDITIVE_IDENTITY.to_extended(), |acc, (p, x)| {
+                acc + *p * Base::from(*x)
+            });
+
+        let scalars = terms
+            .iter()
+            .map(|(_, x)| FieldAssignment::witness(&mut cs, *x))
+            .collect::<Vec<_>>();
+        let fixed = terms[..2]
+            .iter()
+            .zip(&scalars)
+            .map(|((p, _), x)| (*p, x.clone()))
+            .collect::<Vec<_>>();
+        let variable = terms[2..]
+            .iter()
+            .zip(&scalars[2..])
+            .map(|((p, _), x)| (PointAssignment::instance(&mut cs, *p), x.clone()))
+            .collect::<Vec<_>>();
+
+        let sum = PointAssignment::multi_scalar_point(&mut cs, &fixed, &variable);
+        sum.assert_equal_public_point(&mut cs, expected);
+        assert!(cs.is_sat());
+
+        // the bits of both constant and variable base scalars are bound to the scalars
+        for ((_, x), scalar) in terms.iter().zip(&scalars) {
+            let wire = scalar.inner().terms()[0].0;
+            assert!(!tamper(&cs, &wire, *x + Scalar::one()).is_sat());
+        }
+    }
+
+    #[test]
+    fn curve_multi_scalar_mul_uneven_test() {
+        let mut rng = OsRng;
+        for (lengths, xs) in [([10, 3, 1], [1000, 5, 1]), ([4, 9, 2], [0, 300, 3])] {
+            let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+            let points = (0..3)
+                .map(|_| G1Affine::random(&mut rng))
+                .collect::<Vec<_>>();
+            let expected = points
+                .iter()
+                .zip(xs)
+                .fold(G1Affine::ADDITIVE_IDENTITY.to_extended(), |acc, (p, x)| {
+                    acc + *p * Base::from(x)
+                });
+
+            let terms = points
+                .iter()
+                .zip(lengths.iter().zip(xs))
+                .map(|(p, (n, x))| {
+                    let x = FieldAssignment::witness(&mut cs, Scalar::from(x));
+                    (*p, FieldAssignment::to_bits(&mut cs, &x, *n))
+                })
+                .collect::<Vec<_>>();
+            let variable = terms
+                .iter()
+                .map(|(p, bits)| (PointAssignment::instance(&mut cs, *p), bits.clone()))
+                .collect::<Vec<_>>();
+
+            let sum =
+                PointAssignment::multi_scalar_point_bits::<_, G1Affine>(&mut cs, &[], &variable);
+            sum.assert_equal_public_point(&mut cs, expected);
+            let fixed = PointAssignment::multi_scalar_point_bits(&mut cs, &terms, &[]);
+            fixed.assert_equal_public_point(&mut cs, expected);
+            let mixed =
+                PointAssignment::multi_scalar_point_bits(&mut cs, &terms[..1], &variable[1..]);
+            mixed.assert_equal_public_point(&mut cs, expected);
+
+            assert!(cs.is_sat());
+        }
+    }
 }
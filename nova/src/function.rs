@@ -7,10 +7,27 @@ use zkstd::r1cs::R1cs;
 pub trait FunctionCircuit<F: PrimeField>:
     Clone + Debug + Default + PartialEq + Eq + Encode + Decode
 {
+    /// Length of the step state `z_i`
+    const ARITY: usize = 1;
+
     fn invoke(z_i: &DenseVectors<F>) -> DenseVectors<F>;
 
     fn invoke_cs<C: CircuitDriver<Scalar = F>>(
         cs: &mut R1cs<C>,
         z_i: Vec<FieldAssignment<F>>,
     ) -> Vec<FieldAssignment<F>>;
+
+    /// Step of this instance, which carries the private inputs of the step such as
+    /// the memory resumed by a `RamAssignment`. `Ivc::prove_step_with` runs it.
+    fn invoke_with(&self, z_i: &DenseVectors<F>) -> DenseVectors<F> {
+        Self::invoke(z_i)
+    }
+
+    fn invoke_cs_with<C: CircuitDriver<Scalar = F>>(
+        &self,
+        cs: &mut R1cs<C>,
+        z_i: Vec<FieldAssignment<F>>,
+    ) -> Vec<FieldAssignment<F>> {
+        Self::invoke_cs(cs, z_i)
+    }
 }
@@ -1,9 +1,7 @@
-mod big_nat;
 mod instance;
 mod mimc;
 mod relaxed_instance;
 
-pub(crate) use big_nat::{f_to_nat, nat_to_limbs, BigNatAssignment, BN_LIMB_WIDTH, BN_N_LIMBS};
 pub(crate) use instance::R1csInstanceAssignment;
 pub(crate) use mimc::MimcAssignment;
 pub(crate) use relaxed_instance::RelaxedR1csInstanceAssignment;
@@ -2,11 +2,11 @@ use crate::relaxed_r1cs::RelaxedR1csInstance;
 
 use crate::circuit::MimcROCircuit;
 use crate::driver::scalar_as_base;
-use crate::gadget::big_nat::{BigNatAssignment, BN_LIMB_WIDTH, BN_N_LIMBS};
-use crate::gadget::{f_to_nat, R1csInstanceAssignment};
+use crate::gadget::R1csInstanceAssignment;
 use crate::hash::{HASH_BITS, MIMC_ROUNDS};
 use zkstd::circuit::prelude::{
-    BinaryAssignment, CircuitDriver, FieldAssignment, PointAssignment, R1cs,
+    BinaryAssignment, CircuitDriver, FieldAssignment, NonNativeFieldAssignment, PointAssignment,
+    R1cs,
 };
 use zkstd::common::{CurveGroup, Ring};
 
@@ -15,8 +15,8 @@ pub(crate) struct RelaxedR1csInstanceAssignment<C: CircuitDriver> {
     pub(crate) commit_w: PointAssignment<C::Base>,
     pub(crate) commit_e: PointAssignment<C::Base>,
     pub(crate) u: FieldAssignment<C::Base>,
-    pub(crate) x0: BigNatAssignment<C::Base>,
-    pub(crate) x1: BigNatAssignment<C::Base>,
+    pub(crate) x0: NonNativeFieldAssignment<C::Base, C::Scalar>,
+    pub(crate) x1: NonNativeFieldAssignment<C::Base, C::Scalar>,
 }
 
 impl<C: CircuitDriver> RelaxedR1csInstanceAssignment<C> {
@@ -44,10 +44,8 @@ impl<C: CircuitDriver> RelaxedR1csInstanceAssignment<C> {
             commit_e.is_identity(),
         );
         let u = FieldAssignment::witness(cs, scalar_as_base::<C>(*u));
-        let x0 =
-            BigNatAssignment::witness_from_big_int(cs, f_to_nat(&x[0]), BN_LIMB_WIDTH, BN_N_LIMBS);
-        let x1 =
-            BigNatAssignment::witness_from_big_int(cs, f_to_nat(&x[1]), BN_LIMB_WIDTH, BN_N_LIMBS);
+        let x0 = NonNativeFieldAssignment::witness(cs, x[0]);
+        let x1 = NonNativeFieldAssignment::witness(cs, x[1]);
 
         Self {
             commit_w,
@@ -68,18 +66,8 @@ impl<C: CircuitDriver> RelaxedR1csInstanceAssignment<C> {
             commit_w: instance.commit_w,
             commit_e,
             u: FieldAssignment::constant(&C::Base::one()),
-            x0: BigNatAssignment::witness_from_field_assignment(
-                cs,
-                &instance.x0,
-                BN_LIMB_WIDTH,
-                BN_N_LIMBS,
-            ),
-            x1: BigNatAssignment::witness_from_field_assignment(
-                cs,
-                &instance.x1,
-                BN_LIMB_WIDTH,
-                BN_N_LIMBS,
-            ),
+            x0: NonNativeFieldAssignment::from_field(cs, &instance.x0),
+            x1: NonNativeFieldAssignment::from_field(cs, &instance.x1),
         }
     }
 
@@ -92,8 +80,8 @@ impl<C: CircuitDriver> RelaxedR1csInstanceAssignment<C> {
         let commit_w = PointAssignment::conditional_select(cs, &a.commit_w, &b.commit_w, condition);
         let commit_e = PointAssignment::conditional_select(cs, &a.commit_e, &b.commit_e, condition);
         let u = FieldAssignment::conditional_select(cs, &a.u, &b.u, condition);
-        let x0 = BigNatAssignment::conditional_select(cs, &a.x0, &b.x0, condition);
-        let x1 = BigNatAssignment::conditional_select(cs, &a.x1, &b.x1, condition);
+        let x0 = NonNativeFieldAssignment::conditional_select(cs, &a.x0, &b.x0, condition);
+        let x1 = NonNativeFieldAssignment::conditional_select(cs, &a.x1, &b.x1, condition);
         Self {
             commit_w,
             commit_e,
@@ -113,11 +101,11 @@ impl<C: CircuitDriver> RelaxedR1csInstanceAssignment<C> {
         transcript.append_point(commit_w);
         transcript.append_point(commit_e);
         transcript.append(self.u.clone());
-        for limb in self.x0.as_limbs() {
-            transcript.append(limb);
+        for limb in self.x0.limbs() {
+            transcript.append(limb.clone());
         }
-        for limb in self.x1.as_limbs() {
-            transcript.append(limb);
+        for limb in self.x1.limbs() {
+            transcript.append(limb.clone());
         }
     }
 
@@ -204,20 +192,10 @@ mod tests {
             &scalar_as_base::<GrumpkinDriver>(instance.u),
         );
 
-        let x0_ass = BigNatAssignment::witness_from_big_int(
-            &mut cs,
-            f_to_nat(&instance.x[0]),
-            BN_LIMB_WIDTH,
-            BN_N_LIMBS,
-        );
-        let x1_ass = BigNatAssignment::witness_from_big_int(
-            &mut cs,
-            f_to_nat(&instance.x[0]),
-            BN_LIMB_WIDTH,
-            BN_N_LIMBS,
-        );
-        BigNatAssignment::enforce_eq(&mut cs, &instance_assignment.x0, &x0_ass);
-        BigNatAssignment::enforce_eq(&mut cs, &instance_assignment.x1, &x1_ass);
+        let x0_ass = NonNativeFieldAssignment::constant(instance.x[0]);
+        let x1_ass = NonNativeFieldAssignment::constant(instance.x[1]);
+        NonNativeFieldAssignment::enforce_eq(&mut cs, &instance_assignment.x0, &x0_ass);
+        NonNativeFieldAssignment::enforce_eq(&mut cs, &instance_assignment.x1, &x1_ass);
 
         instance_assignment
             .commit_e
@@ -12,7 +12,9 @@ use zkstd::circuit::prelude::{CircuitDriver, R1cs};
 use zkstd::common::{Decode, Encode, IntGroup};
 use zkstd::matrix::DenseVectors;
 
-pub struct Ivc<E1, E2, FC1, FC2>
+/// Incrementally verifiable computation, the augmented circuits fold the commitments
+/// with the incomplete affine formulas when `AFFINE` is set
+pub struct Ivc<E1, E2, FC1, FC2, const AFFINE: bool = false>
 where
     E1: CircuitDriver<Base = <E2 as CircuitDriver>::Scalar>,
     E2: CircuitDriver<Base = <E1 as CircuitDriver>::Scalar>,
@@ -41,7 +43,7 @@ where
     f: PhantomData<(FC1, FC2)>,
 }
 
-impl<E1, E2, FC1, FC2> Ivc<E1, E2, FC1, FC2>
+impl<E1, E2, FC1, FC2, const AFFINE: bool> Ivc<E1, E2, FC1, FC2, AFFINE>
 where
     E1: CircuitDriver<Base = <E2 as CircuitDriver>::Scalar>,
     E2: CircuitDriver<Base = <E1 as CircuitDriver>::Scalar>,
@@ -49,12 +51,29 @@ where
     FC2: FunctionCircuit<E2::Scalar>,
 {
     pub fn init(
-        pp: &PublicParams<E1, E2, FC1, FC2>,
+        pp: &PublicParams<E1, E2, FC1, FC2, AFFINE>,
+        z0_primary: DenseVectors<E1::Scalar>,
+        z0_secondary: DenseVectors<E2::Scalar>,
+    ) -> Self {
+        Self::init_with(
+            pp,
+            &FC1::default(),
+            &FC2::default(),
+            z0_primary,
+            z0_secondary,
+        )
+    }
+
+    /// Run the first step of the step functions `f_primary` and `f_secondary`
+    pub fn init_with(
+        pp: &PublicParams<E1, E2, FC1, FC2, AFFINE>,
+        f_primary: &FC1,
+        f_secondary: &FC2,
         z0_primary: DenseVectors<E1::Scalar>,
         z0_secondary: DenseVectors<E2::Scalar>,
     ) -> Self {
         let mut cs_primary = R1cs::<E1>::default();
-        let circuit_primary = AugmentedFCircuit::<E2, FC1> {
+        let circuit_primary = AugmentedFCircuit::<E2, FC1, AFFINE> {
             is_primary: true,
             i: 0,
             z_0: z0_primary.clone(),
@@ -62,7 +81,7 @@ where
             u_single: None,
             u_range: None,
             commit_t: None,
-            f: Default::default(),
+            f: f_primary.clone(),
         };
         let zi_primary = circuit_primary.generate(&mut cs_primary);
 
@@ -71,7 +90,7 @@ where
         let prover_primary = Prover::new(pp.r1cs_shape_primary.clone(), pp.ck_primary.clone());
 
         let mut cs_secondary = R1cs::<E2>::default();
-        let circuit_secondary = AugmentedFCircuit::<E1, FC2> {
+        let circuit_secondary = AugmentedFCircuit::<E1, FC2, AFFINE> {
             is_primary: false,
             i: 0,
             z_0: z0_secondary.clone(),
@@ -79,7 +98,7 @@ where
             u_single: Some(u_single_next_primary.clone()),
             u_range: None,
             commit_t: None,
-            f: Default::default(),
+            f: f_secondary.clone(),
         };
         let zi_secondary = circuit_secondary.generate(&mut cs_secondary);
 
@@ -131,7 +150,18 @@ where
 
     pub fn prove_step(
         &mut self,
-        pp: &PublicParams<E1, E2, FC1, FC2>,
+        pp: &PublicParams<E1, E2, FC1, FC2, AFFINE>,
+    ) -> RecursiveProof<E1, E2, FC1, FC2> {
+        self.prove_step_with(pp, &FC1::default(), &FC2::default())
+    }
+
+    /// Run the next step of the step functions `f_primary` and `f_secondary`. The first call
+    /// returns the proof of the step run by `init_with` and ignores the step functions.
+    pub fn prove_step_with(
+        &mut self,
+        pp: &PublicParams<E1, E2, FC1, FC2, AFFINE>,
+        f_primary: &FC1,
+        f_secondary: &FC2,
     ) -> RecursiveProof<E1, E2, FC1, FC2> {
         if self.i == 0 {
             self.i = 1;
@@ -155,7 +185,6 @@ where
                 marker: Default::default(),
             };
         }
-        let z_next = FC1::invoke(&self.zi_primary);
         let (u_range_next_secondary, w_range_next_secondary, commit_t_secondary) =
             self.prover_secondary.prove(
                 &self.u_range_secondary,
@@ -165,7 +194,7 @@ where
             );
 
         let mut cs_primary = R1cs::<E1>::default();
-        let circuit_primary = AugmentedFCircuit::<E2, FC1> {
+        let circuit_primary = AugmentedFCircuit::<E2, FC1, AFFINE> {
             is_primary: true,
             i: self.i,
             z_0: self.z0_primary.clone(),
@@ -173,7 +202,7 @@ where
             u_single: Some(self.u_single_secondary.clone()),
             u_range: Some(self.u_range_secondary.clone()),
             commit_t: Some(commit_t_secondary),
-            f: Default::default(),
+            f: f_primary.clone(),
         };
 
         let zi_primary = circuit_primary.generate(&mut cs_primary);
@@ -189,7 +218,7 @@ where
             );
 
         let mut cs_secondary = R1cs::<E2>::default();
-        let circuit_secondary = AugmentedFCircuit::<E1, FC2> {
+        let circuit_secondary = AugmentedFCircuit::<E1, FC2, AFFINE> {
             is_primary: false,
             i: self.i,
             z_0: self.z0_secondary.clone(),
@@ -197,7 +226,7 @@ where
             u_single: Some(u_single_next_primary),
             u_range: Some(self.u_range_primary.clone()),
             commit_t: Some(commit_t_primary),
-            f: Default::default(),
+            f: f_secondary.clone(),
         };
 
         let zi_secondary = circuit_secondary.generate(&mut cs_secondary);
@@ -248,7 +277,7 @@ where
 }
 
 #[derive(Decode, Encode, Clone, PartialEq, Eq, Debug)]
-pub struct PublicParams<E1, E2, FC1, FC2>
+pub struct PublicParams<E1, E2, FC1, FC2, const AFFINE: bool = false>
 where
     E1: CircuitDriver<Base = <E2 as CircuitDriver>::Scalar>,
     E2: CircuitDriver<Base = <E1 as CircuitDriver>::Scalar>,
@@ -262,7 +291,7 @@ where
     marker: PhantomData<(FC1, FC2)>,
 }
 
-impl<E1, E2, FC1, FC2> PublicParams<E1, E2, FC1, FC2>
+impl<E1, E2, FC1, FC2, const AFFINE: bool> PublicParams<E1, E2, FC1, FC2, AFFINE>
 where
     E1: CircuitDriver<Base = <E2 as CircuitDriver>::Scalar>,
     E2: CircuitDriver<Base = <E1 as CircuitDriver>::Scalar>,
@@ -271,10 +300,10 @@ where
 {
     pub fn setup<R: RngCore>(rng: &mut R) -> Self {
         // Initialize shape for the primary
-        let circuit_primary = AugmentedFCircuit::<E2, FC1> {
+        let circuit_primary = AugmentedFCircuit::<E2, FC1, AFFINE> {
             is_primary: true,
             i: 0,
-            z_0: DenseVectors::new(vec![E2::Base::zero(); 1]),
+            z_0: DenseVectors::new(vec![E2::Base::zero(); FC1::ARITY]),
             z_i: None,
             u_single: None,
             u_range: None,
@@ -286,10 +315,10 @@ where
         let r1cs_shape_primary = R1csShape::from(cs);
 
         // Initialize shape for the secondary
-        let circuit_secondary = AugmentedFCircuit::<E1, FC2> {
+        let circuit_secondary = AugmentedFCircuit::<E1, FC2, AFFINE> {
             is_primary: false,
             i: 0,
-            z_0: DenseVectors::new(vec![E1::Base::zero(); 1]),
+            z_0: DenseVectors::new(vec![E1::Base::zero(); FC2::ARITY]),
             z_i: None,
             u_single: None,
             u_range: None,
@@ -318,3 +347,43 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::driver::{Bn254Driver, GrumpkinDriver};
+    use crate::test::ExampleFunction;
+
+    use bn_254::{Fq, Fr};
+    use rand_core::OsRng;
+
+    fn three_steps<const AFFINE: bool>() {
+        let mut rng = OsRng;
+        let pp = PublicParams::<
+            Bn254Driver,
+            GrumpkinDriver,
+            ExampleFunction<Fr>,
+            ExampleFunction<Fq>,
+            AFFINE,
+        >::setup(&mut rng);
+
+        let z0_primary = DenseVectors::new(vec![Fr::from(0)]);
+        let z0_secondary = DenseVectors::new(vec![Fq::from(0)]);
+        let mut ivc = Ivc::init(&pp, z0_primary, z0_secondary);
+        (0..2).for_each(|_| {
+            ivc.prove_step(&pp);
+        });
+        let proof = ivc.prove_step(&pp);
+        assert!(proof.verify(&pp));
+    }
+
+    #[test]
+    fn ivc_test() {
+        three_steps::<false>();
+    }
+
+    #[test]
+    fn ivc_affine_test() {
+        three_steps::<true>();
+    }
+}
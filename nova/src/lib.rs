@@ -6,9 +6,12 @@ mod function;
 mod gadget;
 mod hash;
 mod ivc;
+mod memory;
+mod merkle;
 mod pedersen;
 mod proof;
 mod prover;
+mod reddsa;
 mod relaxed_r1cs;
 mod verifier;
 
@@ -19,8 +22,15 @@ mod test;
 pub use driver::{Bn254Driver, GrumpkinDriver};
 pub use function::FunctionCircuit;
 pub use ivc::{Ivc, PublicParams};
+pub use memory::{
+    Memory, MemoryState, MemoryStateAssignment, RamAssignment, RomAssignment, TIMESTAMP_BITS,
+};
+pub use merkle::{
+    DenseMerkleTree, MerklePath, MerklePathAssignment, MimcHash, SparseMerkleTree, TwoToOneHash,
+};
 pub use pedersen::PedersenCommitment;
 pub use proof::RecursiveProof;
 pub use prover::Prover;
+pub use reddsa::{PublicKey, SecretKey, Signature, SignatureAssignment};
 pub use relaxed_r1cs::R1csShape;
 pub use verifier::Verifier;
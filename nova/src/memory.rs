@@ -0,0 +1,487 @@
+//! Offline memory checking for random access memory in circuits.
+//!
+//! Every access reads the tuple `(address, value, timestamp)` last written to the address
+//! and writes it back with the current timestamp and the new value. The memory is consistent
+//! iff the multiset of written tuples, including the initial contents, equals the multiset of
+//! read tuples, including the final contents. Both multisets are compared by their hashes
+//! `prod (gamma - (address + alpha * value + alpha^2 * timestamp))` at challenges derived
+//! from the hash of the whole access trace.
+//!
+//! A single circuit derives the challenges itself in `RamAssignment::finalize`. Across the
+//! steps of an `Ivc` the challenges are fixed in advance by the prover in `MemoryState`,
+//! which is carried in the step state, and checked against the trace by the verifier. The
+//! step function resumes the memory from the contents replayed up to its step, which it
+//! carries as the instance of `FunctionCircuit` given to `Ivc::prove_step_with`.
+
+use crate::merkle::TwoToOneHash;
+
+use zkstd::circuit::prelude::{CircuitDriver, FieldAssignment, R1cs};
+use zkstd::common::PrimeField;
+
+/// Bits of the access counter, the number of accesses must stay below `2^TIMESTAMP_BITS`
+pub const TIMESTAMP_BITS: usize = 32;
+
+/// Native memory replaying the accesses of a circuit. It provides the witnesses of the
+/// gadgets and lets the prover compute the challenges before proving the first step.
+pub struct Memory<F: PrimeField, H: TwoToOneHash<F>> {
+    values: Vec<F>,
+    timestamps: Vec<u64>,
+    timestamp: u64,
+    trace: F,
+    hasher: H,
+}
+
+impl<F: PrimeField, H: TwoToOneHash<F>> Memory<F, H> {
+    pub fn new(init: &[F]) -> Self {
+        let hasher = H::default();
+        let trace = init
+            .iter()
+            .fold(F::zero(), |trace, value| hasher.hash(trace, *value));
+
+        Self {
+            values: init.to_vec(),
+            timestamps: vec![0; init.len()],
+            timestamp: 1,
+            trace,
+            hasher,
+        }
+    }
+
+    pub fn read(&mut self, address: usize) -> F {
+        self.access(F::from(address as u64), None).0
+    }
+
+    pub fn write(&mut self, address: usize, value: F) {
+        self.access(F::from(address as u64), Some(value));
+    }
+
+    pub fn values(&self) -> &[F] {
+        &self.values
+    }
+
+    pub fn timestamps(&self) -> &[u64] {
+        &self.timestamps
+    }
+
+    /// Challenges `(alpha, gamma)` derived from the accesses so far and the current contents
+    pub fn challenges(&self) -> (F, F) {
+        challenges(&self.hasher, self.trace, &self.values, &self.timestamps)
+    }
+
+    /// Return the value and timestamp last written to the address and record the access,
+    /// an address out of range reads zero and leaves the memory unchanged
+    fn access(&mut self, address: F, value: Option<F>) -> (F, u64) {
+        let (old_value, old_timestamp) = match to_index(address, self.values.len()) {
+            Some(index) => {
+                let old = (self.values[index], self.timestamps[index]);
+                self.values[index] = value.unwrap_or(old.0);
+                self.timestamps[index] = self.timestamp;
+                old
+            }
+            None => (F::zero(), 0),
+        };
+        let new_value = value.unwrap_or(old_value);
+        for element in [address, old_value, F::from(old_timestamp), new_value] {
+            self.trace = self.hasher.hash(self.trace, element);
+        }
+        self.timestamp += 1;
+
+        (old_value, old_timestamp)
+    }
+}
+
+/// State of the argument carried between the steps of an `Ivc`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryState<F: PrimeField> {
+    pub timestamp: F,
+    pub trace: F,
+    /// hash of the read tuples so far
+    pub reads: F,
+    /// hash of the written tuples so far, including the initial contents
+    pub writes: F,
+    pub alpha: F,
+    pub gamma: F,
+}
+
+impl<F: PrimeField> MemoryState<F> {
+    pub const LEN: usize = 6;
+
+    /// Initial state of a memory with contents `init` for the challenges of the whole run,
+    /// which the prover computes by `Memory::challenges` after replaying all steps
+    pub fn new<H: TwoToOneHash<F>>(init: &[F], alpha: F, gamma: F) -> Self {
+        let memory = Memory::<F, H>::new(init);
+        let writes = init
+            .iter()
+            .enumerate()
+            .fold(F::one(), |acc, (address, value)| {
+                acc * (gamma - fingerprint(alpha, F::from(address as u64), *value, F::zero()))
+            });
+
+        Self {
+            timestamp: F::from(memory.timestamp),
+            trace: memory.trace,
+            reads: F::one(),
+            writes,
+            alpha,
+            gamma,
+        }
+    }
+
+    /// Check the final state against the final contents and timestamps of the memory
+    pub fn verify<H: TwoToOneHash<F>>(&self, values: &[F], timestamps: &[u64]) -> bool {
+        let (alpha, gamma) = challenges(&H::default(), self.trace, values, timestamps);
+        let audit = values.iter().zip(timestamps).enumerate().fold(
+            F::one(),
+            |acc, (address, (value, timestamp))| {
+                acc * (gamma
+                    - fingerprint(alpha, F::from(address as u64), *value, F::from(*timestamp)))
+            },
+        );
+
+        alpha == self.alpha && gamma == self.gamma && self.writes == self.reads * audit
+    }
+
+    pub fn to_vec(&self) -> Vec<F> {
+        vec![
+            self.timestamp,
+            self.trace,
+            self.reads,
+            self.writes,
+            self.alpha,
+            self.gamma,
+        ]
+    }
+
+    pub fn from_slice(state: &[F]) -> Self {
+        assert_eq!(state.len(), Self::LEN);
+        Self {
+            timestamp: state[0],
+            trace: state[1],
+            reads: state[2],
+            writes: state[3],
+            alpha: state[4],
+            gamma: state[5],
+        }
+    }
+}
+
+/// `MemoryState` as a slice of the step state
+#[derive(Clone)]
+pub struct MemoryStateAssignment<F: PrimeField> {
+    timestamp: FieldAssignment<F>,
+    trace: FieldAssignment<F>,
+    reads: FieldAssignment<F>,
+    writes: FieldAssignment<F>,
+    alpha: FieldAssignment<F>,
+    gamma: FieldAssignment<F>,
+}
+
+impl<F: PrimeField> MemoryStateAssignment<F> {
+    pub fn witness<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, state: &MemoryState<F>) -> Self {
+        let state = state
+            .to_vec()
+            .into_iter()
+            .map(|x| FieldAssignment::witness(cs, x))
+            .collect::<Vec<_>>();
+        Self::from_slice(&state)
+    }
+
+    pub fn from_slice(state: &[FieldAssignment<F>]) -> Self {
+        assert_eq!(state.len(), MemoryState::<F>::LEN);
+        Self {
+            timestamp: state[0].clone(),
+            trace: state[1].clone(),
+            reads: state[2].clone(),
+            writes: state[3].clone(),
+            alpha: state[4].clone(),
+            gamma: state[5].clone(),
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<FieldAssignment<F>> {
+        vec![
+            self.timestamp.clone(),
+            self.trace.clone(),
+            self.reads.clone(),
+            self.writes.clone(),
+            self.alpha.clone(),
+            self.gamma.clone(),
+        ]
+    }
+
+    pub fn value<C: CircuitDriver<Scalar = F>>(&self, cs: &R1cs<C>) -> MemoryState<F> {
+        let state = self
+            .to_vec()
+            .iter()
+            .map(|x| x.value(cs))
+            .collect::<Vec<_>>();
+        MemoryState::from_slice(&state)
+    }
+}
+
+/// Read and write memory checked by the multiset hash of its accesses.
+/// constraints: 4 hashes and 39 per access, 2 hashes and 6 per address
+pub struct RamAssignment<F: PrimeField, H: TwoToOneHash<F>> {
+    memory: Memory<F, H>,
+    timestamp: FieldAssignment<F>,
+    trace: FieldAssignment<F>,
+    reads: Vec<[FieldAssignment<F>; 3]>,
+    writes: Vec<[FieldAssignment<F>; 3]>,
+    carried: Option<MemoryStateAssignment<F>>,
+}
+
+impl<F: PrimeField, H: TwoToOneHash<F>> RamAssignment<F, H> {
+    /// Memory of a single circuit with the contents `init`
+    pub fn new<C: CircuitDriver<Scalar = F>>(
+        cs: &mut R1cs<C>,
+        init: &[FieldAssignment<F>],
+    ) -> Self {
+        let values = init.iter().map(|x| x.value(cs)).collect::<Vec<_>>();
+        let memory = Memory::<F, H>::new(&values);
+        let trace = init
+            .iter()
+            .fold(FieldAssignment::constant(&F::zero()), |trace, value| {
+                memory.hasher.hash_assignment(cs, trace, value.clone())
+            });
+        let writes = init
+            .iter()
+            .enumerate()
+            .map(|(address, value)| {
+                [
+                    FieldAssignment::constant(&F::from(address as u64)),
+                    value.clone(),
+                    FieldAssignment::constant(&F::zero()),
+                ]
+            })
+            .collect();
+
+        Self {
+            memory,
+            timestamp: FieldAssignment::constant(&F::one()),
+            trace,
+            reads: Vec::new(),
+            writes,
+            carried: None,
+        }
+    }
+
+    /// Continue the memory of the previous step from the state carried by the step
+    pub fn resume(memory: Memory<F, H>, state: MemoryStateAssignment<F>) -> Self {
+        Self {
+            memory,
+            timestamp: state.timestamp.clone(),
+            trace: state.trace.clone(),
+            reads: Vec::new(),
+            writes: Vec::new(),
+            carried: Some(state),
+        }
+    }
+
+    pub fn read<C: CircuitDriver<Scalar = F>>(
+        &mut self,
+        cs: &mut R1cs<C>,
+        address: &FieldAssignment<F>,
+    ) -> FieldAssignment<F> {
+        self.access(cs, address, None)
+    }
+
+    pub fn write<C: CircuitDriver<Scalar = F>>(
+        &mut self,
+        cs: &mut R1cs<C>,
+        address: &FieldAssignment<F>,
+        value: &FieldAssignment<F>,
+    ) {
+        self.access(cs, address, Some(value));
+    }
+
+    /// Enforce the consistency of all accesses of a memory created by `new`
+    pub fn finalize<C: CircuitDriver<Scalar = F>>(mut self, cs: &mut R1cs<C>) {
+        assert!(
+            self.carried.is_none(),
+            "a resumed memory is checked by the verifier"
+        );
+        let audit = self
+            .memory
+            .values
+            .clone()
+            .into_iter()
+            .zip(self.memory.timestamps.clone())
+            .enumerate()
+            .map(|(address, (value, timestamp))| {
+                [
+                    FieldAssignment::constant(&F::from(address as u64)),
+                    FieldAssignment::witness(cs, value),
+                    FieldAssignment::witness(cs, F::from(timestamp)),
+                ]
+            })
+            .collect::<Vec<_>>();
+        for [_, value, timestamp] in audit.iter() {
+            self.absorb(cs, value);
+            self.absorb(cs, timestamp);
+        }
+        self.reads.extend(audit);
+
+        let zero = FieldAssignment::constant(&F::zero());
+        let one = FieldAssignment::constant(&F::one());
+        let alpha = self
+            .memory
+            .hasher
+            .hash_assignment(cs, self.trace.clone(), zero);
+        let gamma = self
+            .memory
+            .hasher
+            .hash_assignment(cs, self.trace.clone(), one.clone());
+        let reads = multiset_hash(cs, &self.reads, &alpha, &gamma, one.clone());
+        let writes = multiset_hash(cs, &self.writes, &alpha, &gamma, one);
+        FieldAssignment::enforce_eq(cs, &reads, &writes);
+    }
+
+    /// Fold the accesses of this step into the carried state with its challenges,
+    /// and return the state and the memory for the next step
+    pub fn suspend<C: CircuitDriver<Scalar = F>>(
+        self,
+        cs: &mut R1cs<C>,
+    ) -> (MemoryStateAssignment<F>, Memory<F, H>) {
+        let state = self.carried.expect("only a resumed memory is carried over");
+        let reads = multiset_hash(cs, &self.reads, &state.alpha, &state.gamma, state.reads);
+        let writes = multiset_hash(cs, &self.writes, &state.alpha, &state.gamma, state.writes);
+
+        (
+            MemoryStateAssignment {
+                timestamp: self.timestamp,
+                trace: self.trace,
+                reads,
+                writes,
+                alpha: state.alpha,
+                gamma: state.gamma,
+            },
+            self.memory,
+        )
+    }
+
+    fn access<C: CircuitDriver<Scalar = F>>(
+        &mut self,
+        cs: &mut R1cs<C>,
+        address: &FieldAssignment<F>,
+        value: Option<&FieldAssignment<F>>,
+    ) -> FieldAssignment<F> {
+        let (old_value, old_timestamp) = self
+            .memory
+            .access(address.value(cs), value.map(|value| value.value(cs)));
+        let old_value = FieldAssignment::witness(cs, old_value);
+        let old_timestamp = FieldAssignment::witness(cs, F::from(old_timestamp));
+        let new_value = value.cloned().unwrap_or_else(|| old_value.clone());
+
+        // the tuple read was written before
+        let elapsed = &(&self.timestamp - &old_timestamp) - &FieldAssignment::constant(&F::one());
+        let bits = FieldAssignment::to_bits(cs, &elapsed, TIMESTAMP_BITS);
+        FieldAssignment::enforce_eq_bits(cs, &elapsed, &bits);
+
+        for element in [address, &old_value, &old_timestamp, &new_value] {
+            self.absorb(cs, element);
+        }
+        self.reads
+            .push([address.clone(), old_value.clone(), old_timestamp]);
+        self.writes
+            .push([address.clone(), new_value, self.timestamp.clone()]);
+        self.timestamp = &self.timestamp + &FieldAssignment::constant(&F::one());
+
+        old_value
+    }
+
+    fn absorb<C: CircuitDriver<Scalar = F>>(
+        &mut self,
+        cs: &mut R1cs<C>,
+        element: &FieldAssignment<F>,
+    ) {
+        self.trace = self
+            .memory
+            .hasher
+            .hash_assignment(cs, self.trace.clone(), element.clone());
+    }
+}
+
+/// Read only memory, checked like `RamAssignment`
+pub struct RomAssignment<F: PrimeField, H: TwoToOneHash<F>>(RamAssignment<F, H>);
+
+impl<F: PrimeField, H: TwoToOneHash<F>> RomAssignment<F, H> {
+    pub fn new<C: CircuitDriver<Scalar = F>>(
+        cs: &mut R1cs<C>,
+        contents: &[FieldAssignment<F>],
+    ) -> Self {
+        Self(RamAssignment::new(cs, contents))
+    }
+
+    pub fn resume(memory: Memory<F, H>, state: MemoryStateAssignment<F>) -> Self {
+        Self(RamAssignment::resume(memory, state))
+    }
+
+    pub fn read<C: CircuitDriver<Scalar = F>>(
+        &mut self,
+        cs: &mut R1cs<C>,
+        address: &FieldAssignment<F>,
+    ) -> FieldAssignment<F> {
+        self.0.read(cs, address)
+    }
+
+    pub fn finalize<C: CircuitDriver<Scalar = F>>(self, cs: &mut R1cs<C>) {
+        self.0.finalize(cs)
+    }
+
+    pub fn suspend<C: CircuitDriver<Scalar = F>>(
+        self,
+        cs: &mut R1cs<C>,
+    ) -> (MemoryStateAssignment<F>, Memory<F, H>) {
+        self.0.suspend(cs)
+    }
+}
+
+fn fingerprint<F: PrimeField>(alpha: F, address: F, value: F, timestamp: F) -> F {
+    address + alpha * value + alpha.square() * timestamp
+}
+
+fn challenges<F: PrimeField, H: TwoToOneHash<F>>(
+    hasher: &H,
+    trace: F,
+    values: &[F],
+    timestamps: &[u64],
+) -> (F, F) {
+    let trace = values
+        .iter()
+        .zip(timestamps)
+        .fold(trace, |trace, (value, timestamp)| {
+            let trace = hasher.hash(trace, *value);
+            hasher.hash(trace, F::from(*timestamp))
+        });
+
+    (hasher.hash(trace, F::zero()), hasher.hash(trace, F::one()))
+}
+
+/// `acc * prod (gamma - fingerprint)` over the tuples
+/// constraints: 3 per tuple
+fn multiset_hash<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    tuples: &[[FieldAssignment<F>; 3]],
+    alpha: &FieldAssignment<F>,
+    gamma: &FieldAssignment<F>,
+    acc: FieldAssignment<F>,
+) -> FieldAssignment<F> {
+    let alpha_square = FieldAssignment::square(cs, alpha);
+    tuples.iter().fold(acc, |acc, [address, value, timestamp]| {
+        let fingerprint = &(address + &FieldAssignment::mul(cs, alpha, value))
+            + &FieldAssignment::mul(cs, &alpha_square, timestamp);
+        FieldAssignment::mul(cs, &acc, &(gamma - &fingerprint))
+    })
+}
+
+/// Index of an address below `len`
+fn to_index<F: PrimeField>(address: F, len: usize) -> Option<usize> {
+    let bits = address.to_bits(); // BE
+    let (high, low) = bits.split_at(bits.len() - 64);
+    if high.iter().any(|bit| *bit != 0) {
+        return None;
+    }
+    let index = low.iter().fold(0u64, |acc, bit| (acc << 1) | *bit as u64) as usize;
+
+    (index < len).then_some(index)
+}
@@ -0,0 +1,55 @@
+mod dense;
+mod path;
+mod sparse;
+
+pub use dense::DenseMerkleTree;
+pub use path::{MerklePath, MerklePathAssignment};
+pub use sparse::SparseMerkleTree;
+
+use crate::gadget::MimcAssignment;
+use crate::hash::{Mimc, MIMC_ROUNDS};
+
+use zkstd::circuit::prelude::{CircuitDriver, FieldAssignment, R1cs};
+use zkstd::common::PrimeField;
+
+/// Two-to-one compression used to build Merkle trees, natively and in circuit
+pub trait TwoToOneHash<F: PrimeField>: Default {
+    fn hash(&self, left: F, right: F) -> F;
+
+    fn hash_assignment<C: CircuitDriver<Scalar = F>>(
+        &self,
+        cs: &mut R1cs<C>,
+        left: FieldAssignment<F>,
+        right: FieldAssignment<F>,
+    ) -> FieldAssignment<F>;
+}
+
+/// MiMC in Feistel mode as used by the Nova transcript
+pub struct MimcHash<F: PrimeField> {
+    native: Mimc<MIMC_ROUNDS, F>,
+    assignment: MimcAssignment<MIMC_ROUNDS, F>,
+}
+
+impl<F: PrimeField> Default for MimcHash<F> {
+    fn default() -> Self {
+        Self {
+            native: Mimc::default(),
+            assignment: MimcAssignment::default(),
+        }
+    }
+}
+
+impl<F: PrimeField> TwoToOneHash<F> for MimcHash<F> {
+    fn hash(&self, left: F, right: F) -> F {
+        self.native.hash(left, right)
+    }
+
+    fn hash_assignment<C: CircuitDriver<Scalar = F>>(
+        &self,
+        cs: &mut R1cs<C>,
+        left: FieldAssignment<F>,
+        right: FieldAssignment<F>,
+    ) -> FieldAssignment<F> {
+        self.assignment.hash(cs, left, right)
+    }
+}
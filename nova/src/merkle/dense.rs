@@ -0,0 +1,157 @@
+use super::{MerklePath, TwoToOneHash};
+
+use zkstd::common::PrimeField;
+
+/// Merkle tree storing every node of `2^depth` leaves, empty leaves are zero
+pub struct DenseMerkleTree<F: PrimeField, H: TwoToOneHash<F>> {
+    hasher: H,
+    // layers[0] are the leaves, the last layer is the root
+    layers: Vec<Vec<F>>,
+    next_index: usize,
+}
+
+impl<F: PrimeField, H: TwoToOneHash<F>> DenseMerkleTree<F, H> {
+    pub fn new(depth: usize) -> Self {
+        Self::from_leaves(depth, &[])
+    }
+
+    /// Build a tree with the given leaves at the first positions
+    pub fn from_leaves(depth: usize, leaves: &[F]) -> Self {
+        assert!(depth < usize::BITS as usize && leaves.len() <= 1 << depth);
+        let hasher = H::default();
+        let mut layer = leaves.to_vec();
+        layer.resize(1 << depth, F::zero());
+
+        let mut layers = vec![layer];
+        for _ in 0..depth {
+            let layer = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| hasher.hash(pair[0], pair[1]))
+                .collect();
+            layers.push(layer);
+        }
+
+        Self {
+            hasher,
+            layers,
+            next_index: leaves.len(),
+        }
+    }
+
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    pub fn depth(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    pub fn root(&self) -> F {
+        self.layers[self.depth()][0]
+    }
+
+    pub fn leaf(&self, index: usize) -> F {
+        self.layers[0][index]
+    }
+
+    /// Append a leaf at the next free position and return its index
+    pub fn insert(&mut self, leaf: F) -> usize {
+        let index = self.next_index;
+        assert!(index < self.layers[0].len(), "merkle tree is full");
+        self.set(index, leaf);
+        self.next_index += 1;
+        index
+    }
+
+    /// Replace a leaf that was already inserted
+    pub fn update(&mut self, index: usize, leaf: F) {
+        assert!(index < self.next_index, "merkle leaf is not inserted");
+        self.set(index, leaf);
+    }
+
+    fn set(&mut self, index: usize, leaf: F) {
+        self.layers[0][index] = leaf;
+        let mut index = index;
+        for level in 0..self.depth() {
+            let pair = index & !1;
+            let node = self
+                .hasher
+                .hash(self.layers[level][pair], self.layers[level][pair + 1]);
+            index >>= 1;
+            self.layers[level + 1][index] = node;
+        }
+    }
+
+    pub fn auth_path(&self, index: usize) -> MerklePath<F> {
+        let siblings = (0..self.depth())
+            .map(|level| self.layers[level][(index >> level) ^ 1])
+            .collect();
+
+        MerklePath {
+            index: index as u64,
+            siblings,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DenseMerkleTree;
+    use crate::merkle::{MimcHash, TwoToOneHash};
+
+    use bn_254::Fr;
+    use rand_core::OsRng;
+    use zkstd::common::Group;
+
+    #[test]
+    fn dense_merkle_tree_test() {
+        let mut rng = OsRng;
+        let leaves: Vec<Fr> = (0..5).map(|_| Fr::random(&mut rng)).collect();
+        let mut tree = DenseMerkleTree::<Fr, MimcHash<Fr>>::from_leaves(3, &leaves);
+
+        let mut appended = DenseMerkleTree::<Fr, MimcHash<Fr>>::new(3);
+        for leaf in leaves.iter() {
+            appended.insert(*leaf);
+        }
+        assert_eq!(tree.root(), appended.root());
+
+        let hasher = MimcHash::default();
+        let level = |nodes: &[Fr]| -> Vec<Fr> {
+            nodes
+                .chunks(2)
+                .map(|pair| hasher.hash(pair[0], pair[1]))
+                .collect()
+        };
+        let mut nodes = leaves.clone();
+        nodes.resize(8, Fr::zero());
+        let root = level(&level(&level(&nodes)))[0];
+        assert_eq!(tree.root(), root);
+
+        for index in 0..8 {
+            let path = tree.auth_path(index);
+            assert!(path.verify(tree.hasher(), tree.leaf(index), tree.root()));
+        }
+
+        let leaf = Fr::random(&mut rng);
+        let old_root = tree.root();
+        let path = tree.auth_path(6);
+        assert_eq!(tree.insert(leaf), 5);
+        // an empty leaf keeps the root
+        assert_eq!(tree.insert(Fr::zero()), 6);
+        tree.update(6, leaf);
+        assert_ne!(tree.root(), old_root);
+        assert!(!path.verify(tree.hasher(), leaf, tree.root()));
+        assert!(tree.auth_path(6).verify(tree.hasher(), leaf, tree.root()));
+        assert_eq!(tree.auth_path(7).siblings[0], leaf);
+        assert_eq!(tree.auth_path(6).siblings[1], tree.auth_path(7).siblings[1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "merkle leaf is not inserted")]
+    fn dense_merkle_update_out_of_range_test() {
+        let mut tree = DenseMerkleTree::<Fr, MimcHash<Fr>>::from_leaves(3, &[Fr::one()]);
+        tree.update(1, Fr::one());
+    }
+}
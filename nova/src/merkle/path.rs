@@ -0,0 +1,200 @@
+use super::TwoToOneHash;
+
+use zkstd::circuit::prelude::{BinaryAssignment, CircuitDriver, FieldAssignment, R1cs};
+use zkstd::common::PrimeField;
+
+/// Authentication path of a leaf, siblings ordered from the leaf level to the root
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerklePath<F: PrimeField> {
+    pub index: u64,
+    pub siblings: Vec<F>,
+}
+
+impl<F: PrimeField> MerklePath<F> {
+    pub fn depth(&self) -> usize {
+        self.siblings.len()
+    }
+
+    pub fn compute_root<H: TwoToOneHash<F>>(&self, hasher: &H, leaf: F) -> F {
+        self.siblings
+            .iter()
+            .enumerate()
+            .fold(leaf, |node, (level, sibling)| {
+                if (self.index >> level) & 1 == 1 {
+                    hasher.hash(*sibling, node)
+                } else {
+                    hasher.hash(node, *sibling)
+                }
+            })
+    }
+
+    pub fn verify<H: TwoToOneHash<F>>(&self, hasher: &H, leaf: F, root: F) -> bool {
+        self.compute_root(hasher, leaf) == root
+    }
+}
+
+/// Authentication path allocated as witness with a boolean constrained index
+#[derive(Clone)]
+pub struct MerklePathAssignment<F: PrimeField> {
+    index: FieldAssignment<F>,
+    // little-endian, i.e. the leaf level first
+    index_bits: Vec<BinaryAssignment>,
+    siblings: Vec<FieldAssignment<F>>,
+}
+
+impl<F: PrimeField> MerklePathAssignment<F> {
+    pub fn witness<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, path: &MerklePath<F>) -> Self {
+        let index = FieldAssignment::witness(cs, F::from(path.index));
+        // the root of an empty path is the leaf itself, at index zero
+        let index_bits = if path.depth() == 0 {
+            FieldAssignment::enforce_eq_constant(cs, &index, &F::zero());
+            vec![]
+        } else {
+            let mut index_bits = FieldAssignment::to_bits(cs, &index, path.depth());
+            FieldAssignment::enforce_eq_bits(cs, &index, &index_bits);
+            index_bits.reverse();
+            index_bits
+        };
+        let siblings = path
+            .siblings
+            .iter()
+            .map(|sibling| FieldAssignment::witness(cs, *sibling))
+            .collect();
+
+        Self {
+            index,
+            index_bits,
+            siblings,
+        }
+    }
+
+    /// Position of the leaf, can be bound to a public input
+    pub fn index(&self) -> &FieldAssignment<F> {
+        &self.index
+    }
+
+    /// constraints: depth * (hash + 1)
+    pub fn compute_root<C: CircuitDriver<Scalar = F>, H: TwoToOneHash<F>>(
+        &self,
+        cs: &mut R1cs<C>,
+        hasher: &H,
+        leaf: &FieldAssignment<F>,
+    ) -> FieldAssignment<F> {
+        let mut node = leaf.clone();
+        for (bit, sibling) in self.index_bits.iter().zip(self.siblings.iter()) {
+            // left = bit ? sibling : node, right = bit ? node : sibling
+            let swap = FieldAssignment::mul(cs, &FieldAssignment::from(bit), &(sibling - &node));
+            let left = &node + &swap;
+            let right = &(&node + sibling) - &left;
+            node = hasher.hash_assignment(cs, left, right);
+        }
+        node
+    }
+
+    pub fn enforce_membership<C: CircuitDriver<Scalar = F>, H: TwoToOneHash<F>>(
+        &self,
+        cs: &mut R1cs<C>,
+        hasher: &H,
+        leaf: &FieldAssignment<F>,
+        root: &FieldAssignment<F>,
+    ) {
+        let computed = self.compute_root(cs, hasher, leaf);
+        FieldAssignment::enforce_eq(cs, &computed, root);
+    }
+
+    /// Check that `old_leaf` is in the tree of `old_root` and return the root after
+    /// replacing it with `new_leaf` at the same position
+    pub fn update_root<C: CircuitDriver<Scalar = F>, H: TwoToOneHash<F>>(
+        &self,
+        cs: &mut R1cs<C>,
+        hasher: &H,
+        old_leaf: &FieldAssignment<F>,
+        new_leaf: &FieldAssignment<F>,
+        old_root: &FieldAssignment<F>,
+    ) -> FieldAssignment<F> {
+        self.enforce_membership(cs, hasher, old_leaf, old_root);
+        self.compute_root(cs, hasher, new_leaf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MerklePathAssignment;
+    use crate::driver::Bn254Driver;
+    use crate::merkle::{DenseMerkleTree, MimcHash, SparseMerkleTree};
+
+    use bn_254::Fr;
+    use rand_core::{OsRng, RngCore};
+    use zkstd::circuit::prelude::{FieldAssignment, R1cs};
+    use zkstd::common::Group;
+
+    #[test]
+    fn membership_gadget_test() {
+        let mut rng = OsRng;
+        let leaves: Vec<Fr> = (0..8).map(|_| Fr::random(&mut rng)).collect();
+        let tree = DenseMerkleTree::<Fr, MimcHash<Fr>>::from_leaves(3, &leaves);
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let mut cs: R1cs<Bn254Driver> = R1cs::default();
+            let root = FieldAssignment::instance(&mut cs, tree.root());
+            let leaf = FieldAssignment::witness(&mut cs, *leaf);
+            let path = MerklePathAssignment::witness(&mut cs, &tree.auth_path(index));
+            path.enforce_membership(&mut cs, tree.hasher(), &leaf, &root);
+            assert_eq!(path.index().value(&cs), Fr::from(index as u64));
+            assert!(cs.is_sat());
+
+            // the path of a different position does not open the leaf
+            let mut ncs: R1cs<Bn254Driver> = R1cs::default();
+            let root = FieldAssignment::instance(&mut ncs, tree.root());
+            let leaf = FieldAssignment::witness(&mut ncs, tree.leaf(index));
+            let path = MerklePathAssignment::witness(&mut ncs, &tree.auth_path(index ^ 1));
+            path.enforce_membership(&mut ncs, tree.hasher(), &leaf, &root);
+            assert!(!ncs.is_sat());
+        }
+    }
+
+    #[test]
+    fn empty_path_gadget_test() {
+        let leaf = Fr::random(&mut OsRng);
+        let tree = DenseMerkleTree::<Fr, MimcHash<Fr>>::from_leaves(0, &[leaf]);
+        assert_eq!(tree.root(), leaf);
+
+        let mut cs: R1cs<Bn254Driver> = R1cs::default();
+        let root = FieldAssignment::instance(&mut cs, tree.root());
+        let leaf = FieldAssignment::witness(&mut cs, leaf);
+        let path = MerklePathAssignment::witness(&mut cs, &tree.auth_path(0));
+        path.enforce_membership(&mut cs, tree.hasher(), &leaf, &root);
+        assert!(cs.is_sat());
+
+        // a single leaf has no other position
+        let mut ncs: R1cs<Bn254Driver> = R1cs::default();
+        let mut path = tree.auth_path(0);
+        path.index = 1;
+        MerklePathAssignment::witness(&mut ncs, &path);
+        assert!(!ncs.is_sat());
+    }
+
+    #[test]
+    fn update_root_gadget_test() {
+        let mut rng = OsRng;
+        let mut tree = SparseMerkleTree::<Fr, MimcHash<Fr>>::new(16);
+        for _ in 0..4 {
+            tree.update(rng.next_u64() % (1 << 16), Fr::random(&mut rng));
+        }
+        let index = rng.next_u64() % (1 << 16);
+        let old_leaf = tree.leaf(index);
+        let new_leaf = Fr::random(&mut rng);
+        let old_root = tree.root();
+        let path = tree.auth_path(index);
+        tree.update(index, new_leaf);
+
+        let mut cs: R1cs<Bn254Driver> = R1cs::default();
+        let old_root = FieldAssignment::instance(&mut cs, old_root);
+        let old_leaf = FieldAssignment::witness(&mut cs, old_leaf);
+        let new_leaf = FieldAssignment::witness(&mut cs, new_leaf);
+        let path = MerklePathAssignment::witness(&mut cs, &path);
+        let new_root = path.update_root(&mut cs, tree.hasher(), &old_leaf, &new_leaf, &old_root);
+        assert_eq!(new_root.value(&cs), tree.root());
+        assert!(cs.is_sat());
+    }
+}
@@ -0,0 +1,135 @@
+use super::{MerklePath, TwoToOneHash};
+
+use std::collections::BTreeMap;
+use zkstd::common::PrimeField;
+
+/// Merkle tree over `2^depth` positions which only stores the nodes of non empty subtrees.
+/// Empty leaves are zero and empty subtrees hash to precomputed defaults.
+pub struct SparseMerkleTree<F: PrimeField, H: TwoToOneHash<F>> {
+    hasher: H,
+    depth: usize,
+    // root of an empty subtree for each level
+    defaults: Vec<F>,
+    // (level, index) to node
+    nodes: BTreeMap<(usize, u64), F>,
+}
+
+impl<F: PrimeField, H: TwoToOneHash<F>> SparseMerkleTree<F, H> {
+    pub fn new(depth: usize) -> Self {
+        assert!(depth <= 64);
+        let hasher = H::default();
+        let mut defaults = vec![F::zero()];
+        for level in 0..depth {
+            defaults.push(hasher.hash(defaults[level], defaults[level]));
+        }
+
+        Self {
+            hasher,
+            depth,
+            defaults,
+            nodes: BTreeMap::new(),
+        }
+    }
+
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn root(&self) -> F {
+        self.node(self.depth, 0)
+    }
+
+    pub fn leaf(&self, index: u64) -> F {
+        self.node(0, index)
+    }
+
+    /// Insert or overwrite the leaf at `index`, a zero leaf removes it
+    pub fn update(&mut self, index: u64, leaf: F) {
+        assert!(self.depth == 64 || index >> self.depth == 0);
+        self.set(0, index, leaf);
+        let mut index = index;
+        for level in 0..self.depth {
+            let pair = index & !1;
+            let node = self
+                .hasher
+                .hash(self.node(level, pair), self.node(level, pair + 1));
+            index >>= 1;
+            self.set(level + 1, index, node);
+        }
+    }
+
+    pub fn auth_path(&self, index: u64) -> MerklePath<F> {
+        let siblings = (0..self.depth)
+            .map(|level| self.node(level, (index >> level) ^ 1))
+            .collect();
+
+        MerklePath { index, siblings }
+    }
+
+    fn node(&self, level: usize, index: u64) -> F {
+        self.nodes
+            .get(&(level, index))
+            .copied()
+            .unwrap_or(self.defaults[level])
+    }
+
+    fn set(&mut self, level: usize, index: u64, node: F) {
+        if node == self.defaults[level] {
+            self.nodes.remove(&(level, index));
+        } else {
+            self.nodes.insert((level, index), node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SparseMerkleTree;
+    use crate::merkle::{DenseMerkleTree, MimcHash};
+
+    use bn_254::Fr;
+    use rand_core::{OsRng, RngCore};
+    use zkstd::common::Group;
+
+    #[test]
+    fn sparse_matches_dense_test() {
+        let mut rng = OsRng;
+        let mut sparse = SparseMerkleTree::<Fr, MimcHash<Fr>>::new(4);
+        // every leaf is inserted as empty so that any index can be updated
+        let mut dense = DenseMerkleTree::<Fr, MimcHash<Fr>>::from_leaves(4, &[Fr::zero(); 16]);
+        assert_eq!(sparse.root(), dense.root());
+
+        for _ in 0..10 {
+            let index = rng.next_u64() % 16;
+            let leaf = Fr::random(&mut rng);
+            sparse.update(index, leaf);
+            dense.update(index as usize, leaf);
+            assert_eq!(sparse.root(), dense.root());
+            assert_eq!(sparse.auth_path(index), dense.auth_path(index as usize));
+        }
+    }
+
+    #[test]
+    fn sparse_merkle_tree_test() {
+        let mut rng = OsRng;
+        let mut tree = SparseMerkleTree::<Fr, MimcHash<Fr>>::new(64);
+        let empty_root = tree.root();
+
+        let index = rng.next_u64();
+        let leaf = Fr::random(&mut rng);
+        tree.update(index, leaf);
+        let path = tree.auth_path(index);
+        assert!(path.verify(tree.hasher(), leaf, tree.root()));
+        // absence of a neighbour is proven with a zero leaf
+        let neighbour = tree.auth_path(index ^ 1);
+        assert!(neighbour.verify(tree.hasher(), Fr::zero(), tree.root()));
+
+        tree.update(index, Fr::zero());
+        assert_eq!(tree.root(), empty_root);
+        assert!(tree.nodes.is_empty());
+    }
+}
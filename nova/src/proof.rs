@@ -41,7 +41,7 @@ where
     FC1: FunctionCircuit<E1::Scalar>,
     FC2: FunctionCircuit<E2::Scalar>,
 {
-    pub fn verify(&self, pp: &PublicParams<E1, E2, FC1, FC2>) -> bool {
+    pub fn verify<const AFFINE: bool>(&self, pp: &PublicParams<E1, E2, FC1, FC2, AFFINE>) -> bool {
         let (
             (u_single_secondary, w_single_secondary),
             (u_range_primary, w_range_primary),
@@ -0,0 +1,234 @@
+mod gadget;
+
+pub use gadget::SignatureAssignment;
+
+use crate::merkle::TwoToOneHash;
+
+use blake2b_simd::Params;
+use rand_core::RngCore;
+use zkstd::common::{
+    CurveGroup, Group, PrimeField, RedDSA, SigUtils, TwistedEdwardsAffine, TwistedEdwardsExtended,
+};
+
+const NONCE_PERSONAL: &[u8; 16] = b"Kogarashi_RedDSA";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SecretKey<P: RedDSA>(P::Scalar);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey<P: RedDSA>(P::Affine);
+
+/// Signature encoded as `R || S`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature<P: RedDSA> {
+    r: P::Affine,
+    s: P::Scalar,
+}
+
+impl<P: RedDSA> SecretKey<P> {
+    pub fn new(secret: P::Scalar) -> Self {
+        Self(secret)
+    }
+
+    pub fn random(rng: &mut impl RngCore) -> Self {
+        Self(P::Scalar::random(rng))
+    }
+
+    pub fn to_public_key(&self) -> PublicKey<P> {
+        PublicKey((P::Affine::ADDITIVE_GENERATOR * self.0).into())
+    }
+
+    /// Re-randomize the key by `alpha`, matching `PublicKey::randomize`
+    pub fn randomize(&self, alpha: P::Scalar) -> Self {
+        Self(self.0 + alpha)
+    }
+
+    /// Sign a message of base field elements with a nonce hedged by `rng`
+    pub fn sign<H: TwoToOneHash<P::Base>>(
+        &self,
+        hasher: &H,
+        message: &[P::Base],
+        rng: &mut impl RngCore,
+    ) -> Signature<P> {
+        let public_key = self.to_public_key();
+
+        // r = H*(T || vk || M) for 80 random bytes T
+        let mut t = [0u8; 80];
+        rng.fill_bytes(&mut t);
+        let mut state = Params::new()
+            .hash_length(64)
+            .personal(NONCE_PERSONAL)
+            .to_state();
+        state.update(&t);
+        state.update(&public_key.to_bytes());
+        for m in message {
+            state.update(&m.to_bytes());
+        }
+        let r = P::Scalar::from_bytes_wide(state.finalize().as_array());
+
+        let big_r: P::Affine = (P::Affine::ADDITIVE_GENERATOR * r).into();
+        let c = challenge::<P, H>(hasher, big_r, public_key.0, message);
+
+        Signature {
+            r: big_r,
+            s: r + c * self.0,
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    pub fn from_bytes(bytes: [u8; 32]) -> Option<Self> {
+        P::Scalar::from_bytes(bytes).map(Self)
+    }
+}
+
+impl<P: RedDSA> PublicKey<P> {
+    pub fn new(point: P::Affine) -> Self {
+        Self(point)
+    }
+
+    pub fn point(&self) -> P::Affine {
+        self.0
+    }
+
+    /// Re-randomize the key by `alpha`, matching `SecretKey::randomize`
+    pub fn randomize(&self, alpha: P::Scalar) -> Self {
+        Self((P::Affine::ADDITIVE_GENERATOR * alpha + self.0).into())
+    }
+
+    /// Check the cofactored equation `[h]([S]G - R - [c]vk) = 0`
+    pub fn verify<H: TwoToOneHash<P::Base>>(
+        &self,
+        hasher: &H,
+        message: &[P::Base],
+        signature: &Signature<P>,
+    ) -> bool {
+        let c = challenge::<P, H>(hasher, signature.r, self.0, message);
+        let mut check =
+            P::Affine::ADDITIVE_GENERATOR * signature.s - signature.r.to_extended() - self.0 * c;
+        for _ in 0..P::COFACTOR_BITS {
+            check = check.double();
+        }
+        check.is_identity()
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    pub fn from_bytes(bytes: [u8; 32]) -> Option<Self> {
+        P::Affine::from_bytes(bytes).map(Self)
+    }
+}
+
+impl<P: RedDSA> Signature<P> {
+    pub fn r(&self) -> P::Affine {
+        self.r
+    }
+
+    pub fn s(&self) -> P::Scalar {
+        self.s
+    }
+
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&self.r.to_bytes());
+        bytes[32..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+
+    /// Reject a non canonical `S` to prevent malleability
+    pub fn from_bytes(bytes: [u8; 64]) -> Option<Self> {
+        let r = P::Affine::from_bytes(bytes[..32].try_into().unwrap())?;
+        let s = P::Scalar::from_bytes(bytes[32..].try_into().unwrap())?;
+        Some(Self { r, s })
+    }
+}
+
+/// c = H(H(H(H(R.x, R.y), vk.x), vk.y), M) chained over the message elements
+pub(crate) fn challenge<P: RedDSA, H: TwoToOneHash<P::Base>>(
+    hasher: &H,
+    r: P::Affine,
+    public_key: P::Affine,
+    message: &[P::Base],
+) -> P::Scalar {
+    let c = [public_key.get_x(), public_key.get_y()]
+        .iter()
+        .chain(message.iter())
+        .fold(hasher.hash(r.get_x(), r.get_y()), |acc, m| {
+            hasher.hash(acc, *m)
+        });
+    // reduce the hash modulo the subgroup order
+    let mut bytes = [0; 64];
+    bytes[..32].copy_from_slice(&c.to_bytes());
+    P::Scalar::from_bytes_wide(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PublicKey, SecretKey, Signature};
+    use crate::merkle::MimcHash;
+
+    use baby_jubjub::{BabyJubjub, Fs};
+    use bn_254::Fr;
+    use rand_core::OsRng;
+    use zkstd::common::Group;
+
+    #[test]
+    fn sign_verify_test() {
+        let mut rng = OsRng;
+        let hasher = MimcHash::default();
+        let message: Vec<Fr> = (0..3).map(|_| Fr::random(&mut rng)).collect();
+        let secret_key = SecretKey::<BabyJubjub>::random(&mut rng);
+        let public_key = secret_key.to_public_key();
+
+        let signature = secret_key.sign(&hasher, &message, &mut rng);
+        assert!(public_key.verify(&hasher, &message, &signature));
+
+        let bytes = signature.to_bytes();
+        assert_eq!(Signature::from_bytes(bytes), Some(signature));
+        assert_eq!(
+            PublicKey::from_bytes(public_key.to_bytes()),
+            Some(public_key)
+        );
+        assert_eq!(
+            SecretKey::from_bytes(secret_key.to_bytes()),
+            Some(secret_key)
+        );
+
+        // nonces are fresh
+        assert_ne!(secret_key.sign(&hasher, &message, &mut rng), signature);
+
+        let mut tampered = message.clone();
+        tampered[1] += Fr::one();
+        assert!(!public_key.verify(&hasher, &tampered, &signature));
+        let other = SecretKey::<BabyJubjub>::random(&mut rng).to_public_key();
+        assert!(!other.verify(&hasher, &message, &signature));
+        let forged = Signature::<BabyJubjub> {
+            r: signature.r,
+            s: signature.s + Fs::one(),
+        };
+        assert!(!public_key.verify(&hasher, &message, &forged));
+    }
+
+    #[test]
+    fn randomize_test() {
+        let mut rng = OsRng;
+        let hasher = MimcHash::default();
+        let message = [Fr::random(&mut rng)];
+        let secret_key = SecretKey::<BabyJubjub>::random(&mut rng);
+        let public_key = secret_key.to_public_key();
+        let alpha = Fs::random(&mut rng);
+
+        let randomized = secret_key.randomize(alpha);
+        assert_eq!(randomized.to_public_key(), public_key.randomize(alpha));
+
+        let signature = randomized.sign(&hasher, &message, &mut rng);
+        assert!(public_key
+            .randomize(alpha)
+            .verify(&hasher, &message, &signature));
+        assert!(!public_key.verify(&hasher, &message, &signature));
+    }
+}
@@ -0,0 +1,127 @@
+use super::Signature;
+use crate::merkle::TwoToOneHash;
+
+use zkstd::circuit::prelude::{
+    BinaryAssignment, CircuitDriver, EdwardsPointAssignment, FieldAssignment, R1cs,
+};
+use zkstd::common::{Group, IntGroup, PrimeField, RedDSA, Ring};
+
+/// Signature allocated as witness, `S` is constrained to be canonical
+#[derive(Clone)]
+pub struct SignatureAssignment<P: RedDSA> {
+    r: EdwardsPointAssignment<P::Affine>,
+    // big-endian
+    s: Vec<BinaryAssignment>,
+}
+
+impl<P: RedDSA> SignatureAssignment<P> {
+    pub fn witness<C: CircuitDriver<Scalar = P::Base>>(
+        cs: &mut R1cs<C>,
+        signature: &Signature<P>,
+    ) -> Self {
+        let r = EdwardsPointAssignment::witness(cs, signature.r);
+        r.enforce_on_curve(cs);
+
+        // S < l
+        let num_bits = scalar_bits::<P>();
+        let order: P::Base = (P::Scalar::zero() - P::Scalar::one()).into();
+        let order = FieldAssignment::constant(&(order + P::Base::one()));
+        let s = FieldAssignment::witness(cs, signature.s.into());
+        let s_bits = FieldAssignment::to_bits(cs, &s, num_bits);
+        FieldAssignment::enforce_eq_bits(cs, &s, &s_bits);
+        let is_canonical = FieldAssignment::less_than(cs, &s, &order, num_bits);
+        FieldAssignment::enforce_eq_constant(
+            cs,
+            &FieldAssignment::from(&is_canonical),
+            &P::Base::one(),
+        );
+
+        Self { r, s: s_bits }
+    }
+
+    /// Enforce the cofactored equation `[h]([S]G - R - [c]vk) = 0` with the challenge
+    /// hashed as by `PublicKey::verify`
+    pub fn enforce_valid<C: CircuitDriver<Scalar = P::Base>, H: TwoToOneHash<P::Base>>(
+        &self,
+        cs: &mut R1cs<C>,
+        hasher: &H,
+        public_key: &EdwardsPointAssignment<P::Affine>,
+        message: &[FieldAssignment<P::Base>],
+    ) {
+        public_key.enforce_on_curve(cs);
+
+        let c = [public_key.get_x(), public_key.get_y()]
+            .into_iter()
+            .chain(message.iter().cloned())
+            .fold(
+                hasher.hash_assignment(cs, self.r.get_x(), self.r.get_y()),
+                |acc, m| hasher.hash_assignment(cs, acc, m),
+            );
+
+        let s_g = EdwardsPointAssignment::fixed_base_scalar_point(
+            cs,
+            P::Affine::ADDITIVE_GENERATOR,
+            &self.s,
+        );
+        let c_vk = public_key.scalar_point(cs, &c);
+        let rhs = self.r.add(&c_vk, cs);
+        let mut check = s_g.add(&rhs.neg(), cs);
+        for _ in 0..P::COFACTOR_BITS {
+            check = check.double(cs);
+        }
+        EdwardsPointAssignment::enforce_equal(cs, &check, &EdwardsPointAssignment::identity());
+    }
+}
+
+/// Bit length of the scalar field modulus
+fn scalar_bits<P: RedDSA>() -> usize {
+    let bits = (P::Scalar::zero() - P::Scalar::one()).to_bits();
+    bits.len() - bits.iter().take_while(|bit| **bit == 0).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SignatureAssignment;
+    use crate::driver::Bn254Driver;
+    use crate::merkle::MimcHash;
+    use crate::reddsa::SecretKey;
+
+    use baby_jubjub::{BabyJubjub, Fs};
+    use bn_254::Fr;
+    use rand_core::OsRng;
+    use zkstd::circuit::prelude::{EdwardsPointAssignment, FieldAssignment, R1cs};
+    use zkstd::common::Group;
+
+    #[test]
+    fn signature_gadget_test() {
+        let mut rng = OsRng;
+        let hasher = MimcHash::default();
+        let message: Vec<Fr> = (0..2).map(|_| Fr::random(&mut rng)).collect();
+        let secret_key = SecretKey::<BabyJubjub>::random(&mut rng);
+        let alpha = Fs::random(&mut rng);
+        let public_key = secret_key.to_public_key().randomize(alpha);
+        let signature = secret_key
+            .randomize(alpha)
+            .sign(&hasher, &message, &mut rng);
+
+        for (key, tampered, sat) in [
+            (public_key, false, true),
+            (secret_key.to_public_key(), false, false),
+            (public_key, true, false),
+        ] {
+            let mut cs: R1cs<Bn254Driver> = R1cs::default();
+            let key = EdwardsPointAssignment::instance(&mut cs, key.point());
+            let mut message = message.clone();
+            if tampered {
+                message[0] += Fr::one();
+            }
+            let message: Vec<_> = message
+                .iter()
+                .map(|m| FieldAssignment::instance(&mut cs, *m))
+                .collect();
+            let signature = SignatureAssignment::witness(&mut cs, &signature);
+            signature.enforce_valid(&mut cs, &hasher, &key, &message);
+            assert_eq!(cs.is_sat(), sat);
+        }
+    }
+}
@@ -1,9 +1,8 @@
 use crate::driver::scalar_as_base;
-use crate::gadget::{f_to_nat, nat_to_limbs, BN_LIMB_WIDTH, BN_N_LIMBS};
 use crate::hash::{MimcRO, HASH_BITS, MIMC_ROUNDS};
 use crate::{PedersenCommitment, R1csShape};
 use zkstd::circuit::prelude::CircuitDriver;
-use zkstd::common::{Decode, Encode, Group, IntGroup, Ring};
+use zkstd::common::{Decode, Encode, Group, IntGroup, PrimeField, Ring};
 use zkstd::matrix::DenseVectors;
 
 #[derive(Clone, Debug, Encode, Decode, PartialEq, Eq)]
@@ -107,10 +106,11 @@ impl<C: CircuitDriver> RelaxedR1csInstance<C> {
         transcript.append_point(self.commit_w);
         transcript.append_point(self.commit_e);
         transcript.append(scalar_as_base::<C>(self.u));
+        // the 64 bit limbs of the public inputs as emulated in the circuit
         for x in &self.x.get() {
-            let limbs = nat_to_limbs(&f_to_nat(x), BN_LIMB_WIDTH, BN_N_LIMBS);
-            for limb in limbs {
-                transcript.append(scalar_as_base::<C>(limb));
+            for limb in x.to_raw_bytes().chunks(8) {
+                let limb = u64::from_le_bytes(limb.try_into().unwrap());
+                transcript.append(C::Base::from(limb));
             }
         }
     }
@@ -1,10 +1,22 @@
+mod blake2;
+mod ecdsa;
+mod edwards;
+mod field_gadget;
+mod keccak;
+mod memory;
+mod nonnative;
+mod r1cs_export;
+mod r1cs_optimize;
+mod sha256;
+mod uint;
+
 use crate::function::FunctionCircuit;
 
 use core::marker::PhantomData;
 use zkstd::circuit::prelude::{CircuitDriver, FieldAssignment};
 use zkstd::common::{Decode, Encode, PrimeField};
 use zkstd::matrix::DenseVectors;
-use zkstd::r1cs::R1cs;
+use zkstd::r1cs::{R1cs, Wire};
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode)]
 pub(crate) struct ExampleFunction<Field: PrimeField> {
@@ -28,3 +40,19 @@ impl<F: PrimeField> FunctionCircuit<F> for ExampleFunction<F> {
         vec![&(&z_i_cube + &z_i[0]) + &five]
     }
 }
+
+/// Overwrite one wire of the assignment through the witness file
+pub(crate) fn tamper<C: CircuitDriver>(cs: &R1cs<C>, wire: &Wire, value: C::Scalar) -> R1cs<C> {
+    let mut wtns = vec![];
+    cs.write_wtns(&mut wtns);
+    let id = match wire {
+        Wire::Instance(i) => *i as usize,
+        Wire::Witness(i) => cs.l() + *i as usize,
+    };
+    let offset = wtns.len() - 32 * (cs.l() + cs.m_l_1() - id);
+    wtns[offset..offset + 32].copy_from_slice(&value.to_raw_bytes());
+
+    let mut tampered = cs.clone();
+    tampered.read_wtns(&wtns).unwrap();
+    tampered
+}
@@ -0,0 +1,93 @@
+use crate::driver::{Bn254Driver, GrumpkinDriver};
+
+use rand_core::{OsRng, RngCore};
+use zkstd::circuit::prelude::{blake2b, blake2s, CircuitDriver, R1cs, UInt8};
+
+fn witness_bytes<C: CircuitDriver>(cs: &mut R1cs<C>, message: &[u8]) -> Vec<UInt8<C::Scalar>> {
+    message
+        .iter()
+        .map(|b| UInt8::witness(cs, *b as u64))
+        .collect()
+}
+
+fn random_message(len: usize) -> Vec<u8> {
+    let mut message = vec![0u8; len];
+    OsRng.fill_bytes(&mut message);
+    message
+}
+
+#[test]
+fn blake2s_test() {
+    for (len, output_len, personal) in [
+        (0, 32, &b""[..]),
+        (3, 32, b"Zcash_"),
+        (64, 32, b"12345678"),
+        (65, 20, b""),
+    ] {
+        let message = random_message(len);
+        let expected = blake2s_simd::Params::new()
+            .hash_length(output_len)
+            .personal(personal)
+            .hash(&message);
+
+        let mut cs: R1cs<Bn254Driver> = R1cs::default();
+        let input = witness_bytes(&mut cs, &message);
+        let output = blake2s(&mut cs, &input, output_len, personal);
+        let output: Vec<u8> = output.iter().map(|b| b.value(&cs) as u8).collect();
+
+        assert_eq!(output, expected.as_bytes());
+        assert!(cs.is_sat());
+    }
+}
+
+#[test]
+fn blake2b_test() {
+    for (len, output_len, personal) in [
+        (0, 32, &b""[..]),
+        (3, 64, b"ZcashComputehash"),
+        (128, 32, b""),
+        (129, 32, b"substrate"),
+    ] {
+        let message = random_message(len);
+        let expected = blake2b_simd::Params::new()
+            .hash_length(output_len)
+            .personal(personal)
+            .hash(&message);
+
+        let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+        let input = witness_bytes(&mut cs, &message);
+        let output = blake2b(&mut cs, &input, output_len, personal);
+        let output: Vec<u8> = output.iter().map(|b| b.value(&cs) as u8).collect();
+
+        assert_eq!(output, expected.as_bytes());
+        assert!(cs.is_sat());
+    }
+}
+
+fn blake2s_cost(len: usize) -> u64 {
+    let message = random_message(len);
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let input = witness_bytes(&mut cs, &message);
+    let m = cs.m();
+    blake2s(&mut cs, &input, 32, b"");
+    cs.m() - m
+}
+
+fn blake2b_cost(len: usize) -> u64 {
+    let message = random_message(len);
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let input = witness_bytes(&mut cs, &message);
+    let m = cs.m();
+    blake2b(&mut cs, &input, 32, b"");
+    cs.m() - m
+}
+
+#[test]
+fn blake2_cost_test() {
+    assert_eq!(blake2s_cost(64), 21280);
+    assert_eq!(blake2b_cost(128), 50112);
+
+    // the chaining value of a further block is no longer constant
+    assert_eq!(blake2s_cost(128) - blake2s_cost(64), 21664);
+    assert_eq!(blake2b_cost(256) - blake2b_cost(128), 50880);
+}
@@ -0,0 +1,149 @@
+use crate::driver::Bn254Driver;
+
+use bn_254::{Fr, G1Affine};
+use rand_core::OsRng;
+use secp_256k1::ecdsa::{PublicKey, SecretKey, Signature};
+use secp_256k1::{Affine, Fr as Scalar};
+use zkstd::circuit::prelude::{
+    keccak256, BinaryAssignment, EcdsaSignatureAssignment, NonNativeFieldAssignment,
+    NonNativePointAssignment, R1cs, UInt8,
+};
+use zkstd::common::Group;
+
+type Point = NonNativePointAssignment<Fr, Affine>;
+type SignatureAssignment = EcdsaSignatureAssignment<Fr, Affine>;
+
+fn signature_assignment(cs: &mut R1cs<Bn254Driver>, signature: &Signature) -> SignatureAssignment {
+    SignatureAssignment::witness(cs, signature.r(), signature.s(), signature.v())
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn ecdsa_verify_test() {
+    let mut rng = OsRng;
+    let secret = SecretKey::random(&mut rng);
+    let public = secret.to_public_key();
+    let z = Scalar::random(&mut rng);
+    let signature = secret.sign(z, &mut rng);
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let public_key = Point::witness(&mut cs, public.point());
+    let message_hash = NonNativeFieldAssignment::witness(&mut cs, z);
+    let signature = signature_assignment(&mut cs, &signature);
+    let m = cs.m();
+    signature.enforce_valid(&mut cs, &public_key, &message_hash);
+    assert!(cs.m() - m < 1_500_000);
+    assert!(cs.is_sat());
+}
+
+#[test]
+fn ecdsa_ethereum_signed_message_test() {
+    // personal_sign of "Some data" with the web3.js documentation key
+    let message = b"\x19Ethereum Signed Message:\n9Some data";
+    let signature: [u8; 65] = decode_hex(
+        "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd\
+         6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a029\
+         01",
+    )
+    .try_into()
+    .unwrap();
+    let public_key = decode_hex(
+        "04\
+         4e3b81af9c2234cad09d679ce6035ed1392347ce64ce405f5dcd36228a25de6e\
+         47fd35c4215d1edf53e6f83de344615ce719bdb0fd878f6ed76f06dd277956de",
+    );
+    let signature = Signature::from_bytes(signature).unwrap();
+    let z = Scalar::from_be_bytes_reduced(
+        &decode_hex("1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655")
+            .try_into()
+            .unwrap(),
+    );
+    let public = PublicKey::recover(z, &signature).unwrap();
+    assert_eq!(public.point().to_uncompressed().to_vec(), public_key);
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let input: Vec<_> = message
+        .iter()
+        .map(|b| UInt8::witness(&mut cs, *b as u64))
+        .collect();
+    let digest = keccak256(&mut cs, &input);
+    let message_hash = NonNativeFieldAssignment::from_bytes_be(&mut cs, &digest);
+    assert_eq!(message_hash.value(&cs), z);
+
+    let signature = signature_assignment(&mut cs, &signature);
+    let recovered = signature.recover(&mut cs, &message_hash);
+    let public_key = Point::witness(&mut cs, public.point());
+    Point::enforce_equal(&mut cs, &recovered, &public_key);
+    assert_eq!(recovered.value(&cs), public.point());
+    assert!(cs.is_sat());
+}
+
+#[test]
+fn ecdsa_unsatisfied_test() {
+    let mut rng = OsRng;
+    let secret = SecretKey::random(&mut rng);
+    let public = secret.to_public_key();
+    let z = Scalar::random(&mut rng);
+    let signature = secret.sign(z, &mut rng);
+
+    // signature of another message
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let public_key = Point::witness(&mut cs, public.point());
+    let message_hash = NonNativeFieldAssignment::witness(&mut cs, z + Scalar::one());
+    let assignment = signature_assignment(&mut cs, &signature);
+    assignment.enforce_valid(&mut cs, &public_key, &message_hash);
+    assert!(!cs.is_sat());
+
+    // zero r and s are rejected
+    for (r, s) in [
+        (Scalar::zero(), signature.s()),
+        (signature.r(), Scalar::zero()),
+    ] {
+        let mut cs: R1cs<Bn254Driver> = R1cs::default();
+        let public_key = Point::witness(&mut cs, public.point());
+        let message_hash = NonNativeFieldAssignment::witness(&mut cs, z);
+        let assignment = SignatureAssignment::witness(&mut cs, r, s, signature.v());
+        assignment.enforce_valid(&mut cs, &public_key, &message_hash);
+        assert!(!cs.is_sat());
+    }
+}
+
+#[test]
+fn nonnative_generator_scalar_mul_test() {
+    // the least positive x coordinate of BN254 is the one of the generator, the offset
+    // point must differ from it to multiply the generator
+    let mut rng = OsRng;
+    let g = G1Affine::ADDITIVE_GENERATOR;
+    let q = G1Affine::random(&mut rng);
+    let (a, b) = (5u8, 9u8);
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let bits = |cs: &mut R1cs<Bn254Driver>, x: u8| {
+        (0..8)
+            .rev()
+            .map(|i| BinaryAssignment::witness(cs, (x >> i) & 1))
+            .collect::<Vec<_>>()
+    };
+    let a_bits = bits(&mut cs, a);
+    let b_bits = bits(&mut cs, b);
+    let p_assignment = NonNativePointAssignment::<Fr, G1Affine>::witness(&mut cs, g);
+    let q_assignment = NonNativePointAssignment::witness(&mut cs, q);
+    let result = NonNativePointAssignment::double_scalar_mul(
+        &mut cs,
+        &p_assignment,
+        &a_bits,
+        &q_assignment,
+        &b_bits,
+    );
+    assert_eq!(
+        result.value(&cs),
+        G1Affine::from(g * Fr::from(a as u64) + q * Fr::from(b as u64))
+    );
+    assert!(cs.is_sat());
+}
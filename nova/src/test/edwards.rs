@@ -0,0 +1,136 @@
+use crate::driver::Bn254Driver;
+
+use baby_jubjub::{Affine, Extended, Fs};
+use bn_254::Fr;
+use rand_core::OsRng;
+use zkstd::circuit::prelude::{BinaryAssignment, EdwardsPointAssignment, FieldAssignment, R1cs};
+use zkstd::common::{CurveGroup, Group, TwistedEdwardsAffine};
+
+type Point = EdwardsPointAssignment<Affine>;
+
+fn scalar_bits(cs: &mut R1cs<Bn254Driver>, scalar: Fs) -> Vec<BinaryAssignment> {
+    let scalar = FieldAssignment::witness(cs, Fr::from(scalar));
+    let bits = FieldAssignment::to_bits(cs, &scalar, 251);
+    FieldAssignment::enforce_eq_bits(cs, &scalar, &bits);
+    bits
+}
+
+#[test]
+fn edwards_add_double_test() {
+    let mut rng = OsRng;
+    let p = Affine::random(&mut rng);
+    let q = Affine::random(&mut rng);
+    let identity = Affine::ADDITIVE_IDENTITY;
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let a = Point::witness(&mut cs, p);
+    let b = Point::witness(&mut cs, q);
+    let o = Point::witness(&mut cs, identity);
+    a.enforce_on_curve(&mut cs);
+    o.enforce_on_curve(&mut cs);
+
+    let m = cs.m();
+    let sum = a.add(&b, &mut cs);
+    assert_eq!(cs.m() - m, 6);
+    assert_eq!(sum.value(&cs), Affine::from(p + q));
+
+    let m = cs.m();
+    let double = a.double(&mut cs);
+    assert_eq!(cs.m() - m, 5);
+    assert_eq!(double.value(&cs), Affine::from(p.double()));
+
+    // complete formulas for doubling, the identity and inverses
+    assert_eq!(a.add(&a, &mut cs).value(&cs), Affine::from(p.double()));
+    assert_eq!(a.add(&o, &mut cs).value(&cs), p);
+    assert_eq!(o.double(&mut cs).value(&cs), identity);
+    assert_eq!(a.add(&a.neg(), &mut cs).value(&cs), identity);
+
+    let m = cs.m();
+    let constant = a.add(&Point::constant(q), &mut cs);
+    assert_eq!(cs.m() - m, 3);
+    assert_eq!(constant.value(&cs), Affine::from(p + q));
+
+    let expected = Point::instance(&mut cs, Affine::from(p + q));
+    Point::enforce_equal(&mut cs, &sum, &expected);
+    assert!(cs.is_sat());
+}
+
+#[test]
+fn edwards_on_curve_test() {
+    let mut rng = OsRng;
+    let p = Affine::random(&mut rng);
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let m = cs.m();
+    Point::witness(&mut cs, p).enforce_on_curve(&mut cs);
+    assert_eq!(cs.m() - m, 3);
+    assert!(cs.is_sat());
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let off_curve = Affine::from_raw_unchecked(p.get_x(), p.get_y() + Fr::one());
+    Point::witness(&mut cs, off_curve).enforce_on_curve(&mut cs);
+    assert!(!cs.is_sat());
+}
+
+#[test]
+fn edwards_scalar_point_test() {
+    let mut rng = OsRng;
+    let p = Affine::random(&mut rng);
+    let s = Fs::random(&mut rng);
+    let expected = Affine::from(p * s);
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let point = Point::witness(&mut cs, p);
+    let scalar = FieldAssignment::witness(&mut cs, Fr::from(s));
+    let res = point.scalar_point(&mut cs, &scalar);
+    assert_eq!(res.value(&cs), expected);
+    assert!(cs.is_sat());
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let point = Point::witness(&mut cs, p);
+    let bits = scalar_bits(&mut cs, s);
+    let m = cs.m();
+    let res = point.scalar_point_bits(&mut cs, &bits);
+    assert_eq!(cs.m() - m, 13 * 250 + 2);
+    assert_eq!(res.value(&cs), expected);
+
+    let m = cs.m();
+    let fixed = Point::fixed_base_scalar_point(&mut cs, p, &bits);
+    assert_eq!(cs.m() - m, 6 * 250);
+    assert_eq!(fixed.value(&cs), expected);
+    Point::enforce_equal(&mut cs, &res, &fixed);
+    assert!(cs.is_sat());
+
+    // the order of the generator
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let bits = scalar_bits(&mut cs, -Fs::one());
+    let g = Affine::ADDITIVE_GENERATOR;
+    let res = Point::fixed_base_scalar_point(&mut cs, g, &bits);
+    let res = res.add(&Point::constant(g), &mut cs);
+    assert!(res.value(&cs).is_identity());
+    assert!(cs.is_sat());
+    assert_eq!(
+        Affine::from(Extended::ADDITIVE_GENERATOR * -Fs::one()),
+        -Affine::ADDITIVE_GENERATOR
+    );
+}
+
+#[test]
+fn edwards_select_test() {
+    let mut rng = OsRng;
+    let p = Affine::random(&mut rng);
+    let q = Affine::random(&mut rng);
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let a = Point::witness(&mut cs, p);
+    let b = Point::witness(&mut cs, q);
+    for (bit, selected, identity) in [(1, p, p), (0, q, Affine::ADDITIVE_IDENTITY)] {
+        let bit = BinaryAssignment::witness(&mut cs, bit);
+        let m = cs.m();
+        let res = Point::conditional_select(&mut cs, &a, &b, &bit);
+        assert_eq!(cs.m() - m, 4);
+        assert_eq!(res.value(&cs), selected);
+        assert_eq!(a.select_identity(&mut cs, &bit).value(&cs), identity);
+    }
+    assert!(cs.is_sat());
+}
@@ -0,0 +1,216 @@
+use super::tamper;
+use crate::driver::{Bn254Driver, GrumpkinDriver};
+
+use bn_254::{Fq, Fr};
+use rand_core::OsRng;
+use zkstd::circuit::prelude::{BinaryAssignment, CircuitDriver, FieldAssignment, R1cs};
+use zkstd::common::{FftField, Group, PrimeField};
+
+fn bit_value<C: CircuitDriver>(cs: &R1cs<C>, bit: &BinaryAssignment) -> C::Scalar {
+    FieldAssignment::from(bit).value(cs)
+}
+
+#[test]
+fn inverse_div_test() {
+    let mut rng = OsRng;
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let a = Fr::random(&mut rng);
+    let b = Fr::random(&mut rng);
+
+    let x = FieldAssignment::witness(&mut cs, a);
+    let y = FieldAssignment::witness(&mut cs, b);
+    let m = cs.m();
+    let inv = FieldAssignment::inverse(&mut cs, &x);
+    assert_eq!(cs.m() - m, 1);
+    let div = FieldAssignment::div(&mut cs, &x, &y);
+    assert_eq!(cs.m() - m, 3);
+
+    assert_eq!(inv.value(&cs), a.invert().unwrap());
+    assert_eq!(div.value(&cs), a * b.invert().unwrap());
+    assert!(cs.is_sat());
+
+    let mut ncs: R1cs<Bn254Driver> = R1cs::default();
+    let x = FieldAssignment::witness(&mut ncs, a);
+    let zero = FieldAssignment::witness(&mut ncs, Fr::zero());
+    FieldAssignment::div(&mut ncs, &x, &zero);
+    assert!(!ncs.is_sat());
+
+    // a constant zero has no inverse either
+    let mut ncs: R1cs<Bn254Driver> = R1cs::default();
+    let zero = FieldAssignment::constant(&Fr::zero());
+    FieldAssignment::inverse(&mut ncs, &zero);
+    assert_eq!(ncs.m(), 1);
+    assert!(!ncs.is_sat());
+    let mut ncs: R1cs<Bn254Driver> = R1cs::default();
+    let x = FieldAssignment::witness(&mut ncs, a);
+    FieldAssignment::div(&mut ncs, &x, &zero);
+    assert!(!ncs.is_sat());
+
+    // while a nonzero constant is inverted for free
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let inv = FieldAssignment::inverse(&mut cs, &FieldAssignment::constant(&b));
+    assert_eq!(cs.m(), 0);
+    assert_eq!(inv.value(&cs), b.invert().unwrap());
+}
+
+#[test]
+fn binary_soundness_test() {
+    for (a, b) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+        let mut cs: R1cs<Bn254Driver> = R1cs::default();
+        let x = BinaryAssignment::witness(&mut cs, a);
+        let y = BinaryAssignment::witness(&mut cs, b);
+        let m = cs.m();
+        let not = BinaryAssignment::not(&mut cs, &x);
+        let and = BinaryAssignment::and(&mut cs, &x, &y);
+        assert_eq!(cs.m() - m, 2);
+        assert_eq!(bit_value(&cs, &not), Fr::from(u64::from(1 - a)));
+        assert_eq!(bit_value(&cs, &and), Fr::from(u64::from(a & b)));
+        assert!(cs.is_sat());
+
+        // the results are bound to the inputs
+        let flipped = Fr::one() - bit_value(&cs, &not);
+        assert!(!tamper(&cs, not.inner(), flipped).is_sat());
+        let flipped = Fr::one() - bit_value(&cs, &and);
+        assert!(!tamper(&cs, and.inner(), flipped).is_sat());
+    }
+}
+
+#[test]
+fn is_neq_soundness_test() {
+    let mut rng = OsRng;
+    let a = Fr::random(&mut rng);
+    for b in [a, a + Fr::one()] {
+        let mut cs: R1cs<Bn254Driver> = R1cs::default();
+        let x = FieldAssignment::witness(&mut cs, a);
+        let y = FieldAssignment::witness(&mut cs, b);
+        let m = cs.m();
+        let is_neq = FieldAssignment::is_neq(&mut cs, &x, &y);
+        assert_eq!(cs.m() - m, 2);
+        assert_eq!(bit_value(&cs, &is_neq), Fr::from(u64::from(a != b)));
+        assert!(cs.is_sat());
+
+        let flipped = Fr::one() - bit_value(&cs, &is_neq);
+        assert!(!tamper(&cs, is_neq.inner(), flipped).is_sat());
+    }
+}
+
+#[test]
+fn range_check_test() {
+    // 0b101 accepts exactly the values up to 5, whatever the position of the zeros of C
+    for bound in [5u64, 4, 6, 7] {
+        for value in 0..8u64 {
+            let mut cs: R1cs<Bn254Driver> = R1cs::default();
+            let bits = (0..3)
+                .rev()
+                .map(|i| BinaryAssignment::witness(&mut cs, ((value >> i) & 1) as u8))
+                .collect::<Vec<_>>();
+            FieldAssignment::range_check(&mut cs, &bits, Fr::from(bound));
+            assert_eq!(cs.is_sat(), value <= bound, "{value} <= {bound}");
+        }
+    }
+
+    // the bits of `to_bits` are the only allocated witnesses, and are boolean
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let x = FieldAssignment::witness(&mut cs, Fr::from(0b1011_0110));
+    let w = cs.m_l_1();
+    let bits = FieldAssignment::to_bits(&mut cs, &x, 8);
+    FieldAssignment::enforce_eq_bits(&mut cs, &x, &bits);
+    assert_eq!(cs.m_l_1() - w, 8);
+    assert!(cs.is_sat());
+    assert!(!tamper(&cs, bits[1].inner(), Fr::from(2)).is_sat());
+}
+
+#[test]
+fn is_zero_test() {
+    let mut rng = OsRng;
+    for value in [Fq::zero(), Fq::random(&mut rng)] {
+        let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+        let x = FieldAssignment::witness(&mut cs, value);
+        let m = cs.m();
+        let is_zero = FieldAssignment::is_zero(&mut cs, &x);
+        assert_eq!(cs.m() - m, 3);
+        assert_eq!(
+            bit_value(&cs, &is_zero),
+            Fq::from(u64::from(value.is_zero()))
+        );
+        assert!(cs.is_sat());
+
+        // a prover can not claim the opposite result
+        let flipped = Fq::one() - bit_value(&cs, &is_zero);
+        assert!(!tamper(&cs, is_zero.inner(), flipped).is_sat());
+    }
+}
+
+#[test]
+fn less_than_test() {
+    let num_bits = 32;
+    let cases = [
+        (3, 5),
+        (5, 3),
+        (7, 7),
+        (0, u32::MAX as u64),
+        (u32::MAX as u64, 0),
+    ];
+    for (a, b) in cases {
+        let mut cs: R1cs<Bn254Driver> = R1cs::default();
+        let x = FieldAssignment::witness(&mut cs, Fr::from(a));
+        let y = FieldAssignment::witness(&mut cs, Fr::from(b));
+        let m = cs.m();
+        let lt = FieldAssignment::less_than(&mut cs, &x, &y, num_bits);
+        assert_eq!(cs.m() - m, num_bits as u64 + 3);
+        let le = FieldAssignment::less_or_equal(&mut cs, &x, &y, num_bits);
+
+        assert_eq!(bit_value(&cs, &lt), Fr::from(u64::from(a < b)));
+        assert_eq!(bit_value(&cs, &le), Fr::from(u64::from(a <= b)));
+        assert!(cs.is_sat());
+
+        let flipped = Fr::one() - bit_value(&cs, &lt);
+        assert!(!tamper(&cs, lt.inner(), flipped).is_sat());
+    }
+}
+
+#[test]
+fn pow_test() {
+    let mut rng = OsRng;
+    let a = Fr::random(&mut rng);
+
+    for exp in [0, 1, 2, 5, 17, 0xdead_beef] {
+        let mut cs: R1cs<Bn254Driver> = R1cs::default();
+        let x = FieldAssignment::witness(&mut cs, a);
+        let e = FieldAssignment::witness(&mut cs, Fr::from(exp));
+
+        let constant = FieldAssignment::pow_constant(&mut cs, &x, exp);
+        let m = cs.m();
+        let variable = FieldAssignment::pow(&mut cs, &x, &e, 32);
+        assert_eq!(cs.m() - m, 4 * 32 - 1);
+        assert_eq!(constant.value(&cs), a.pow(exp));
+        assert_eq!(variable.value(&cs), a.pow(exp));
+        assert!(cs.is_sat());
+    }
+
+    // x^5 costs two squarings and one multiplication
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let x = FieldAssignment::witness(&mut cs, a);
+    FieldAssignment::pow_constant(&mut cs, &x, 5);
+    assert_eq!(cs.m(), 3);
+}
+
+#[test]
+fn sqrt_test() {
+    let mut rng = OsRng;
+    for _ in 0..10 {
+        let a = Fr::random(&mut rng);
+        let mut cs: R1cs<Bn254Driver> = R1cs::default();
+        let x = FieldAssignment::witness(&mut cs, a.square());
+        let root = FieldAssignment::sqrt(&mut cs, &x);
+        let root = root.value(&cs);
+        assert!(root == a || root == -a);
+        assert!(cs.is_sat());
+    }
+
+    // the multiplicative generator 7 is a quadratic non-residue
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let x = FieldAssignment::witness(&mut cs, Fr::from(7));
+    FieldAssignment::sqrt(&mut cs, &x);
+    assert!(!cs.is_sat());
+}
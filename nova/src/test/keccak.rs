@@ -0,0 +1,301 @@
+use super::ExampleFunction;
+use crate::driver::{Bn254Driver, GrumpkinDriver};
+use crate::function::FunctionCircuit;
+use crate::ivc::{Ivc, PublicParams};
+
+use bn_254::{Fq, Fr};
+use rand_core::{OsRng, RngCore};
+use zkstd::circuit::prelude::{
+    keccak256, keccak_f1600, CircuitDriver, FieldAssignment, R1cs, UInt64, UInt8,
+};
+use zkstd::common::{Decode, Encode, PrimeField};
+use zkstd::matrix::DenseVectors;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Reference permutation on plain lanes
+fn native_keccak_f1600(a: &mut [u64; 25]) {
+    // rotation offsets and lane order of the combined rho and pi step
+    const RHO: [u32; 24] = [
+        1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+    ];
+    const PI: [usize; 24] = [
+        10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+    ];
+    for rc in ROUND_CONSTANTS {
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                a[x + 5 * y] ^= d;
+            }
+        }
+        let mut last = a[1];
+        for (rho, pi) in RHO.iter().zip(PI.iter()) {
+            let tmp = a[*pi];
+            a[*pi] = last.rotate_left(*rho);
+            last = tmp;
+        }
+        for y in 0..5 {
+            let row = [
+                a[5 * y],
+                a[5 * y + 1],
+                a[5 * y + 2],
+                a[5 * y + 3],
+                a[5 * y + 4],
+            ];
+            for x in 0..5 {
+                a[x + 5 * y] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+        a[0] ^= rc;
+    }
+}
+
+fn native_keccak256(message: &[u8]) -> Vec<u8> {
+    let mut padded = message.to_vec();
+    padded.push(0x01);
+    while padded.len() % 136 != 0 {
+        padded.push(0);
+    }
+    *padded.last_mut().unwrap() |= 0x80;
+
+    let mut state = [0u64; 25];
+    for block in padded.chunks(136) {
+        for (lane, bytes) in state.iter_mut().zip(block.chunks(8)) {
+            *lane ^= u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        native_keccak_f1600(&mut state);
+    }
+    state[..4]
+        .iter()
+        .flat_map(|lane| lane.to_le_bytes())
+        .collect()
+}
+
+/// Step hashing the digest of the previous step, carried as four little-endian lanes
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode)]
+struct KeccakStep;
+
+impl FunctionCircuit<Fr> for KeccakStep {
+    const ARITY: usize = 4;
+
+    fn invoke(z_i: &DenseVectors<Fr>) -> DenseVectors<Fr> {
+        let message: Vec<u8> = z_i
+            .get()
+            .iter()
+            .flat_map(|lane| lane.to_raw_bytes()[..8].to_vec())
+            .collect();
+        let lanes = native_keccak256(&message)
+            .chunks(8)
+            .map(|bytes| Fr::from(u64::from_le_bytes(bytes.try_into().unwrap())))
+            .collect();
+        DenseVectors::new(lanes)
+    }
+
+    fn invoke_cs<C: CircuitDriver<Scalar = Fr>>(
+        cs: &mut R1cs<C>,
+        z_i: Vec<FieldAssignment<Fr>>,
+    ) -> Vec<FieldAssignment<Fr>> {
+        let message: Vec<_> = z_i
+            .iter()
+            .flat_map(|lane| UInt64::from_field(cs, lane).to_bytes_le())
+            .collect();
+        keccak256(cs, &message)
+            .chunks(8)
+            .map(|bytes| UInt64::from_bytes_le(bytes).to_field(cs))
+            .collect()
+    }
+}
+
+fn digest<C: CircuitDriver>(message: &[u8]) -> Vec<u8> {
+    let mut cs: R1cs<C> = R1cs::default();
+    let input: Vec<_> = message
+        .iter()
+        .map(|b| UInt8::witness(&mut cs, *b as u64))
+        .collect();
+    let output = keccak256(&mut cs, &input);
+    assert!(cs.is_sat());
+
+    output.iter().map(|b| b.value(&cs) as u8).collect()
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn keccak_f1600_zero_state_test() {
+    // Keccak-f[1600] applied once and twice to the all-zero state,
+    // from the intermediate values published by the Keccak team
+    let once = [
+        0xf1258f7940e1dde7,
+        0x84d5ccf933c0478a,
+        0xd598261ea65aa9ee,
+        0xbd1547306f80494d,
+        0x8b284e056253d057,
+        0xff97a42d7f8e6fd4,
+        0x90fee5a0a44647c4,
+        0x8c5bda0cd6192e76,
+        0xad30a6f71b19059c,
+        0x30935ab7d08ffc64,
+        0xeb5aa93f2317d635,
+        0xa9a6e6260d712103,
+        0x81a57c16dbcf555f,
+        0x43b831cd0347c826,
+        0x01f22f1a11a5569f,
+        0x05e5635a21d9ae61,
+        0x64befef28cc970f2,
+        0x613670957bc46611,
+        0xb87c5a554fd00ecb,
+        0x8c3ee88a1ccf32c8,
+        0x940c7922ae3a2614,
+        0x1841f924a2c509e4,
+        0x16f53526e70465c2,
+        0x75f644e97f30a13b,
+        0xeaf1ff7b5ceca249,
+    ];
+
+    let mut lanes = [0u64; 25];
+    native_keccak_f1600(&mut lanes);
+    assert_eq!(lanes, once);
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let state: Vec<_> = (0..25).map(|_| UInt64::witness(&mut cs, 0)).collect();
+    let output = keccak_f1600(&mut cs, &state);
+    let output = keccak_f1600(&mut cs, &output);
+    assert!(cs.is_sat());
+    native_keccak_f1600(&mut lanes);
+    assert_eq!(output[0].value(&cs), 0x2d5c954df96ecb3c);
+    let output: Vec<u64> = output.iter().map(|lane| lane.value(&cs)).collect();
+    assert_eq!(output, lanes);
+}
+
+#[test]
+fn keccak256_vectors_test() {
+    let vectors = [
+        (
+            "",
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        ),
+        (
+            "abc",
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+        ),
+    ];
+    for (message, expected) in vectors {
+        assert_eq!(native_keccak256(message.as_bytes()), decode_hex(expected));
+        assert_eq!(
+            digest::<Bn254Driver>(message.as_bytes()),
+            decode_hex(expected)
+        );
+    }
+}
+
+#[test]
+fn keccak256_padding_boundary_test() {
+    let mut rng = OsRng;
+    // one byte of padding, a full padding block and two absorbed blocks
+    for len in [135, 136, 200] {
+        let mut message = vec![0u8; len];
+        rng.fill_bytes(&mut message);
+        assert_eq!(
+            digest::<GrumpkinDriver>(&message),
+            native_keccak256(&message)
+        );
+    }
+}
+
+#[test]
+fn keccak_f1600_cost_test() {
+    let mut rng = OsRng;
+    let mut lanes = [0u64; 25];
+    lanes.iter_mut().for_each(|lane| *lane = rng.next_u64());
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let state: Vec<_> = lanes
+        .iter()
+        .map(|lane| UInt64::witness(&mut cs, *lane))
+        .collect();
+    let m = cs.m();
+    let output = keccak_f1600(&mut cs, &state);
+    assert_eq!(cs.m() - m, 153600);
+    assert!(cs.is_sat());
+
+    native_keccak_f1600(&mut lanes);
+    let output: Vec<u64> = output.iter().map(|lane| lane.value(&cs)).collect();
+    assert_eq!(output, lanes);
+}
+
+#[test]
+fn keccak_step_test() {
+    let z0 = DenseVectors::new(vec![Fr::zero(); KeccakStep::ARITY]);
+    let z1 = KeccakStep::invoke(&z0);
+
+    // the digest of 32 zero bytes
+    let expected = decode_hex("290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563");
+    let lanes: Vec<Fr> = expected
+        .chunks(8)
+        .map(|bytes| Fr::from(u64::from_le_bytes(bytes.try_into().unwrap())))
+        .collect();
+    assert_eq!(z1.get(), lanes);
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let z_i = z1
+        .get()
+        .iter()
+        .map(|lane| FieldAssignment::witness(&mut cs, *lane))
+        .collect();
+    let z_next = KeccakStep::invoke_cs(&mut cs, z_i);
+    assert!(cs.is_sat());
+    let z_next: Vec<Fr> = z_next.iter().map(|lane| lane.value(&cs)).collect();
+    assert_eq!(z_next, KeccakStep::invoke(&z1).get());
+}
+
+#[test]
+fn keccak_ivc_test() {
+    let pp = PublicParams::<Bn254Driver, GrumpkinDriver, KeccakStep, ExampleFunction<Fq>>::setup(
+        &mut OsRng,
+    );
+    let z0_primary = DenseVectors::new(vec![Fr::zero(); KeccakStep::ARITY]);
+    let z0_secondary = DenseVectors::new(vec![Fq::zero()]);
+
+    let mut ivc = Ivc::init(&pp, z0_primary.clone(), z0_secondary);
+    ivc.prove_step(&pp);
+    let proof = ivc.prove_step(&pp);
+    assert!(proof.verify(&pp));
+
+    let expected = KeccakStep::invoke(&KeccakStep::invoke(&z0_primary));
+    assert_eq!(proof.zi_primary.get(), expected.get());
+}
@@ -0,0 +1,338 @@
+use super::ExampleFunction;
+use crate::driver::{Bn254Driver, GrumpkinDriver};
+use crate::function::FunctionCircuit;
+use crate::ivc::{Ivc, PublicParams};
+use crate::memory::{Memory, MemoryState, MemoryStateAssignment, RamAssignment, RomAssignment};
+use crate::merkle::MimcHash;
+
+use bn_254::{Bn254, Fq, Fr};
+use rand_core::OsRng;
+use zkgroth16::{Circuit, Error, ZkSnark};
+use zkstd::circuit::prelude::{CircuitDriver, FieldAssignment, R1cs};
+use zkstd::common::{Decode, Encode, Group};
+use zkstd::matrix::DenseVectors;
+use zkstd::r1cs::Wire;
+
+type Hash = MimcHash<Fr>;
+type Ram = RamAssignment<Fr, Hash>;
+
+const SIZE: usize = 8;
+
+fn contents() -> Vec<Fr> {
+    (0..SIZE).map(|_| Fr::random(&mut OsRng)).collect()
+}
+
+fn witness<C: CircuitDriver<Scalar = Fr>>(
+    cs: &mut R1cs<C>,
+    values: &[Fr],
+) -> Vec<FieldAssignment<Fr>> {
+    values
+        .iter()
+        .map(|value| FieldAssignment::witness(cs, *value))
+        .collect()
+}
+
+fn address<C: CircuitDriver<Scalar = Fr>>(cs: &mut R1cs<C>, address: usize) -> FieldAssignment<Fr> {
+    FieldAssignment::witness(cs, Fr::from(address as u64))
+}
+
+fn accesses(step: usize) -> (usize, usize) {
+    ((step * 3) % SIZE, (step * 7 + 2) % SIZE)
+}
+
+// memory[to] += memory[from]
+fn add(memory: &mut Memory<Fr, Hash>, step: usize) {
+    let (from, to) = accesses(step);
+    let value = memory.read(from) + memory.read(to);
+    memory.write(to, value);
+}
+
+/// Step adding two cells of a memory carried in the step state
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode)]
+struct RamStep {
+    init: Vec<Fr>,
+    step: u64,
+}
+
+impl RamStep {
+    /// Contents of the memory before the step
+    fn memory(&self) -> Memory<Fr, Hash> {
+        let mut memory = Memory::new(&self.init);
+        (0..self.step as usize).for_each(|step| add(&mut memory, step));
+        memory
+    }
+}
+
+impl FunctionCircuit<Fr> for RamStep {
+    const ARITY: usize = MemoryState::<Fr>::LEN;
+
+    fn invoke(z_i: &DenseVectors<Fr>) -> DenseVectors<Fr> {
+        Self::default().invoke_with(z_i)
+    }
+
+    fn invoke_cs<C: CircuitDriver<Scalar = Fr>>(
+        cs: &mut R1cs<C>,
+        z_i: Vec<FieldAssignment<Fr>>,
+    ) -> Vec<FieldAssignment<Fr>> {
+        Self::default().invoke_cs_with(cs, z_i)
+    }
+
+    fn invoke_with(&self, z_i: &DenseVectors<Fr>) -> DenseVectors<Fr> {
+        let mut cs: R1cs<Bn254Driver> = R1cs::default();
+        let z_i = witness(&mut cs, &z_i.get());
+        let z_next = self.invoke_cs_with(&mut cs, z_i);
+        DenseVectors::new(z_next.iter().map(|x| x.value(&cs)).collect())
+    }
+
+    fn invoke_cs_with<C: CircuitDriver<Scalar = Fr>>(
+        &self,
+        cs: &mut R1cs<C>,
+        z_i: Vec<FieldAssignment<Fr>>,
+    ) -> Vec<FieldAssignment<Fr>> {
+        let (from, to) = accesses(self.step as usize);
+        let mut ram = Ram::resume(self.memory(), MemoryStateAssignment::from_slice(&z_i));
+        let a = address(cs, from);
+        let b = address(cs, to);
+        let x = ram.read(cs, &a);
+        let y = ram.read(cs, &b);
+        ram.write(cs, &b, &(&x + &y));
+        let (state, _) = ram.suspend(cs);
+        state.to_vec()
+    }
+}
+
+/// Single circuit adding cells of a memory and exposing the last cell written
+#[derive(Debug)]
+struct RamCircuit {
+    init: Vec<Fr>,
+    output: Fr,
+}
+
+impl Default for RamCircuit {
+    fn default() -> Self {
+        Self {
+            init: vec![Fr::zero(); SIZE],
+            output: Fr::zero(),
+        }
+    }
+}
+
+impl RamCircuit {
+    const STEPS: usize = 4;
+
+    fn new(init: Vec<Fr>) -> Self {
+        let mut memory = Memory::<Fr, Hash>::new(&init);
+        (0..Self::STEPS).for_each(|step| add(&mut memory, step));
+        let (_, to) = accesses(Self::STEPS - 1);
+        let output = memory.read(to);
+        Self { init, output }
+    }
+
+    /// Return the last read
+    fn build<C: CircuitDriver<Scalar = Fr>>(&self, cs: &mut R1cs<C>) -> FieldAssignment<Fr> {
+        let output = FieldAssignment::instance(cs, self.output);
+        let values = witness(cs, &self.init);
+        let mut ram = Ram::new(cs, &values);
+        for step in 0..Self::STEPS {
+            let (from, to) = accesses(step);
+            let a = address(cs, from);
+            let b = address(cs, to);
+            let x = ram.read(cs, &a);
+            let y = ram.read(cs, &b);
+            ram.write(cs, &b, &(&x + &y));
+        }
+        let (_, to) = accesses(Self::STEPS - 1);
+        let a = address(cs, to);
+        let value = ram.read(cs, &a);
+        FieldAssignment::enforce_eq(cs, &value, &output);
+        ram.finalize(cs);
+
+        value
+    }
+}
+
+impl Circuit for RamCircuit {
+    type Driver = zkgroth16::Bn254Driver;
+
+    fn synthesize(&self, cs: &mut R1cs<Self::Driver>) -> Result<(), Error> {
+        self.build(cs);
+        Ok(())
+    }
+}
+
+#[test]
+fn ram_test() {
+    let init = contents();
+    let mut expected = init.clone();
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let values = witness(&mut cs, &init);
+    let mut ram = Ram::new(&mut cs, &values);
+    for step in 0..16 {
+        let (from, to) = ((step * 3) % SIZE, (step * 5 + 1) % SIZE);
+        let a = address(&mut cs, from);
+        let b = address(&mut cs, to);
+        // memory[to] += memory[from]
+        let x = ram.read(&mut cs, &a);
+        let y = ram.read(&mut cs, &b);
+        ram.write(&mut cs, &b, &(&x + &y));
+        assert_eq!(x.value(&cs), expected[from]);
+        assert_eq!(y.value(&cs), expected[to]);
+        let sum = expected[from] + expected[to];
+        expected[to] = sum;
+    }
+    ram.finalize(&mut cs);
+
+    assert!(cs.is_sat());
+}
+
+#[test]
+fn rom_test() {
+    let init = contents();
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let values = witness(&mut cs, &init);
+    let mut rom = RomAssignment::<Fr, Hash>::new(&mut cs, &values);
+    for index in [3, 1, 3, 7, 0] {
+        let a = address(&mut cs, index);
+        let value = rom.read(&mut cs, &a);
+        assert_eq!(value.value(&cs), init[index]);
+    }
+    rom.finalize(&mut cs);
+
+    assert!(cs.is_sat());
+}
+
+#[test]
+fn ram_unsatisfied_test() {
+    let init = contents();
+
+    // the address out of range was never written
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let values = witness(&mut cs, &init);
+    let mut ram = Ram::new(&mut cs, &values);
+    let a = address(&mut cs, SIZE);
+    ram.read(&mut cs, &a);
+    ram.finalize(&mut cs);
+    assert!(!cs.is_sat());
+
+    // the value read differs from the value written
+    let mut forged = init.clone();
+    forged[2] += Fr::one();
+    let mut native = Memory::<Fr, Hash>::new(&forged);
+    native.read(2);
+    let (alpha, gamma) = native.challenges();
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let state = MemoryState::new::<Hash>(&init, alpha, gamma);
+    let carried = MemoryStateAssignment::witness(&mut cs, &state);
+    let mut ram = Ram::resume(Memory::new(&forged), carried);
+    let a = address(&mut cs, 2);
+    let value = ram.read(&mut cs, &a);
+    let (carried, memory) = ram.suspend(&mut cs);
+    assert!(cs.is_sat());
+    assert_eq!(value.value(&cs), forged[2]);
+
+    let state = carried.value(&cs);
+    assert!(!state.verify::<Hash>(memory.values(), memory.timestamps()));
+}
+
+#[test]
+fn ram_steps_test() {
+    let init = contents();
+    let steps = 4;
+
+    // the prover replays the whole run to fix the challenges before the first step
+    let mut native = Memory::<Fr, Hash>::new(&init);
+    (0..steps).for_each(|step| add(&mut native, step));
+    let (alpha, gamma) = native.challenges();
+
+    let mut state = MemoryState::new::<Hash>(&init, alpha, gamma);
+    let mut memory = Memory::<Fr, Hash>::new(&init);
+    for step in 0..steps {
+        let (from, to) = accesses(step);
+        let mut cs: R1cs<Bn254Driver> = R1cs::default();
+        let carried = MemoryStateAssignment::witness(&mut cs, &state);
+        let mut ram = Ram::resume(memory, carried);
+        let a = address(&mut cs, from);
+        let b = address(&mut cs, to);
+        let x = ram.read(&mut cs, &a);
+        let y = ram.read(&mut cs, &b);
+        ram.write(&mut cs, &b, &(&x + &y));
+        let (carried, next) = ram.suspend(&mut cs);
+        assert!(cs.is_sat());
+
+        state = carried.value(&cs);
+        memory = next;
+    }
+
+    assert_eq!(memory.values(), native.values());
+    assert!(state.verify::<Hash>(memory.values(), memory.timestamps()));
+
+    // the final contents are bound by the challenges
+    let mut forged = memory.values().to_vec();
+    forged[0] += Fr::one();
+    assert!(!state.verify::<Hash>(&forged, memory.timestamps()));
+
+    // the challenges must be derived from the whole run
+    let mut early = state.clone();
+    early.alpha += Fr::one();
+    assert!(!early.verify::<Hash>(memory.values(), memory.timestamps()));
+}
+
+#[test]
+fn ram_ivc_test() {
+    let init = contents();
+    let steps = 3;
+
+    let mut native = Memory::<Fr, Hash>::new(&init);
+    (0..steps).for_each(|step| add(&mut native, step));
+    let (alpha, gamma) = native.challenges();
+
+    let pp = PublicParams::<Bn254Driver, GrumpkinDriver, RamStep, ExampleFunction<Fq>>::setup(
+        &mut OsRng,
+    );
+    let primary = |step: usize| RamStep {
+        init: init.clone(),
+        step: step as u64,
+    };
+    let secondary = ExampleFunction::default();
+    let z0_primary = DenseVectors::new(MemoryState::new::<Hash>(&init, alpha, gamma).to_vec());
+    let z0_secondary = DenseVectors::new(vec![Fq::zero()]);
+
+    let mut ivc = Ivc::init_with(&pp, &primary(0), &secondary, z0_primary, z0_secondary);
+    let mut proof = ivc.prove_step_with(&pp, &primary(0), &secondary);
+    for step in 1..steps {
+        proof = ivc.prove_step_with(&pp, &primary(step), &secondary);
+    }
+    assert!(proof.verify(&pp));
+
+    let state = MemoryState::from_slice(&proof.zi_primary.get());
+    assert!(state.verify::<Hash>(native.values(), native.timestamps()));
+}
+
+#[test]
+fn ram_groth16_test() {
+    let circuit = RamCircuit::new(contents());
+    let output = circuit.output;
+
+    let (mut prover, verifier) = ZkSnark::<Bn254>::setup::<RamCircuit>(OsRng).unwrap();
+    let proof = prover.create_proof(&mut OsRng, circuit).unwrap();
+    verifier.verify(&proof, &[output]).unwrap();
+
+    // the last read is tampered and claimed as the output
+    let circuit = RamCircuit::new(contents());
+    let mut cs = R1cs::<zkgroth16::Bn254Driver>::default();
+    let value = circuit.build(&mut cs);
+    let index = match value.inner().terms()[0].0 {
+        Wire::Witness(index) => index as usize,
+        Wire::Instance(_) => unreachable!(),
+    };
+    let mut witness = cs.w();
+    witness[index] += Fr::one();
+    let forged = circuit.output + Fr::one();
+    let proof = prover
+        .create_proof_with_assignment(&mut OsRng, &[forged], &witness)
+        .unwrap();
+    assert!(verifier.verify(&proof, &[forged]).is_err());
+}
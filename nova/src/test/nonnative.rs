@@ -0,0 +1,148 @@
+use crate::driver::Bn254Driver;
+
+use baby_jubjub::Fs;
+use bn_254::{Fq, Fr};
+use rand_core::OsRng;
+use zkstd::circuit::prelude::{FieldAssignment, NonNativeFieldAssignment, R1cs};
+use zkstd::common::{Group, PrimeField};
+
+type Emulated<E> = NonNativeFieldAssignment<Fr, E>;
+
+fn arithmetic_test<E: PrimeField>() {
+    let mut rng = OsRng;
+    let a = E::random(&mut rng);
+    let b = E::random(&mut rng);
+    let c = E::random(&mut rng);
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let x = Emulated::witness(&mut cs, a);
+    let y = Emulated::witness(&mut cs, b);
+    let z = Emulated::constant(c);
+
+    let sum = &x + &y;
+    let diff = &x - &y;
+    let neg = -&x;
+    assert_eq!(sum.value(&cs), a + b);
+    assert_eq!(diff.value(&cs), a - b);
+    assert_eq!(neg.value(&cs), -a);
+
+    let product = Emulated::mul(&mut cs, &x, &y);
+    let scaled = Emulated::mul(&mut cs, &x, &z);
+    let inverse = Emulated::inverse(&mut cs, &x);
+    let quotient = Emulated::div(&mut cs, &x, &y);
+    assert_eq!(product.value(&cs), a * b);
+    assert_eq!(scaled.value(&cs), a * c);
+    assert_eq!(inverse.value(&cs), a.invert().unwrap());
+    assert_eq!(quotient.value(&cs), a * b.invert().unwrap());
+
+    // (a + b) * (a - b) - c = a^2 - b^2 - c without intermediate reductions
+    let lhs = &Emulated::mul(&mut cs, &sum, &diff) - &z;
+    let a2 = Emulated::square(&mut cs, &x);
+    let b2 = Emulated::square(&mut cs, &y);
+    let rhs = &(&a2 - &b2) - &z;
+    Emulated::enforce_eq(&mut cs, &lhs, &rhs);
+    assert_eq!(lhs.value(&cs), a * a - b * b - c);
+
+    let reduced = Emulated::reduce(&mut cs, &lhs);
+    assert_eq!(reduced.value(&cs), lhs.value(&cs));
+
+    let is_eq = Emulated::is_eq(&mut cs, &lhs, &rhs);
+    let is_neq = Emulated::is_eq(&mut cs, &x, &y);
+    assert_eq!(FieldAssignment::from(&is_eq).value(&cs), 1.into());
+    assert_eq!(FieldAssignment::from(&is_neq).value(&cs), 0.into());
+
+    assert!(cs.is_sat());
+}
+
+#[test]
+fn nonnative_base_field_test() {
+    arithmetic_test::<Fq>();
+}
+
+#[test]
+fn nonnative_small_field_test() {
+    arithmetic_test::<Fs>();
+}
+
+#[test]
+fn nonnative_lazy_reduction_test() {
+    let mut rng = OsRng;
+    let a = Fq::random(&mut rng);
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let x = Emulated::witness(&mut cs, a);
+    let mut acc = x.clone();
+    let mut expected = a;
+    for _ in 0..64 {
+        let m = cs.m();
+        acc = Emulated::mul(&mut cs, &acc, &(&x + &x));
+        let limit = cs.m() - m;
+        expected *= a + a;
+        // lazy sums are reduced on demand by the multiplication
+        acc = &(&acc + &acc) - &x;
+        expected = expected + expected - a;
+        assert!(limit < 1000);
+    }
+    assert_eq!(acc.value(&cs), expected);
+    assert!(cs.is_sat());
+}
+
+#[test]
+fn nonnative_unsatisfied_test() {
+    let mut rng = OsRng;
+    let a = Fq::random(&mut rng);
+    let b = Fq::random(&mut rng);
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let x = Emulated::witness(&mut cs, a);
+    let y = Emulated::witness(&mut cs, b);
+    Emulated::enforce_eq(&mut cs, &x, &y);
+    assert!(!cs.is_sat());
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let x = Emulated::witness(&mut cs, a);
+    let zero = Emulated::witness(&mut cs, Fq::zero());
+    Emulated::inverse(&mut cs, &zero);
+    Emulated::enforce_eq(&mut cs, &x, &x);
+    assert!(!cs.is_sat());
+}
+
+#[test]
+fn nonnative_from_field_test() {
+    let mut rng = OsRng;
+    let a = Fr::random(&mut rng);
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let x = FieldAssignment::witness(&mut cs, a);
+    let emulated = Emulated::<Fq>::from_field(&mut cs, &x);
+    assert_eq!(emulated.value(&cs), Fq::from(a));
+    assert!(cs.is_sat());
+}
+
+#[test]
+fn nonnative_from_field_bound_test() {
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let x = FieldAssignment::witness(&mut cs, -Fr::one());
+    let emulated = Emulated::<Fq>::from_field(&mut cs, &x);
+    assert_eq!(emulated.value(&cs), Fq::from(-Fr::one()));
+    assert!(cs.is_sat());
+
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let x = FieldAssignment::witness(&mut cs, Fr::one());
+    Emulated::<Fq>::from_field(&mut cs, &x);
+
+    // range checked limbs of p + 1 recompose to 1 in the native field
+    let mut forged: R1cs<Bn254Driver> = R1cs::default();
+    FieldAssignment::witness(&mut forged, Fr::one());
+    Emulated::witness(&mut forged, Fq::from(-Fr::one()) + Fq::from(2u64));
+
+    let mut wtns = vec![];
+    cs.write_wtns(&mut wtns);
+    // the values follow the header and the data section header
+    let offset = 76 + 32 * cs.l();
+    for (i, value) in forged.w().iter().enumerate() {
+        wtns[offset + 32 * i..offset + 32 * (i + 1)].copy_from_slice(&value.to_raw_bytes());
+    }
+    cs.read_wtns(&wtns).unwrap();
+    assert!(!cs.is_sat());
+}
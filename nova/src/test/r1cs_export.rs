@@ -0,0 +1,163 @@
+use crate::driver::{Bn254Driver, GrumpkinDriver};
+
+use bn_254::Fr;
+use zkstd::circuit::prelude::{FieldAssignment, R1cs};
+use zkstd::common::{Group, OsRng};
+use zkstd::r1cs::test::example_r1cs;
+use zkstd::r1cs::FormatError;
+
+fn mimc_like_r1cs() -> R1cs<Bn254Driver> {
+    let mut rng = OsRng;
+    let mut cs = R1cs::default();
+    let x = FieldAssignment::instance(&mut cs, Fr::random(&mut rng));
+    let k = FieldAssignment::witness(&mut cs, Fr::random(&mut rng));
+    let mut acc = x;
+    for i in 0..8 {
+        let t = &(&acc + &k) + &FieldAssignment::constant(&Fr::from(i));
+        let t2 = FieldAssignment::square(&mut cs, &t);
+        let t4 = FieldAssignment::square(&mut cs, &t2);
+        acc = FieldAssignment::mul(&mut cs, &t4, &t);
+    }
+    FieldAssignment::inputize(&mut cs, acc);
+    cs
+}
+
+fn assert_round_trip<C: zkstd::circuit::CircuitDriver>(cs: &R1cs<C>) {
+    let mut r1cs = vec![];
+    let mut wtns = vec![];
+    cs.write_r1cs(&mut r1cs);
+    cs.write_wtns(&mut wtns);
+
+    let mut imported = R1cs::<C>::read_r1cs(&r1cs).unwrap();
+    assert_eq!(imported.m(), cs.m());
+    assert_eq!(imported.l(), cs.l());
+    assert_eq!(imported.m_l_1(), cs.m_l_1());
+    assert_eq!(imported.matrices(), cs.matrices());
+
+    imported.read_wtns(&wtns).unwrap();
+    assert_eq!(imported.x(), cs.x());
+    assert_eq!(imported.w(), cs.w());
+    assert!(imported.is_sat());
+}
+
+#[test]
+fn r1cs_round_trip_test() {
+    assert_round_trip(&example_r1cs::<GrumpkinDriver>(3));
+    assert_round_trip(&mimc_like_r1cs());
+}
+
+#[test]
+fn r1cs_binary_layout_test() {
+    let cs = example_r1cs::<Bn254Driver>(3);
+    let mut bytes = vec![];
+    cs.write_r1cs(&mut bytes);
+
+    assert_eq!(&bytes[0..4], b"r1cs");
+    assert_eq!(u32::from_le_bytes(bytes[4..8].try_into().unwrap()), 1);
+    assert_eq!(u32::from_le_bytes(bytes[8..12].try_into().unwrap()), 3);
+    // header section
+    assert_eq!(u32::from_le_bytes(bytes[12..16].try_into().unwrap()), 1);
+    assert_eq!(u64::from_le_bytes(bytes[16..24].try_into().unwrap()), 64);
+    assert_eq!(u32::from_le_bytes(bytes[24..28].try_into().unwrap()), 32);
+    assert_eq!(
+        bytes[28..60],
+        [
+            0x01, 0x00, 0x00, 0xf0, 0x93, 0xf5, 0xe1, 0x43, 0x91, 0x70, 0xb9, 0x79, 0x48, 0xe8,
+            0x33, 0x28, 0x5d, 0x58, 0x81, 0x81, 0xb6, 0x45, 0x50, 0xb8, 0x29, 0xa0, 0x31, 0xe1,
+            0x72, 0x4e, 0x64, 0x30
+        ]
+    );
+    // wires, public outputs, public inputs, private inputs, labels, constraints
+    assert_eq!(u32::from_le_bytes(bytes[60..64].try_into().unwrap()), 6);
+    assert_eq!(u32::from_le_bytes(bytes[64..68].try_into().unwrap()), 0);
+    assert_eq!(u32::from_le_bytes(bytes[68..72].try_into().unwrap()), 1);
+    assert_eq!(u32::from_le_bytes(bytes[72..76].try_into().unwrap()), 0);
+    assert_eq!(u64::from_le_bytes(bytes[76..84].try_into().unwrap()), 6);
+    assert_eq!(u32::from_le_bytes(bytes[84..88].try_into().unwrap()), 4);
+}
+
+#[test]
+fn r1cs_import_error_test() {
+    let cs = example_r1cs::<Bn254Driver>(3);
+    let mut bytes = vec![];
+    cs.write_r1cs(&mut bytes);
+
+    let mut wrong_magic = bytes.clone();
+    wrong_magic[0] = b'x';
+    assert_eq!(
+        R1cs::<Bn254Driver>::read_r1cs(&wrong_magic).unwrap_err(),
+        FormatError::InvalidMagic
+    );
+    assert_eq!(
+        R1cs::<Bn254Driver>::read_r1cs(&bytes[..bytes.len() - 1]).unwrap_err(),
+        FormatError::UnexpectedEof
+    );
+    // grumpkin scalar field differs from bn254 scalar field
+    assert_eq!(
+        R1cs::<GrumpkinDriver>::read_r1cs(&bytes).unwrap_err(),
+        FormatError::PrimeMismatch
+    );
+
+    let mut wtns = vec![];
+    mimc_like_r1cs().write_wtns(&mut wtns);
+    let mut imported = R1cs::<Bn254Driver>::read_r1cs(&bytes).unwrap();
+    assert!(matches!(
+        imported.read_wtns(&wtns),
+        Err(FormatError::WitnessLengthMismatch { expected: 6, .. })
+    ));
+}
+
+#[test]
+fn r1cs_oversized_header_test() {
+    // 12-byte file announcing u32::MAX sections
+    let mut crafted = b"r1cs".to_vec();
+    crafted.extend(1u32.to_le_bytes());
+    crafted.extend(u32::MAX.to_le_bytes());
+    assert_eq!(
+        R1cs::<Bn254Driver>::read_r1cs(&crafted).unwrap_err(),
+        FormatError::UnexpectedEof
+    );
+
+    let cs = example_r1cs::<Bn254Driver>(3);
+    let mut bytes = vec![];
+    cs.write_r1cs(&mut bytes);
+
+    let mut wires = bytes.clone();
+    wires[60..64].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(
+        R1cs::<Bn254Driver>::read_r1cs(&wires).unwrap_err(),
+        FormatError::UnexpectedEof
+    );
+
+    // term count of the first row of A
+    let mut terms = bytes.clone();
+    terms[100..104].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(
+        R1cs::<Bn254Driver>::read_r1cs(&terms).unwrap_err(),
+        FormatError::UnexpectedEof
+    );
+
+    // wire label section dropped
+    let mut truncated = bytes[..bytes.len() - 6 * 8 - 12].to_vec();
+    truncated[8..12].copy_from_slice(&2u32.to_le_bytes());
+    assert_eq!(
+        R1cs::<Bn254Driver>::read_r1cs(&truncated).unwrap_err(),
+        FormatError::MissingSection(3)
+    );
+}
+
+#[test]
+fn r1cs_json_test() {
+    let cs = example_r1cs::<Bn254Driver>(3);
+    let json = cs.to_json();
+
+    assert!(json.starts_with(
+        "{\"n8\":32,\"prime\":\"21888242871839275222246405745257275088548364400416034343698204186575808495617\",\"nVars\":6,\"nOutputs\":0,\"nPubInputs\":1,"
+    ));
+    // x * x = sym_1 and 5 + sym_3 = out
+    assert!(json.contains("[{\"1\":\"1\"},{\"1\":\"1\"},{\"3\":\"1\"}]"));
+    assert!(json.contains("[{\"0\":\"5\",\"5\":\"1\"},{\"0\":\"1\"},{\"2\":\"1\"}]"));
+    assert!(json.ends_with(
+        "\"labels\":[\"instance 0\",\"instance 1\",\"witness 0\",\"witness 1\",\"witness 2\",\"witness 3\"]}"
+    ));
+}
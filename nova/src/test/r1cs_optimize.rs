@@ -0,0 +1,95 @@
+use crate::driver::{Bn254Driver, GrumpkinDriver};
+use crate::gadget::MimcAssignment;
+use crate::hash::{Mimc, MIMC_ROUNDS};
+
+use bn_254::{Fq, Fr, G1Affine};
+use zkstd::circuit::prelude::{FieldAssignment, PointAssignment, R1cs};
+use zkstd::common::{CurveGroup, Group, OsRng};
+
+#[test]
+fn optimize_add_chain_test() {
+    let mut rng = OsRng;
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let values = (0..8).map(|_| Fr::random(&mut rng)).collect::<Vec<_>>();
+    let expected = values.iter().fold(Fr::zero(), |acc, v| acc + v);
+
+    let inputs = values
+        .iter()
+        .map(|v| FieldAssignment::witness(&mut cs, *v))
+        .collect::<Vec<_>>();
+    let sum = inputs.iter().skip(1).fold(inputs[0].clone(), |acc, x| {
+        FieldAssignment::add(&mut cs, &acc, x)
+    });
+    let out = FieldAssignment::instance(&mut cs, expected);
+    let square = FieldAssignment::square(&mut cs, &sum);
+    let out_square = FieldAssignment::square(&mut cs, &out);
+    FieldAssignment::enforce_eq(&mut cs, &square, &out_square);
+    assert!(cs.is_sat());
+    assert_eq!(cs.m(), 10);
+
+    let x = cs.x();
+    cs.optimize();
+    assert!(cs.is_sat());
+    // both squares and the final equality reduce to a single constraint
+    assert_eq!(cs.m(), 2);
+    // the add gates are eliminated by substituting the free summands
+    assert_eq!(cs.m_l_1(), 2);
+    assert_eq!(cs.x(), x);
+}
+
+#[test]
+fn optimize_keeps_unsat_test() {
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let a = FieldAssignment::witness(&mut cs, Fr::from(2));
+    let b = FieldAssignment::witness(&mut cs, Fr::from(3));
+    let o = FieldAssignment::instance(&mut cs, Fr::from(7));
+    let sum = FieldAssignment::add(&mut cs, &a, &b);
+    let product = FieldAssignment::mul(&mut cs, &sum, &b);
+    FieldAssignment::enforce_eq(&mut cs, &product, &o);
+    assert!(!cs.is_sat());
+
+    cs.optimize();
+    assert!(!cs.is_sat());
+    assert_eq!(cs.m(), 1);
+}
+
+#[test]
+fn optimize_mimc_test() {
+    let mut rng = OsRng;
+    let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+    let (xl, xr) = (Fq::random(&mut rng), Fq::random(&mut rng));
+    let hash = Mimc::<MIMC_ROUNDS, Fq>::default().hash(xl, xr);
+
+    let xl = FieldAssignment::witness(&mut cs, xl);
+    let xr = FieldAssignment::witness(&mut cs, xr);
+    let out = MimcAssignment::<MIMC_ROUNDS, Fq>::default().hash(&mut cs, xl, xr);
+    let out = FieldAssignment::add(&mut cs, &out, &FieldAssignment::constant(&Fq::zero()));
+    let hash = FieldAssignment::instance(&mut cs, hash);
+    FieldAssignment::enforce_eq(&mut cs, &out, &hash);
+    assert!(cs.is_sat());
+
+    let m = cs.m();
+    cs.optimize();
+    assert!(cs.is_sat());
+    assert_eq!(cs.m(), m - 1);
+}
+
+#[test]
+fn optimize_point_add_test() {
+    let mut rng = OsRng;
+    let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+    let a = G1Affine::random(&mut rng);
+    let b = G1Affine::random(&mut rng);
+
+    let a_assignment = PointAssignment::instance(&mut cs, a);
+    let b_assignment = PointAssignment::witness(&mut cs, b.get_x(), b.get_y(), false);
+    let sum = a_assignment.add(&b_assignment, &mut cs);
+    sum.assert_equal_public_point(&mut cs, a + b);
+    assert!(cs.is_sat());
+
+    let (m, witnesses) = (cs.m(), cs.m_l_1());
+    cs.optimize();
+    assert!(cs.is_sat());
+    assert!(cs.m() < m);
+    assert!(cs.m_l_1() < witnesses);
+}
@@ -0,0 +1,66 @@
+use crate::driver::{Bn254Driver, GrumpkinDriver};
+
+use zkstd::circuit::prelude::{sha256, sha256_compression, CircuitDriver, R1cs, UInt32, UInt8};
+
+fn digest<C: CircuitDriver>(message: &[u8]) -> Vec<u8> {
+    let mut cs: R1cs<C> = R1cs::default();
+    let input: Vec<_> = message
+        .iter()
+        .map(|b| UInt8::witness(&mut cs, *b as u64))
+        .collect();
+    let output = sha256(&mut cs, &input);
+    assert!(cs.is_sat());
+
+    output.iter().map(|b| b.value(&cs) as u8).collect()
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn sha256_nist_vectors_test() {
+    // FIPS 180-2 examples
+    let vectors = [
+        (
+            "",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        (
+            "abc",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        (
+            "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+    ];
+    for (message, expected) in vectors {
+        assert_eq!(
+            digest::<Bn254Driver>(message.as_bytes()),
+            decode_hex(expected)
+        );
+    }
+
+    let message = "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+    let expected = "cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1";
+    assert_eq!(
+        digest::<GrumpkinDriver>(message.as_bytes()),
+        decode_hex(expected)
+    );
+}
+
+#[test]
+fn sha256_compression_cost_test() {
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let state: Vec<_> = (0..8).map(|i| UInt32::witness(&mut cs, i)).collect();
+    let block: Vec<_> = (0..16).map(|i| UInt32::witness(&mut cs, i)).collect();
+
+    let m = cs.m();
+    sha256_compression(&mut cs, &state, &block);
+    assert_eq!(cs.m() - m, 26416);
+    assert!(cs.is_sat());
+}
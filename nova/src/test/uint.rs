@@ -0,0 +1,150 @@
+use crate::driver::{Bn254Driver, GrumpkinDriver};
+
+use rand_core::{OsRng, RngCore};
+use zkstd::circuit::prelude::{
+    CircuitDriver, FieldAssignment, R1cs, UInt32, UInt64, UInt8, UIntAssignment,
+};
+
+fn uint_ops<C: CircuitDriver, const N: usize>(a: u64, b: u64) {
+    let mask = u64::MAX >> (64 - N);
+    let (a, b) = (a & mask, b & mask);
+    let mut cs: R1cs<C> = R1cs::default();
+    let x = UIntAssignment::<C::Scalar, N>::witness(&mut cs, a);
+    let y = UIntAssignment::<C::Scalar, N>::witness(&mut cs, b);
+    assert_eq!(cs.m() as usize, 2 * N);
+
+    let sum = UIntAssignment::add(&mut cs, &x, &y);
+    let (_, carry) = UIntAssignment::add_with_carry(&mut cs, &x, &y);
+    let xor = UIntAssignment::xor(&mut cs, &x, &y);
+    let and = UIntAssignment::and(&mut cs, &x, &y);
+    let or = UIntAssignment::or(&mut cs, &x, &y);
+
+    let wide = (a as u128) + (b as u128);
+    assert_eq!(sum.value(&cs), (wide as u64) & mask);
+    assert_eq!(
+        FieldAssignment::from(&carry).value(&cs),
+        C::Scalar::from((wide >> N) as u64)
+    );
+    assert_eq!(xor.value(&cs), a ^ b);
+    assert_eq!(and.value(&cs), a & b);
+    assert_eq!(or.value(&cs), a | b);
+    assert_eq!(x.not().value(&cs), !a & mask);
+
+    for n in [0, 1, 7, N - 1] {
+        let rotl = (a << n | a >> ((N - n) % N)) & mask;
+        assert_eq!(x.rotate_left(n).value(&cs), rotl);
+        assert_eq!(x.rotate_right(N - n).value(&cs), rotl);
+        assert_eq!(x.shl(n).value(&cs), (a << n) & mask);
+        assert_eq!(x.shr(n).value(&cs), a >> n);
+    }
+
+    let field = x.to_field(&mut cs);
+    assert_eq!(field.value(&cs), C::Scalar::from(a));
+    let back = UIntAssignment::<C::Scalar, N>::from_field(&mut cs, &field);
+    UIntAssignment::enforce_equal(&mut cs, &x, &back);
+    assert!(cs.is_sat());
+}
+
+#[test]
+fn uint_ops_test() {
+    let mut rng = OsRng;
+    for _ in 0..10 {
+        let (a, b) = (rng.next_u64(), rng.next_u64());
+        uint_ops::<Bn254Driver, 8>(a, b);
+        uint_ops::<Bn254Driver, 32>(a, b);
+        uint_ops::<Bn254Driver, 64>(a, b);
+        uint_ops::<GrumpkinDriver, 8>(a, b);
+        uint_ops::<GrumpkinDriver, 32>(a, b);
+        uint_ops::<GrumpkinDriver, 64>(a, b);
+    }
+    uint_ops::<Bn254Driver, 64>(u64::MAX, u64::MAX);
+    uint_ops::<GrumpkinDriver, 32>(u64::MAX, 1);
+}
+
+#[test]
+fn uint_bitwise_test() {
+    let mut rng = OsRng;
+    let values = (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>();
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let words = values
+        .iter()
+        .map(|value| UInt64::witness(&mut cs, *value))
+        .collect::<Vec<_>>();
+
+    // each bit of a chained result is a single wire costing one constraint
+    let m = cs.m();
+    let xor = words[1..].iter().fold(words[0].clone(), |acc, word| {
+        UInt64::xor(&mut cs, &acc, word)
+    });
+    let or = words[1..].iter().fold(words[0].clone(), |acc, word| {
+        UInt64::or(&mut cs, &acc, word)
+    });
+    assert_eq!(cs.m() - m, 2 * 4 * 64);
+    assert!(xor.bits().iter().all(|bit| bit.inner().num_terms() == 1));
+    assert_eq!(
+        xor.value(&cs),
+        values.iter().fold(0, |acc, value| acc ^ value)
+    );
+    assert_eq!(
+        or.value(&cs),
+        values.iter().fold(0, |acc, value| acc | value)
+    );
+
+    // constant operands
+    let c = rng.next_u64();
+    let xor = UInt64::xor(&mut cs, &words[0], &UInt64::constant(c));
+    let or = UInt64::or(&mut cs, &UInt64::constant(c), &words[1]);
+    let constant = UInt64::xor(&mut cs, &UInt64::constant(c), &UInt64::constant(!c));
+    assert_eq!(xor.value(&cs), values[0] ^ c);
+    assert_eq!(or.value(&cs), values[1] | c);
+    assert_eq!(constant.value(&cs), u64::MAX);
+    assert!(cs.is_sat());
+}
+
+#[test]
+fn uint_add_many_test() {
+    let mut rng = OsRng;
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let values: Vec<u32> = (0..5).map(|_| rng.next_u32()).collect();
+    let operands: Vec<_> = values
+        .iter()
+        .map(|v| UInt32::witness(&mut cs, *v as u64))
+        .collect();
+
+    let m = cs.m();
+    let sum = UInt32::add_many(&mut cs, &operands);
+    // 32 result bits, 3 carry bits and the recomposition
+    assert_eq!(cs.m() - m, 36);
+    let expected = values.iter().fold(0u32, |acc, v| acc.wrapping_add(*v));
+    assert_eq!(sum.value(&cs), expected as u64);
+    assert!(cs.is_sat());
+}
+
+#[test]
+fn uint_bytes_test() {
+    let mut cs: R1cs<GrumpkinDriver> = R1cs::default();
+    let value = 0x0123_4567_89ab_cdef;
+    let x = UInt64::witness(&mut cs, value);
+
+    let be: Vec<u64> = x.to_bytes_be().iter().map(|b| b.value(&cs)).collect();
+    let le: Vec<u64> = x.to_bytes_le().iter().map(|b| b.value(&cs)).collect();
+    let expected_be: Vec<u64> = value.to_be_bytes().iter().map(|b| *b as u64).collect();
+    let expected_le: Vec<u64> = value.to_le_bytes().iter().map(|b| *b as u64).collect();
+    assert_eq!(be, expected_be);
+    assert_eq!(le, expected_le);
+
+    let bytes: Vec<_> = [0xde, 0xad, 0xbe, 0xef]
+        .iter()
+        .map(|b| UInt8::constant(*b))
+        .collect();
+    assert_eq!(UInt32::from_bytes_be(&bytes).value(&cs), 0xdeadbeef);
+    assert_eq!(UInt32::from_bytes_le(&bytes).value(&cs), 0xefbeadde);
+}
+
+#[test]
+fn uint_from_field_bound_test() {
+    let mut cs: R1cs<Bn254Driver> = R1cs::default();
+    let x = FieldAssignment::witness(&mut cs, <Bn254Driver as CircuitDriver>::Scalar::from(256));
+    UInt8::from_field(&mut cs, &x);
+    assert!(!cs.is_sat());
+}
@@ -0,0 +1,294 @@
+use crate::params::{GENERATOR_X, GENERATOR_Y, PARAM_B, PARAM_B3};
+use crate::{Fq, Fr};
+use core::borrow::Borrow;
+use core::iter::Sum;
+use zkstd::arithmetic::weierstrass::*;
+use zkstd::common::*;
+use zkstd::macros::curve::weierstrass::*;
+
+/// The affine form of coordinate
+#[derive(Debug, Clone, Copy, Decode, Encode)]
+pub struct Affine {
+    pub(crate) x: Fq,
+    pub(crate) y: Fq,
+    is_infinity: bool,
+}
+
+impl Affine {
+    /// SEC1 uncompressed encoding `0x04 || x || y` with big-endian coordinates
+    pub fn to_uncompressed(&self) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[0] = 4;
+        let (x, y) = (self.x.to_bytes(), self.y.to_bytes());
+        bytes[1..33].copy_from_slice(&x);
+        bytes[33..].copy_from_slice(&y);
+        bytes[1..33].reverse();
+        bytes[33..].reverse();
+
+        bytes
+    }
+
+    /// The point with the x coordinate whose y coordinate has the given parity
+    pub fn from_x(x: Fq, is_odd: bool) -> Option<Self> {
+        let y = (x.square() * x + PARAM_B).sqrt()?;
+        let y = if y.is_odd() == is_odd { y } else { -y };
+
+        Some(Self {
+            x,
+            y,
+            is_infinity: false,
+        })
+    }
+}
+
+impl Add for Affine {
+    type Output = Projective;
+
+    fn add(self, rhs: Affine) -> Self::Output {
+        add_affine_point(self, rhs)
+    }
+}
+
+impl Neg for Affine {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+            is_infinity: self.is_infinity,
+        }
+    }
+}
+
+impl Sub for Affine {
+    type Output = Projective;
+
+    fn sub(self, rhs: Affine) -> Self::Output {
+        add_affine_point(self, rhs.neg())
+    }
+}
+
+impl Mul<Fr> for Affine {
+    type Output = Projective;
+
+    fn mul(self, rhs: Fr) -> Self::Output {
+        scalar_point(self.to_extended(), &rhs)
+    }
+}
+
+impl Mul<Affine> for Fr {
+    type Output = Projective;
+
+    fn mul(self, rhs: Affine) -> Self::Output {
+        scalar_point(rhs.to_extended(), &self)
+    }
+}
+
+/// The projective form of coordinate
+#[derive(Debug, Clone, Copy, Decode, Encode)]
+pub struct Projective {
+    pub(crate) x: Fq,
+    pub(crate) y: Fq,
+    pub(crate) z: Fq,
+}
+
+impl Projective {
+    /// Converts a batch of `G1Projective` elements into `G1Affine` elements. This
+    /// function will panic if `p.len() != q.len()`.
+    pub fn batch_normalize(p: &[Self], q: &mut [Affine]) {
+        assert_eq!(p.len(), q.len());
+
+        p.iter()
+            .zip(q.iter_mut())
+            .for_each(|(a, b)| *b = Affine::from(*a))
+    }
+}
+
+impl Add for Projective {
+    type Output = Self;
+
+    fn add(self, rhs: Projective) -> Self {
+        add_projective_point(self, rhs)
+    }
+}
+
+impl Neg for Projective {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl Sub for Projective {
+    type Output = Self;
+
+    fn sub(self, rhs: Projective) -> Self {
+        add_projective_point(self, -rhs)
+    }
+}
+
+impl Mul<Fr> for Projective {
+    type Output = Projective;
+
+    fn mul(self, rhs: Fr) -> Self::Output {
+        scalar_point(self, &rhs)
+    }
+}
+
+impl Mul<Projective> for Fr {
+    type Output = Projective;
+
+    fn mul(self, rhs: Projective) -> Self::Output {
+        scalar_point(rhs, &self)
+    }
+}
+
+impl<T> Sum<T> for Projective
+where
+    T: Borrow<Projective>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::ADDITIVE_IDENTITY, |acc, item| acc + *item.borrow())
+    }
+}
+
+weierstrass_curve_operation!(
+    Fr,
+    Fq,
+    PARAM_B,
+    PARAM_B3,
+    Affine,
+    Projective,
+    GENERATOR_X,
+    GENERATOR_Y
+);
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use rand_core::OsRng;
+
+    curve_test!(secp256k1, Fr, Affine, Projective, 100);
+
+    #[test]
+    fn test_batch_normalize() {
+        let a = Projective::ADDITIVE_GENERATOR.double();
+        let b = a.double();
+        let c = b.double();
+
+        for a_identity in (0..1).map(|n| n == 1) {
+            for b_identity in (0..1).map(|n| n == 1) {
+                for c_identity in (0..1).map(|n| n == 1) {
+                    let mut v = [a, b, c];
+                    if a_identity {
+                        v[0] = Projective::ADDITIVE_IDENTITY
+                    }
+                    if b_identity {
+                        v[1] = Projective::ADDITIVE_IDENTITY
+                    }
+                    if c_identity {
+                        v[2] = Projective::ADDITIVE_IDENTITY
+                    }
+
+                    let mut t = [
+                        Affine::ADDITIVE_IDENTITY,
+                        Affine::ADDITIVE_IDENTITY,
+                        Affine::ADDITIVE_IDENTITY,
+                    ];
+                    let expected = [Affine::from(v[0]), Affine::from(v[1]), Affine::from(v[2])];
+
+                    Projective::batch_normalize(&v[..], &mut t[..]);
+
+                    assert_eq!(&t[..], &expected[..]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn secp256k1_operations() {
+        let mut rng = OsRng;
+        let aff1 = Affine::random(&mut rng);
+        let aff2 = Affine::random(&mut rng);
+        let mut ext1 = Projective::random(&mut rng);
+        let ext2 = Projective::random(&mut rng);
+        let scalar = Fr::from(42);
+
+        let _ = aff1 + aff2;
+        let _ = &aff1 + &aff2;
+        let _ = &aff1 + aff2;
+        let _ = aff1 + &aff2;
+
+        let _ = aff1 + ext1;
+        let _ = &aff1 + &ext1;
+        let _ = &aff1 + ext1;
+        let _ = aff1 + &ext1;
+        let _ = ext1 + aff1;
+        let _ = &ext1 + &aff1;
+        let _ = &ext1 + aff1;
+        let _ = ext1 + &aff1;
+
+        let _ = ext1 + ext2;
+        let _ = &ext1 + &ext2;
+        let _ = &ext1 + ext2;
+        let _ = ext1 + &ext2;
+        ext1 += ext2;
+        ext1 += &ext2;
+        ext1 += aff2;
+        ext1 += &aff2;
+
+        let _ = aff1 - aff2;
+        let _ = &aff1 - &aff2;
+        let _ = &aff1 - aff2;
+        let _ = aff1 - &aff2;
+
+        let _ = aff1 - ext1;
+        let _ = &aff1 - &ext1;
+        let _ = &aff1 - ext1;
+        let _ = aff1 - &ext1;
+        let _ = ext1 - aff1;
+        let _ = &ext1 - &aff1;
+        let _ = &ext1 - aff1;
+        let _ = ext1 - &aff1;
+
+        let _ = ext1 - ext2;
+        let _ = &ext1 - &ext2;
+        let _ = &ext1 - ext2;
+        let _ = ext1 - &ext2;
+        ext1 -= ext2;
+        ext1 -= &ext2;
+        ext1 -= aff2;
+        ext1 -= &aff2;
+
+        let _ = aff1 * scalar;
+        let _ = aff1 * &scalar;
+        let _ = &aff1 * scalar;
+        let _ = &aff1 * &scalar;
+        let _ = scalar * aff1;
+        let _ = &scalar * &aff1;
+        let _ = scalar * &aff1;
+        let _ = &scalar * aff1;
+
+        let _ = ext1 * scalar;
+        let _ = ext1 * &scalar;
+        let _ = &ext1 * scalar;
+        let _ = &ext1 * &scalar;
+        let _ = scalar * ext1;
+        let _ = &scalar * &ext1;
+        let _ = scalar * &ext1;
+        let _ = &scalar * ext1;
+        ext1 *= scalar;
+        ext1 *= &scalar;
+    }
+}
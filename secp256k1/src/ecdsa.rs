@@ -0,0 +1,225 @@
+use crate::{Affine, Fq, Fr, Projective};
+use zkstd::common::{BNProjective, CurveGroup, Group, RngCore, SigUtils};
+
+/// ECDSA secret key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SecretKey(Fr);
+
+/// ECDSA public key `Q = dG`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey(Affine);
+
+/// ECDSA signature `(r, s)` normalized to the lower half of `s`,
+/// with the parity of the nonce point y coordinate to recover the public key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    r: Fr,
+    s: Fr,
+    v: bool,
+}
+
+impl SecretKey {
+    pub fn new(key: Fr) -> Self {
+        Self(key)
+    }
+
+    pub fn random(rng: &mut impl RngCore) -> Self {
+        Self(Fr::random(rng))
+    }
+
+    pub fn to_public_key(&self) -> PublicKey {
+        PublicKey(Affine::from(Affine::ADDITIVE_GENERATOR * self.0))
+    }
+
+    /// Sign the message hash `z`, see [`Fr::from_be_bytes_reduced`] for digests
+    pub fn sign(&self, z: Fr, rng: &mut impl RngCore) -> Signature {
+        loop {
+            let k = Fr::random(&mut *rng);
+            let point = Affine::from(Affine::ADDITIVE_GENERATOR * k);
+            let r = Fr::from(point.get_x());
+            // skip the negligible nonces whose x coordinate exceeds the group order
+            if r == Fr::zero() || Fq::from(r) != point.get_x() {
+                continue;
+            }
+            let s = k.invert().unwrap() * (z + r * self.0);
+            if s == Fr::zero() {
+                continue;
+            }
+            let v = point.get_y().is_odd();
+            return match s.is_high() {
+                true => Signature { r, s: -s, v: !v },
+                false => Signature { r, s, v },
+            };
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    pub fn from_bytes(bytes: [u8; 32]) -> Option<Self> {
+        Fr::from_bytes(bytes).map(Self)
+    }
+}
+
+impl PublicKey {
+    pub fn new(point: Affine) -> Self {
+        Self(point)
+    }
+
+    pub fn point(&self) -> Affine {
+        self.0
+    }
+
+    /// Check `x(z/s G + r/s Q) = r mod n`
+    pub fn verify(&self, z: Fr, signature: &Signature) -> bool {
+        let Signature { r, s, .. } = *signature;
+        if r == Fr::zero() || s == Fr::zero() || self.0.is_identity() {
+            return false;
+        }
+        let w = s.invert().unwrap();
+        let point = Affine::ADDITIVE_GENERATOR * (z * w) + self.0 * (r * w);
+        let point = point.to_affine();
+
+        !point.is_identity() && Fr::from(point.get_x()) == r
+    }
+
+    /// Recover the public key `r^-1 (sR - zG)` from the nonce point `R`
+    pub fn recover(z: Fr, signature: &Signature) -> Option<Self> {
+        let Signature { r, s, v } = *signature;
+        if r == Fr::zero() || s == Fr::zero() {
+            return None;
+        }
+        let nonce = Affine::from_x(Fq::from(r), v)?;
+        let r_inv = r.invert().unwrap();
+        let point =
+            Projective::from(nonce) * (s * r_inv) - Affine::ADDITIVE_GENERATOR * (z * r_inv);
+        let point = point.to_affine();
+
+        match point.is_identity() {
+            true => None,
+            false => Some(Self(point)),
+        }
+    }
+}
+
+impl Signature {
+    pub fn new(r: Fr, s: Fr, v: bool) -> Self {
+        Self { r, s, v }
+    }
+
+    pub fn r(&self) -> Fr {
+        self.r
+    }
+
+    pub fn s(&self) -> Fr {
+        self.s
+    }
+
+    /// Whether the nonce point has an odd y coordinate
+    pub fn v(&self) -> bool {
+        self.v
+    }
+
+    /// Big-endian `r || s || v` as in Ethereum transactions without the offset of `v`
+    pub fn to_bytes(&self) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[..32].copy_from_slice(&self.r.to_bytes());
+        bytes[32..64].copy_from_slice(&self.s.to_bytes());
+        bytes[..32].reverse();
+        bytes[32..64].reverse();
+        bytes[64] = self.v.into();
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: [u8; 65]) -> Option<Self> {
+        let mut r = [0u8; 32];
+        let mut s = [0u8; 32];
+        r.copy_from_slice(&bytes[..32]);
+        s.copy_from_slice(&bytes[32..64]);
+        r.reverse();
+        s.reverse();
+        let v = match bytes[64] {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+
+        Some(Self {
+            r: Fr::from_bytes(r)?,
+            s: Fr::from_bytes(s)?,
+            v,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    fn from_hex(hex: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn sign_verify_recover_test() {
+        let mut rng = OsRng;
+        let secret = SecretKey::random(&mut rng);
+        let public = secret.to_public_key();
+        let z = Fr::random(&mut rng);
+
+        let signature = secret.sign(z, &mut rng);
+        assert!(!signature.s().is_high());
+        assert!(public.verify(z, &signature));
+        assert_eq!(PublicKey::recover(z, &signature), Some(public));
+        assert!(!public.verify(z + Fr::one(), &signature));
+        assert_ne!(PublicKey::recover(z + Fr::one(), &signature), Some(public));
+
+        let bytes = signature.to_bytes();
+        assert_eq!(Signature::from_bytes(bytes), Some(signature));
+    }
+
+    // web3.eth.accounts.sign("Some data", key) from the web3.js documentation
+    #[test]
+    fn known_signature_test() {
+        let secret = SecretKey::from_bytes({
+            let mut key =
+                from_hex("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318");
+            key.reverse();
+            key
+        })
+        .unwrap();
+        let z = Fr::from_be_bytes_reduced(&from_hex(
+            "1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655",
+        ));
+        let mut bytes = [0u8; 65];
+        bytes[..32].copy_from_slice(&from_hex(
+            "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd",
+        ));
+        bytes[32..64].copy_from_slice(&from_hex(
+            "6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a029",
+        ));
+        // v = 0x1c
+        bytes[64] = 1;
+        let signature = Signature::from_bytes(bytes).unwrap();
+
+        let public = secret.to_public_key();
+        let uncompressed = public.point().to_uncompressed();
+        assert_eq!(
+            uncompressed[1..33],
+            from_hex("4e3b81af9c2234cad09d679ce6035ed1392347ce64ce405f5dcd36228a25de6e")
+        );
+        assert_eq!(
+            uncompressed[33..],
+            from_hex("47fd35c4215d1edf53e6f83de344615ce719bdb0fd878f6ed76f06dd277956de")
+        );
+        assert!(public.verify(z, &signature));
+        assert_eq!(PublicKey::recover(z, &signature), Some(public));
+    }
+}
@@ -0,0 +1,179 @@
+use crate::Fr;
+use core::borrow::Borrow;
+use core::iter::{Product, Sum};
+use zkstd::arithmetic::bits_256::*;
+use zkstd::common::*;
+use zkstd::macros::field::*;
+
+/// Constant representing the modulus
+/// p = 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f
+pub(crate) const MODULUS: [u64; 4] = [
+    0xfffffffefffffc2f,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+];
+
+const GENERATOR: [u64; 4] = [3, 0, 0, 0];
+
+/// R = 2^256 mod p
+const R: [u64; 4] = [0x00000001000003d1, 0, 0, 0];
+
+/// R^2 = 2^512 mod p
+const R2: [u64; 4] = [0x000007a2000e90a1, 0x0000000000000001, 0, 0];
+
+/// R^3 = 2^768 mod p
+const R3: [u64; 4] = [0x002bb1e33795f671, 0x0000000100000b73, 0, 0];
+
+/// INV = -(p^{-1} mod 2^64) mod 2^64
+const INV: u64 = 0xd838091dd2253531;
+
+/// secp256k1 curve base field
+#[derive(Clone, Copy, Decode, Encode, Serialize, Deserialize)]
+pub struct Fq(pub(crate) [u64; 4]);
+
+impl SigUtils<32> for Fq {
+    fn to_bytes(self) -> [u8; Self::LENGTH] {
+        let tmp = self.montgomery_reduce();
+
+        let mut res = [0; Self::LENGTH];
+        res[0..8].copy_from_slice(&tmp[0].to_le_bytes());
+        res[8..16].copy_from_slice(&tmp[1].to_le_bytes());
+        res[16..24].copy_from_slice(&tmp[2].to_le_bytes());
+        res[24..32].copy_from_slice(&tmp[3].to_le_bytes());
+
+        res
+    }
+
+    fn from_bytes(bytes: [u8; Self::LENGTH]) -> Option<Self> {
+        let l0 = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+        let l1 = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        let l2 = u64::from_le_bytes(bytes[16..24].try_into().unwrap());
+        let l3 = u64::from_le_bytes(bytes[24..32].try_into().unwrap());
+
+        let (_, borrow) = sbb(l0, MODULUS[0], 0);
+        let (_, borrow) = sbb(l1, MODULUS[1], borrow);
+        let (_, borrow) = sbb(l2, MODULUS[2], borrow);
+        let (_, borrow) = sbb(l3, MODULUS[3], borrow);
+
+        if borrow & 1 == 1 {
+            Some(Self([l0, l1, l2, l3]) * Self(R2))
+        } else {
+            None
+        }
+    }
+}
+
+impl Fq {
+    pub const fn to_mont_form(val: [u64; 4]) -> Self {
+        Self(to_mont_form(val, R2, MODULUS, INV))
+    }
+
+    pub(crate) const fn montgomery_reduce(self) -> [u64; 4] {
+        mont(
+            [self.0[0], self.0[1], self.0[2], self.0[3], 0, 0, 0, 0],
+            MODULUS,
+            INV,
+        )
+    }
+
+    /// p = 3 mod 4 so the square root is self^((p + 1) / 4)
+    pub fn sqrt(&self) -> Option<Self> {
+        let sqrt = Self(pow(
+            self.0,
+            [
+                0xffffffffbfffff0c,
+                0xffffffffffffffff,
+                0xffffffffffffffff,
+                0x3fffffffffffffff,
+            ],
+            R,
+            MODULUS,
+            INV,
+        ));
+
+        match sqrt.square() == *self {
+            true => Some(sqrt),
+            false => None,
+        }
+    }
+
+    pub fn is_odd(&self) -> bool {
+        self.montgomery_reduce()[0] & 1 == 1
+    }
+}
+
+impl<T> Product<T> for Fq
+where
+    T: Borrow<Fq>,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::one(), |acc, item| acc * *item.borrow())
+    }
+}
+
+impl<T> Sum<T> for Fq
+where
+    T: Borrow<Fq>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::zero(), |acc, item| acc + *item.borrow())
+    }
+}
+
+prime_field_operation!(Fq, MODULUS, GENERATOR, INV, R, R2, R3);
+
+/// Embed a scalar into the base field, the group order is smaller than the base field modulus
+impl From<Fr> for Fq {
+    fn from(val: Fr) -> Fq {
+        Fq::to_mont_form(val.montgomery_reduce())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::paste;
+    use rand_core::OsRng;
+
+    field_test!(secp256k1_base, Fq, 1000);
+
+    #[test]
+    fn test_serde() {
+        let mut rng = OsRng;
+        for _ in 0..1000 {
+            let s = Fq::random(&mut rng);
+            let bytes = s.to_bytes();
+            let s_prime = Fq::from_bytes(bytes).unwrap();
+            assert_eq!(s, s_prime);
+        }
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let mut rng = OsRng;
+        for _ in 0..100 {
+            let a = Fq::random(&mut rng).square();
+            let b = a.sqrt().unwrap();
+            assert_eq!(b.square(), a);
+        }
+        // 3 is the multiplicative generator and hence a non residue
+        assert!(Fq::from(3).sqrt().is_none());
+    }
+
+    #[test]
+    fn test_overflow() {
+        // p - 1 doubled and squared exceed 2^256 before the reduction
+        let minus_one = -Fq::one();
+        assert_eq!(minus_one + minus_one, -Fq::from(2));
+        assert_eq!(minus_one.double(), -Fq::from(2));
+        assert_eq!(minus_one.square(), Fq::one());
+        assert_eq!(minus_one * Fq::from(3), -Fq::from(3));
+    }
+}
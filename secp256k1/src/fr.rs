@@ -0,0 +1,189 @@
+use crate::Fq;
+use core::borrow::Borrow;
+use core::iter::{Product, Sum};
+use zkstd::arithmetic::bits_256::*;
+use zkstd::common::*;
+use zkstd::macros::field::*;
+
+/// Constant representing the modulus
+/// n = 0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141
+pub(crate) const MODULUS: [u64; 4] = [
+    0xbfd25e8cd0364141,
+    0xbaaedce6af48a03b,
+    0xfffffffffffffffe,
+    0xffffffffffffffff,
+];
+
+const GENERATOR: [u64; 4] = [7, 0, 0, 0];
+
+/// R = 2^256 mod n
+const R: [u64; 4] = [
+    0x402da1732fc9bebf,
+    0x4551231950b75fc4,
+    0x0000000000000001,
+    0x0000000000000000,
+];
+
+/// R^2 = 2^512 mod n
+const R2: [u64; 4] = [
+    0x896cf21467d7d140,
+    0x741496c20e7cf878,
+    0xe697f5e45bcd07c6,
+    0x9d671cd581c69bc5,
+];
+
+/// R^3 = 2^768 mod n
+const R3: [u64; 4] = [
+    0x7bc0cfe0e9ff41ed,
+    0x0017648444d4322c,
+    0xb1b31347f1d0b2da,
+    0x555d800c18ef116d,
+];
+
+/// INV = -(n^{-1} mod 2^64) mod 2^64
+const INV: u64 = 0x4b0dff665588b13f;
+
+/// secp256k1 curve scalar field, the order of the curve
+#[derive(Clone, Copy, Decode, Encode, Serialize, Deserialize)]
+pub struct Fr(pub(crate) [u64; 4]);
+
+impl SigUtils<32> for Fr {
+    fn to_bytes(self) -> [u8; Self::LENGTH] {
+        let tmp = self.montgomery_reduce();
+
+        let mut res = [0; Self::LENGTH];
+        res[0..8].copy_from_slice(&tmp[0].to_le_bytes());
+        res[8..16].copy_from_slice(&tmp[1].to_le_bytes());
+        res[16..24].copy_from_slice(&tmp[2].to_le_bytes());
+        res[24..32].copy_from_slice(&tmp[3].to_le_bytes());
+
+        res
+    }
+
+    fn from_bytes(bytes: [u8; Self::LENGTH]) -> Option<Self> {
+        let l0 = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+        let l1 = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        let l2 = u64::from_le_bytes(bytes[16..24].try_into().unwrap());
+        let l3 = u64::from_le_bytes(bytes[24..32].try_into().unwrap());
+
+        let (_, borrow) = sbb(l0, MODULUS[0], 0);
+        let (_, borrow) = sbb(l1, MODULUS[1], borrow);
+        let (_, borrow) = sbb(l2, MODULUS[2], borrow);
+        let (_, borrow) = sbb(l3, MODULUS[3], borrow);
+
+        if borrow & 1 == 1 {
+            Some(Self([l0, l1, l2, l3]) * Self(R2))
+        } else {
+            None
+        }
+    }
+}
+
+impl Fr {
+    pub const fn to_mont_form(val: [u64; 4]) -> Self {
+        Self(to_mont_form(val, R2, MODULUS, INV))
+    }
+
+    pub(crate) const fn montgomery_reduce(self) -> [u64; 4] {
+        mont(
+            [self.0[0], self.0[1], self.0[2], self.0[3], 0, 0, 0, 0],
+            MODULUS,
+            INV,
+        )
+    }
+
+    /// Interpret big-endian bytes such as a message digest as an integer modulo n
+    pub fn from_be_bytes_reduced(bytes: &[u8; 32]) -> Self {
+        let mut wide = [0; 64];
+        wide[..32].copy_from_slice(bytes);
+        wide[..32].reverse();
+        Self::from_bytes_wide(&wide)
+    }
+
+    pub fn is_high(&self) -> bool {
+        let tmp = self.montgomery_reduce();
+
+        // (n - 1) / 2
+        let (_, borrow) = sbb(0xdfe92f46681b20a0, tmp[0], 0);
+        let (_, borrow) = sbb(0x5d576e7357a4501d, tmp[1], borrow);
+        let (_, borrow) = sbb(0xffffffffffffffff, tmp[2], borrow);
+        let (_, borrow) = sbb(0x7fffffffffffffff, tmp[3], borrow);
+
+        borrow & 1 == 1
+    }
+}
+
+impl<T> Product<T> for Fr
+where
+    T: Borrow<Fr>,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::one(), |acc, item| acc * *item.borrow())
+    }
+}
+
+impl<T> Sum<T> for Fr
+where
+    T: Borrow<Fr>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::zero(), |acc, item| acc + *item.borrow())
+    }
+}
+
+prime_field_operation!(Fr, MODULUS, GENERATOR, INV, R, R2, R3);
+
+/// Reduce a base field element modulo the group order
+impl From<Fq> for Fr {
+    fn from(val: Fq) -> Fr {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&val.to_bytes());
+        Fr::from_bytes_wide(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::paste;
+    use rand_core::OsRng;
+
+    field_test!(secp256k1_scalar, Fr, 1000);
+
+    #[test]
+    fn test_serde() {
+        let mut rng = OsRng;
+        for _ in 0..1000 {
+            let s = Fr::random(&mut rng);
+            let bytes = s.to_bytes();
+            let s_prime = Fr::from_bytes(bytes).unwrap();
+            assert_eq!(s, s_prime);
+        }
+    }
+
+    #[test]
+    fn to_fq_and_back() {
+        let mut rng = OsRng;
+        let x = Fr::random(&mut rng);
+        assert_eq!(x, Fr::from(Fq::from(x)));
+
+        // n reduces to zero
+        let n = Fq::to_mont_form(MODULUS);
+        assert_eq!(Fr::from(n), Fr::zero());
+    }
+
+    #[test]
+    fn is_high_test() {
+        let half = Fr::from(2).invert().unwrap();
+        assert!(!(half - Fr::one()).is_high());
+        assert!(half.is_high());
+        assert!((-Fr::one()).is_high());
+        assert!(!Fr::one().is_high());
+    }
+}
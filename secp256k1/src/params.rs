@@ -0,0 +1,20 @@
+use crate::Fq;
+
+// generator of the curve group
+pub const GENERATOR_X: Fq = Fq::to_mont_form([
+    0x59f2815b16f81798,
+    0x029bfcdb2dce28d9,
+    0x55a06295ce870b07,
+    0x79be667ef9dcbbac,
+]);
+
+pub const GENERATOR_Y: Fq = Fq::to_mont_form([
+    0x9c47d08ffb10d4b8,
+    0xfd17b448a6855419,
+    0x5da4fbfc0e1108a8,
+    0x483ada7726a3c465,
+]);
+
+// secp256k1 curve b param
+pub const PARAM_B: Fq = Fq::to_mont_form([7, 0, 0, 0]);
+pub const PARAM_B3: Fq = Fq::to_mont_form([21, 0, 0, 0]);
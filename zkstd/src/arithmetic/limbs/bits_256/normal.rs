@@ -8,7 +8,8 @@ pub const fn add(a: [u64; 4], b: [u64; 4], p: [u64; 4]) -> [u64; 4] {
     let (l1, c) = (s as u64, (s >> 64) as u64);
     let s = a[2] as u128 + b[2] as u128 + c as u128;
     let (l2, c) = (s as u64, (s >> 64) as u64);
-    let l3 = a[3].wrapping_add(b[3]).wrapping_add(c);
+    let s = a[3] as u128 + b[3] as u128 + c as u128;
+    let (l3, l4) = (s as u64, (s >> 64) as u64);
 
     let s = (l0 as u128).wrapping_sub(p[0] as u128);
     let (l0, brw) = (s as u64, (s >> 64) as u64);
@@ -18,6 +19,7 @@ pub const fn add(a: [u64; 4], b: [u64; 4], p: [u64; 4]) -> [u64; 4] {
     let (l2, brw) = (s as u64, (s >> 64) as u64);
     let s = (l3 as u128).wrapping_sub(p[3] as u128 + (brw >> 63) as u128);
     let (l3, brw) = (s as u64, (s >> 64) as u64);
+    let brw = overflow_borrow(l4, brw, p);
 
     let s = l0 as u128 + (p[0] & brw) as u128;
     let (l0, c) = (s as u64, (s >> 64) as u64);
@@ -58,6 +60,7 @@ pub const fn double(a: [u64; 4], p: [u64; 4]) -> [u64; 4] {
     let l1 = a[1] << 1 | a[0] >> 63;
     let l2 = a[2] << 1 | a[1] >> 63;
     let l3 = a[3] << 1 | a[2] >> 63;
+    let l4 = a[3] >> 63;
 
     let s = (l0 as u128).wrapping_sub(p[0] as u128);
     let (l0, brw) = (s as u64, (s >> 64) as u64);
@@ -67,6 +70,7 @@ pub const fn double(a: [u64; 4], p: [u64; 4]) -> [u64; 4] {
     let (l2, brw) = (s as u64, (s >> 64) as u64);
     let s = (l3 as u128).wrapping_sub(p[3] as u128 + (brw >> 63) as u128);
     let (l3, brw) = (s as u64, (s >> 64) as u64);
+    let brw = overflow_borrow(l4, brw, p);
 
     let s = l0 as u128 + (p[0] & brw) as u128;
     let (l0, c) = (s as u64, (s >> 64) as u64);
@@ -136,7 +140,9 @@ pub const fn square(a: [u64; 4], p: [u64; 4], inv: u64) -> [u64; 4] {
     let (l3, c) = (s as u64, (s >> 64) as u64);
     let s = l4 as u128 + c as u128;
     let (l4, c) = (s as u64, (s >> 64) as u64);
-    let l5 = l5.wrapping_add(c);
+    let s = l5 as u128 + c as u128;
+    let (l5, c) = (s as u64, (s >> 64) as u64);
+    let l6 = l6 + c;
 
     let l7 = l6 >> 63;
     let l6 = (l6 << 1) | (l5 >> 63);
@@ -230,7 +236,8 @@ pub const fn mont(a: [u64; 8], p: [u64; 4], inv: u64) -> [u64; 4] {
     let (l5, d) = (s as u64, (s >> 64) as u64);
     let s = l6 as u128 + rhs as u128 * p[3] as u128 + d as u128;
     let (l6, d) = (s as u64, (s >> 64) as u64);
-    let l7 = a[7].wrapping_add(e).wrapping_add(d);
+    let s = a[7] as u128 + e as u128 + d as u128;
+    let (l7, l8) = (s as u64, (s >> 64) as u64);
 
     let s = (l4 as u128).wrapping_sub(p[0] as u128);
     let (l0, brw) = (s as u64, (s >> 64) as u64);
@@ -240,6 +247,7 @@ pub const fn mont(a: [u64; 8], p: [u64; 4], inv: u64) -> [u64; 4] {
     let (l2, brw) = (s as u64, (s >> 64) as u64);
     let s = (l7 as u128).wrapping_sub(p[3] as u128 + (brw >> 63) as u128);
     let (l3, brw) = (s as u64, (s >> 64) as u64);
+    let brw = overflow_borrow(l8, brw, p);
 
     let s = l0 as u128 + (p[0] & brw) as u128;
     let (l0, c) = (s as u64, (s >> 64) as u64);
@@ -252,6 +260,18 @@ pub const fn mont(a: [u64; 8], p: [u64; 4], inv: u64) -> [u64; 4] {
     [l0, l1, l2, l3]
 }
 
+/// Borrow mask of subtracting the modulus from a value whose bit 256 is `carry`.
+/// Only a modulus using the full 256 bits lets the value before reduction overflow,
+/// for the others the modulus is a constant of the inlined caller and the carry is dropped.
+#[inline(always)]
+const fn overflow_borrow(carry: u64, brw: u64, p: [u64; 4]) -> u64 {
+    if p[3] >> 63 == 0 {
+        return brw;
+    }
+    let s = (carry as u128).wrapping_sub((brw >> 63) as u128);
+    (s >> 64) as u64
+}
+
 #[inline(always)]
 pub fn invert(
     a: [u64; 4],
@@ -1,3 +1,12 @@
+pub mod affine_curve;
 pub mod binary;
+pub mod blake2;
 pub mod curve;
+pub mod ecdsa;
+pub mod edwards;
 pub mod field;
+pub mod keccak;
+pub mod nonnative;
+pub mod nonnative_curve;
+pub mod sha256;
+pub mod uint;
@@ -0,0 +1,206 @@
+use super::binary::BinaryAssignment;
+use super::curve::PointAssignment;
+use super::edwards::quotient;
+use super::field::FieldAssignment;
+use super::nonnative_curve::offset_point;
+
+use crate::circuit::CircuitDriver;
+use crate::common::{BNAffine, IntGroup, PrimeField, Ring};
+use crate::r1cs::R1cs;
+
+/// Affine point of a short Weierstrass curve `y^2 = x^3 + b` defined over the circuit field.
+/// The identity is not representable and the addition formulas are incomplete, which makes
+/// them several times cheaper than the complete projective formulas of `PointAssignment`.
+/// Every exceptional case is unsatisfiable rather than silently wrong, and the points must be
+/// on the curve.
+#[derive(Clone)]
+pub struct AffinePointAssignment<C: BNAffine> {
+    x: FieldAssignment<C::Base>,
+    y: FieldAssignment<C::Base>,
+}
+
+impl<C: BNAffine> AffinePointAssignment<C> {
+    /// The point is not checked to be on the curve, see `enforce_on_curve`
+    pub fn witness<CS: CircuitDriver<Scalar = C::Base>>(cs: &mut R1cs<CS>, point: C) -> Self {
+        assert!(!point.is_identity());
+        let x = FieldAssignment::witness(cs, point.get_x());
+        let y = FieldAssignment::witness(cs, point.get_y());
+
+        Self { x, y }
+    }
+
+    pub fn constant(point: C) -> Self {
+        assert!(!point.is_identity());
+        let x = FieldAssignment::constant(&point.get_x());
+        let y = FieldAssignment::constant(&point.get_y());
+
+        Self { x, y }
+    }
+
+    pub fn value<CS: CircuitDriver<Scalar = C::Base>>(&self, cs: &R1cs<CS>) -> C {
+        C::new_unchecked(self.x.value(cs), self.y.value(cs))
+    }
+
+    pub fn get_x(&self) -> FieldAssignment<C::Base> {
+        self.x.clone()
+    }
+
+    pub fn get_y(&self) -> FieldAssignment<C::Base> {
+        self.y.clone()
+    }
+
+    /// constraints: 3
+    pub fn enforce_on_curve<CS: CircuitDriver<Scalar = C::Base>>(&self, cs: &mut R1cs<CS>) {
+        let b = FieldAssignment::constant(&C::PARAM_B);
+        let xx = FieldAssignment::mul(cs, &self.x, &self.x);
+        let xxx = FieldAssignment::mul(cs, &xx, &self.x);
+        let yy = FieldAssignment::mul(cs, &self.y, &self.y);
+        FieldAssignment::enforce_eq(cs, &yy, &(&xxx + &b));
+    }
+
+    /// constraints: 2
+    pub fn enforce_equal<CS: CircuitDriver<Scalar = C::Base>>(
+        cs: &mut R1cs<CS>,
+        a: &Self,
+        b: &Self,
+    ) {
+        FieldAssignment::enforce_eq(cs, &a.x, &b.x);
+        FieldAssignment::enforce_eq(cs, &a.y, &b.y);
+    }
+
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x.clone(),
+            y: -&self.y,
+        }
+    }
+
+    /// Convert a projective point, unsatisfiable for the identity
+    /// constraints: 3
+    pub fn from_projective<CS: CircuitDriver<Scalar = C::Base>>(
+        cs: &mut R1cs<CS>,
+        point: &PointAssignment<C::Base>,
+    ) -> Self {
+        let z_inv = FieldAssignment::inverse(cs, &point.get_z());
+        let x = FieldAssignment::mul(cs, &point.get_x(), &z_inv);
+        let y = FieldAssignment::mul(cs, &point.get_y(), &z_inv);
+
+        Self { x, y }
+    }
+
+    /// Convert a projective point, the identity is replaced by `fallback` and flagged
+    /// by the returned bit so that the caller can restore it after the affine arithmetic
+    /// constraints: 8
+    pub fn from_projective_or<CS: CircuitDriver<Scalar = C::Base>>(
+        cs: &mut R1cs<CS>,
+        point: &PointAssignment<C::Base>,
+        fallback: C,
+    ) -> (Self, BinaryAssignment) {
+        let zero = FieldAssignment::constant(&C::Base::zero());
+        let is_identity = FieldAssignment::is_eq(cs, &point.get_z(), &zero);
+        // z + 1 is invertible for the identity
+        let z = &point.get_z() + &FieldAssignment::from(&is_identity);
+        let z_inv = FieldAssignment::inverse(cs, &z);
+        let point = Self {
+            x: FieldAssignment::mul(cs, &point.get_x(), &z_inv),
+            y: FieldAssignment::mul(cs, &point.get_y(), &z_inv),
+        };
+
+        (
+            Self::conditional_select(cs, &Self::constant(fallback), &point, &is_identity),
+            is_identity,
+        )
+    }
+
+    pub fn to_projective(&self) -> PointAssignment<C::Base> {
+        PointAssignment::new(
+            self.x.clone(),
+            self.y.clone(),
+            FieldAssignment::constant(&C::Base::one()),
+        )
+    }
+
+    /// Chord addition, unsatisfiable when the x coordinates are equal
+    /// constraints: 4
+    pub fn add_incomplete<CS: CircuitDriver<Scalar = C::Base>>(
+        &self,
+        rhs: &Self,
+        cs: &mut R1cs<CS>,
+    ) -> Self {
+        let dx = &rhs.x - &self.x;
+        let dy = &rhs.y - &self.y;
+        // the slope is unconstrained for equal points
+        FieldAssignment::inverse(cs, &dx);
+        let lambda = quotient(cs, &dy, &dx);
+
+        self.chord(cs, &lambda, &rhs.x)
+    }
+
+    /// Tangent doubling, the curve has no point of order two
+    /// constraints: 4
+    pub fn double<CS: CircuitDriver<Scalar = C::Base>>(&self, cs: &mut R1cs<CS>) -> Self {
+        let xx = FieldAssignment::mul(cs, &self.x, &self.x);
+        let three_xx = &(&xx + &xx) + &xx;
+        let two_y = &self.y + &self.y;
+        let lambda = quotient(cs, &three_xx, &two_y);
+
+        self.chord(cs, &lambda, &self.x)
+    }
+
+    /// Return `a` if the condition is true and `b` otherwise
+    /// constraints: 2
+    pub fn conditional_select<CS: CircuitDriver<Scalar = C::Base>>(
+        cs: &mut R1cs<CS>,
+        a: &Self,
+        b: &Self,
+        condition: &BinaryAssignment,
+    ) -> Self {
+        let condition = FieldAssignment::from(condition);
+        let x = &b.x + &FieldAssignment::mul(cs, &(&a.x - &b.x), &condition);
+        let y = &b.y + &FieldAssignment::mul(cs, &(&a.y - &b.y), &condition);
+
+        Self { x, y }
+    }
+
+    /// Multiply by a scalar given in big-endian bits with the incomplete formulas.
+    /// The accumulator is offset by a point of unknown discrete logarithm so that
+    /// an honest prover reaches no exceptional case, except for a result at the identity.
+    /// constraints: 10 per bit
+    pub fn scalar_point_bits<CS: CircuitDriver<Scalar = C::Base>>(
+        &self,
+        cs: &mut R1cs<CS>,
+        bits: &[BinaryAssignment],
+    ) -> Self {
+        assert!(!bits.is_empty());
+        let offset = offset_point::<C>();
+        let h = Self::constant(offset);
+        let hp = h.add_incomplete(self, cs);
+
+        let mut acc = Self::conditional_select(cs, &hp, &h, &bits[0]);
+        for bit in bits.iter().skip(1) {
+            let addend = Self::conditional_select(cs, &hp, &h, bit);
+            acc = acc.double(cs).add_incomplete(&addend, cs);
+        }
+
+        // remove the (2^n - 1) multiple of the offset
+        let scalar = C::Scalar::pow_of_2(bits.len() as u64) - C::Scalar::one();
+        let correction = Self::constant(-C::from(offset * scalar));
+        acc.add_incomplete(&correction, cs)
+    }
+
+    /// The third intersection of the line through self with `slope`, negated,
+    /// given the x coordinate of the other point
+    fn chord<CS: CircuitDriver<Scalar = C::Base>>(
+        &self,
+        cs: &mut R1cs<CS>,
+        slope: &FieldAssignment<C::Base>,
+        x2: &FieldAssignment<C::Base>,
+    ) -> Self {
+        // x3 = slope^2 - x1 - x2
+        let x3 = &(&FieldAssignment::square(cs, slope) - &self.x) - x2;
+        // y3 = slope (x1 - x3) - y1
+        let y3 = &FieldAssignment::mul(cs, slope, &(&self.x - &x3)) - &self.y;
+
+        Self { x: x3, y: y3 }
+    }
+}
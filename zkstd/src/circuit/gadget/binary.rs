@@ -36,6 +36,7 @@ impl BinaryAssignment {
     }
 
     // TODO: Think about the way to do it without new allocation
+    /// constraints: 1
     pub fn not<C: CircuitDriver>(cs: &mut R1cs<C>, b: &Self) -> Self {
         let wire = cs.private_wire();
         let new_val = if cs[b.0] == C::Scalar::one() {
@@ -46,10 +47,18 @@ impl BinaryAssignment {
 
         cs.w.push(new_val);
 
-        Self(wire)
+        let not = Self(wire);
+        FieldAssignment::enforce_eq_constant(
+            cs,
+            &(&FieldAssignment::from(b) + &FieldAssignment::from(&not)),
+            &C::Scalar::one(),
+        );
+
+        not
     }
 
     // TODO: Do without allocations
+    /// constraints: 1
     pub fn and<C: CircuitDriver>(cs: &mut R1cs<C>, a: &Self, b: &Self) -> Self {
         let wire = cs.private_wire();
 
@@ -61,7 +70,14 @@ impl BinaryAssignment {
 
         cs.w.push(a_and_b);
 
-        Self(wire)
+        let and = Self(wire);
+        cs.mul_gate(
+            FieldAssignment::from(a).inner(),
+            FieldAssignment::from(b).inner(),
+            FieldAssignment::from(&and).inner(),
+        );
+
+        and
     }
 
     pub fn inner(&self) -> &Wire {
@@ -0,0 +1,202 @@
+use super::uint::{UInt32, UInt64, UInt8, UIntAssignment};
+
+use crate::circuit::CircuitDriver;
+use crate::common::{PrimeField, Vec};
+use crate::r1cs::R1cs;
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+const BLAKE2S_IV: [u64; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const BLAKE2B_IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// Word size dependent parameters of BLAKE2
+struct Variant {
+    iv: [u64; 8],
+    rounds: usize,
+    rotations: [usize; 4],
+    max_output: usize,
+}
+
+const BLAKE2S: Variant = Variant {
+    iv: BLAKE2S_IV,
+    rounds: 10,
+    rotations: [16, 12, 8, 7],
+    max_output: 32,
+};
+
+const BLAKE2B: Variant = Variant {
+    iv: BLAKE2B_IV,
+    rounds: 12,
+    rotations: [32, 24, 16, 63],
+    max_output: 64,
+};
+
+/// Unkeyed BLAKE2s digest of `output_len <= 32` bytes with up to 8 bytes of personalization.
+/// The message length is fixed when the circuit is synthesized.
+/// constraints: 21280 for a message of one block, 21664 per further 64 byte block
+pub fn blake2s<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    input: &[UInt8<F>],
+    output_len: usize,
+    personal: &[u8],
+) -> Vec<UInt8<F>> {
+    blake2::<F, C, 32>(cs, &BLAKE2S, input, output_len, personal)
+        .iter()
+        .flat_map(UInt32::to_bytes_le)
+        .take(output_len)
+        .collect()
+}
+
+/// Unkeyed BLAKE2b digest of `output_len <= 64` bytes with up to 16 bytes of personalization,
+/// e.g. BLAKE2b-256 used by Substrate with `output_len = 32` and no personalization.
+/// The message length is fixed when the circuit is synthesized.
+/// constraints: 50112 for a message of one block, 50880 per further 128 byte block
+pub fn blake2b<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    input: &[UInt8<F>],
+    output_len: usize,
+    personal: &[u8],
+) -> Vec<UInt8<F>> {
+    blake2::<F, C, 64>(cs, &BLAKE2B, input, output_len, personal)
+        .iter()
+        .flat_map(UInt64::to_bytes_le)
+        .take(output_len)
+        .collect()
+}
+
+fn blake2<F: PrimeField, C: CircuitDriver<Scalar = F>, const N: usize>(
+    cs: &mut R1cs<C>,
+    variant: &Variant,
+    input: &[UInt8<F>],
+    output_len: usize,
+    personal: &[u8],
+) -> Vec<UIntAssignment<F, N>> {
+    let word_bytes = N / 8;
+    let block_bytes = 16 * word_bytes;
+    assert!(output_len > 0 && output_len <= variant.max_output);
+    assert!(personal.len() <= 2 * word_bytes);
+
+    // parameter block with digest length, fanout and depth of one and the personalization
+    let mut params = [0u64; 8];
+    params[0] = 0x01010000 ^ output_len as u64;
+    let mut personal = personal.to_vec();
+    personal.resize(2 * word_bytes, 0);
+    for (param, word) in params[6..].iter_mut().zip(personal.chunks(word_bytes)) {
+        *param = word
+            .iter()
+            .rev()
+            .fold(0, |acc, byte| (acc << 8) | *byte as u64);
+    }
+    let mut h: Vec<UIntAssignment<F, N>> = variant
+        .iv
+        .iter()
+        .zip(params.iter())
+        .map(|(iv, param)| UIntAssignment::constant(iv ^ param))
+        .collect();
+
+    let mut blocks: Vec<Vec<UInt8<F>>> = input.chunks(block_bytes).map(|b| b.to_vec()).collect();
+    if blocks.is_empty() {
+        blocks.push(Vec::new());
+    }
+    let last = blocks.len() - 1;
+    let mut counter = 0u128;
+    for (i, mut block) in blocks.into_iter().enumerate() {
+        counter += block.len() as u128;
+        block.resize(block_bytes, UInt8::constant(0));
+        let m: Vec<_> = block
+            .chunks(word_bytes)
+            .map(UIntAssignment::from_bytes_le)
+            .collect();
+        h = compress(cs, variant, &h, &m, counter, i == last);
+    }
+    h
+}
+
+fn compress<F: PrimeField, C: CircuitDriver<Scalar = F>, const N: usize>(
+    cs: &mut R1cs<C>,
+    variant: &Variant,
+    h: &[UIntAssignment<F, N>],
+    m: &[UIntAssignment<F, N>],
+    counter: u128,
+    last: bool,
+) -> Vec<UIntAssignment<F, N>> {
+    let mask = u64::MAX >> (64 - N);
+    let mut v = h.to_vec();
+    let mut iv = variant.iv;
+    iv[4] ^= counter as u64 & mask;
+    iv[5] ^= (counter >> N) as u64 & mask;
+    if last {
+        iv[6] ^= mask;
+    }
+    v.extend(iv.iter().map(|word| UIntAssignment::constant(*word)));
+
+    for round in 0..variant.rounds {
+        let s = &SIGMA[round % 10];
+        for (i, [a, b, c, d]) in [
+            [0, 4, 8, 12],
+            [1, 5, 9, 13],
+            [2, 6, 10, 14],
+            [3, 7, 11, 15],
+            [0, 5, 10, 15],
+            [1, 6, 11, 12],
+            [2, 7, 8, 13],
+            [3, 4, 9, 14],
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let (x, y) = (&m[s[2 * i]], &m[s[2 * i + 1]]);
+            mix(cs, &mut v, [a, b, c, d], x, y, variant.rotations);
+        }
+    }
+
+    h.iter()
+        .zip(v[..8].iter().zip(v[8..].iter()))
+        .map(|(h, (low, high))| {
+            let t = UIntAssignment::xor(cs, h, low);
+            UIntAssignment::xor(cs, &t, high)
+        })
+        .collect()
+}
+
+/// The G function
+fn mix<F: PrimeField, C: CircuitDriver<Scalar = F>, const N: usize>(
+    cs: &mut R1cs<C>,
+    v: &mut [UIntAssignment<F, N>],
+    [a, b, c, d]: [usize; 4],
+    x: &UIntAssignment<F, N>,
+    y: &UIntAssignment<F, N>,
+    [r1, r2, r3, r4]: [usize; 4],
+) {
+    v[a] = UIntAssignment::add_many(cs, &[v[a].clone(), v[b].clone(), x.clone()]);
+    v[d] = UIntAssignment::xor(cs, &v[d], &v[a]).rotate_right(r1);
+    v[c] = UIntAssignment::add(cs, &v[c], &v[d]);
+    v[b] = UIntAssignment::xor(cs, &v[b], &v[c]).rotate_right(r2);
+    v[a] = UIntAssignment::add_many(cs, &[v[a].clone(), v[b].clone(), y.clone()]);
+    v[d] = UIntAssignment::xor(cs, &v[d], &v[a]).rotate_right(r3);
+    v[c] = UIntAssignment::add(cs, &v[c], &v[d]);
+    v[b] = UIntAssignment::xor(cs, &v[b], &v[c]).rotate_right(r4);
+}
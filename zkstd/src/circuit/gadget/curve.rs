@@ -2,9 +2,14 @@ use super::binary::BinaryAssignment;
 use super::field::FieldAssignment;
 
 use crate::circuit::CircuitDriver;
-use crate::common::{BNAffine, BNProjective, PrimeField};
+use crate::common::{vec, BNAffine, BNProjective, Group, PrimeField, Vec};
 use crate::r1cs::R1cs;
 
+/// Bits of the scalar consumed per window by the fixed-base multiplication
+const FIXED_BASE_WINDOW: usize = 3;
+/// Bits of the scalar consumed per window by the variable-base multiplication
+const VARIABLE_BASE_WINDOW: usize = 2;
+
 #[derive(Clone)]
 pub struct PointAssignment<F: PrimeField> {
     x: FieldAssignment<F>,
@@ -31,6 +36,10 @@ impl<F: PrimeField> PointAssignment<F> {
         Self { x, y, z }
     }
 
+    pub fn new(x: FieldAssignment<F>, y: FieldAssignment<F>, z: FieldAssignment<F>) -> Self {
+        Self { x, y, z }
+    }
+
     pub fn descale<C: CircuitDriver<Scalar = F>>(&self, cs: &mut R1cs<C>) -> Self {
         let take_value =
             FieldAssignment::is_neq(cs, &self.z, &FieldAssignment::constant(&F::zero()));
@@ -53,6 +62,17 @@ impl<F: PrimeField> PointAssignment<F> {
         Self { x, y, z }
     }
 
+    pub fn constant(point: impl BNAffine<Base = F>) -> Self {
+        if point.is_identity() {
+            return Self::identity();
+        }
+        let x = FieldAssignment::constant(&point.get_x());
+        let y = FieldAssignment::constant(&point.get_y());
+        let z = FieldAssignment::constant(&F::one());
+
+        Self { x, y, z }
+    }
+
     pub fn witness<C: CircuitDriver<Scalar = F>>(
         cs: &mut R1cs<C>,
         x: F,
@@ -157,22 +177,218 @@ impl<F: PrimeField> PointAssignment<F> {
         }
     }
 
-    /// coordinate scalar
+    /// Multiply by a scalar whose bits are constrained to its canonical representation
     pub fn scalar_point<C: CircuitDriver<Scalar = F>>(
         &self,
         cs: &mut R1cs<C>,
         scalar: &FieldAssignment<F>,
     ) -> Self {
-        let mut res = PointAssignment::identity();
-        for bit in FieldAssignment::to_bits(cs, scalar, 256).iter() {
-            res = res.double(cs);
-            let point_to_add = self.select_identity(cs, bit);
-            res = res.add(&point_to_add, cs);
+        let bits = Self::scalar_bits(cs, scalar);
+        self.scalar_point_bits(cs, &bits)
+    }
+
+    /// Multiply by a scalar given in big-endian bits, two bits at a time
+    /// from a table of the first multiples of the point
+    /// constraints: 19 per bit and 20 for the table
+    pub fn scalar_point_bits<C: CircuitDriver<Scalar = F>>(
+        &self,
+        cs: &mut R1cs<C>,
+        bits: &[BinaryAssignment],
+    ) -> Self {
+        let table = self.window_table(cs);
+
+        let mut res: Option<Self> = None;
+        for window in bits.rchunks(VARIABLE_BASE_WINDOW).rev() {
+            let term = Self::lookup(cs, &table, window);
+            res = Some(match res {
+                Some(mut acc) => {
+                    for _ in 0..window.len() {
+                        acc = acc.double(cs);
+                    }
+                    acc.add(&term, cs)
+                }
+                None => term,
+            });
+        }
+
+        res.unwrap_or_else(Self::identity)
+    }
+
+    /// Multiply a constant base by a scalar given in big-endian bits, three bits at a time.
+    /// The multiples of the base for every window are computed outside of the circuit,
+    /// so the windows are looked up and summed without any doubling.
+    /// constraints: 16 per window of three bits
+    pub fn fixed_base_scalar_point<C: CircuitDriver<Scalar = F>, A: BNAffine<Base = F>>(
+        cs: &mut R1cs<C>,
+        base: A,
+        bits: &[BinaryAssignment],
+    ) -> Self {
+        Self::fixed_base_windows(cs, base, bits, None).unwrap_or_else(Self::identity)
+    }
+
+    /// Multi-scalar multiplication `sum s_i * P_i` over constant and variable bases,
+    /// the bits of every scalar are constrained to its canonical representation
+    pub fn multi_scalar_point<C: CircuitDriver<Scalar = F>, A: BNAffine<Base = F>>(
+        cs: &mut R1cs<C>,
+        fixed: &[(A, FieldAssignment<F>)],
+        variable: &[(Self, FieldAssignment<F>)],
+    ) -> Self {
+        let fixed = fixed
+            .iter()
+            .map(|(base, scalar)| (*base, Self::scalar_bits(cs, scalar)))
+            .collect::<Vec<_>>();
+        let variable = variable
+            .iter()
+            .map(|(base, scalar)| (base.clone(), Self::scalar_bits(cs, scalar)))
+            .collect::<Vec<_>>();
+
+        Self::multi_scalar_point_bits(cs, &fixed, &variable)
+    }
+
+    /// Multi-scalar multiplication with scalars given in big-endian bits.
+    /// The variable bases are interleaved by Straus' method, two bits at a time,
+    /// so that all terms share the doublings of a single accumulator.
+    /// The constant bases are added window by window as in `fixed_base_scalar_point`.
+    /// constraints: 8 per bit, 11 per bit and variable base, 20 per variable base for the table
+    /// and 16 per window of three bits and constant base
+    pub fn multi_scalar_point_bits<C: CircuitDriver<Scalar = F>, A: BNAffine<Base = F>>(
+        cs: &mut R1cs<C>,
+        fixed: &[(A, Vec<BinaryAssignment>)],
+        variable: &[(Self, Vec<BinaryAssignment>)],
+    ) -> Self {
+        let tables = variable
+            .iter()
+            .map(|(base, _)| base.window_table(cs))
+            .collect::<Vec<_>>();
+        let len = variable
+            .iter()
+            .map(|(_, bits)| bits.len())
+            .max()
+            .unwrap_or(0);
+
+        // windows are aligned at the least significant bit of every scalar
+        let mut res: Option<Self> = None;
+        for start in (0..len).step_by(VARIABLE_BASE_WINDOW).rev() {
+            let end = len.min(start + VARIABLE_BASE_WINDOW);
+            if let Some(mut acc) = res.take() {
+                for _ in start..end {
+                    acc = acc.double(cs);
+                }
+                res = Some(acc);
+            }
+            for (table, (_, bits)) in tables.iter().zip(variable) {
+                if start >= bits.len() {
+                    continue;
+                }
+                let window = &bits[bits.len() - end.min(bits.len())..bits.len() - start];
+                let term = Self::lookup(cs, table, window);
+                res = Some(match res {
+                    Some(acc) => acc.add(&term, cs),
+                    None => term,
+                });
+            }
+        }
+
+        for (base, bits) in fixed {
+            res = Self::fixed_base_windows(cs, *base, bits, res);
+        }
+
+        res.unwrap_or_else(Self::identity)
+    }
+
+    /// Add the windows of a constant base multiplication to the accumulator
+    fn fixed_base_windows<C: CircuitDriver<Scalar = F>, A: BNAffine<Base = F>>(
+        cs: &mut R1cs<C>,
+        base: A,
+        bits: &[BinaryAssignment],
+        mut res: Option<Self>,
+    ) -> Option<Self> {
+        let mut window_base = base.to_extended();
+        for window in bits.rchunks(FIXED_BASE_WINDOW) {
+            let mut multiple = A::Extended::ADDITIVE_IDENTITY;
+            let mut table = Vec::with_capacity(1 << window.len());
+            for _ in 0..1 << window.len() {
+                table.push(Self::constant(A::from(multiple)));
+                multiple += window_base;
+            }
+            for _ in 0..window.len() {
+                window_base = window_base.double();
+            }
+
+            let term = Self::lookup(cs, &table, window);
+            res = Some(match res {
+                Some(acc) => acc.add(&term, cs),
+                None => term,
+            });
         }
 
         res
     }
 
+    /// Canonical big-endian bits of a scalar, bound to the scalar
+    fn scalar_bits<C: CircuitDriver<Scalar = F>>(
+        cs: &mut R1cs<C>,
+        scalar: &FieldAssignment<F>,
+    ) -> Vec<BinaryAssignment> {
+        let bits = FieldAssignment::to_bits(cs, scalar, C::NUM_BITS as usize);
+        FieldAssignment::enforce_eq_bits(cs, scalar, &bits);
+
+        bits
+    }
+
+    /// Multiples of the point indexed by a window of the variable-base multiplication
+    fn window_table<C: CircuitDriver<Scalar = F>>(&self, cs: &mut R1cs<C>) -> [Self; 4] {
+        let double = self.double(cs);
+        let triple = double.add(self, cs);
+
+        [Self::identity(), self.clone(), double, triple]
+    }
+
+    /// Select `table[i]` for the index `i` given in big-endian bits by evaluating
+    /// the multilinear extension of the table, which is linear in constant entries
+    fn lookup<C: CircuitDriver<Scalar = F>>(
+        cs: &mut R1cs<C>,
+        table: &[Self],
+        bits: &[BinaryAssignment],
+    ) -> Self {
+        // products of the bits for every subset of the little-endian index bits
+        let mut products = vec![FieldAssignment::constant(&F::one())];
+        for bit in bits.iter().rev() {
+            let bit = FieldAssignment::from(bit);
+            for i in 0..products.len() {
+                let product = FieldAssignment::mul(cs, &products[i], &bit);
+                products.push(product);
+            }
+        }
+
+        // coefficients of the subset products by the inclusion-exclusion over the entries
+        let mut coeffs: Vec<Self> = table[..products.len()].to_vec();
+        for i in 0..bits.len() {
+            for subset in 0..coeffs.len() {
+                if subset & (1 << i) != 0 {
+                    let low = coeffs[subset ^ (1 << i)].clone();
+                    let coeff = &mut coeffs[subset];
+                    coeff.x = &coeff.x - &low.x;
+                    coeff.y = &coeff.y - &low.y;
+                    coeff.z = &coeff.z - &low.z;
+                }
+            }
+        }
+
+        coeffs.iter().zip(products.iter()).fold(
+            Self {
+                x: FieldAssignment::constant(&F::zero()),
+                y: FieldAssignment::constant(&F::zero()),
+                z: FieldAssignment::constant(&F::zero()),
+            },
+            |acc, (coeff, product)| Self {
+                x: &acc.x + &FieldAssignment::mul(cs, &coeff.x, product),
+                y: &acc.y + &FieldAssignment::mul(cs, &coeff.y, product),
+                z: &acc.z + &FieldAssignment::mul(cs, &coeff.z, product),
+            },
+        )
+    }
+
     pub fn conditional_select<C: CircuitDriver<Scalar = F>>(
         cs: &mut R1cs<C>,
         a: &Self,
@@ -0,0 +1,114 @@
+use super::binary::BinaryAssignment;
+use super::field::{sqrt, FieldAssignment};
+use super::nonnative::NonNativeFieldAssignment;
+use super::nonnative_curve::NonNativePointAssignment;
+
+use crate::circuit::CircuitDriver;
+use crate::common::{BNAffine, IntGroup, PrimeField};
+use crate::r1cs::R1cs;
+
+/// ECDSA signature `(r, s)` over a curve emulated in the native field `N`,
+/// with the parity of the nonce point y coordinate for public key recovery
+#[derive(Clone)]
+pub struct EcdsaSignatureAssignment<N: PrimeField, C: BNAffine> {
+    r: NonNativeFieldAssignment<N, C::Scalar>,
+    s: NonNativeFieldAssignment<N, C::Scalar>,
+    v: BinaryAssignment,
+}
+
+impl<N: PrimeField, C: BNAffine> EcdsaSignatureAssignment<N, C> {
+    pub fn witness<CS: CircuitDriver<Scalar = N>>(
+        cs: &mut R1cs<CS>,
+        r: C::Scalar,
+        s: C::Scalar,
+        v: bool,
+    ) -> Self {
+        let r = NonNativeFieldAssignment::witness(cs, r);
+        let s = NonNativeFieldAssignment::witness(cs, s);
+        let v = BinaryAssignment::witness(cs, u8::from(v));
+
+        Self { r, s, v }
+    }
+
+    pub fn new(
+        r: NonNativeFieldAssignment<N, C::Scalar>,
+        s: NonNativeFieldAssignment<N, C::Scalar>,
+        v: BinaryAssignment,
+    ) -> Self {
+        Self { r, s, v }
+    }
+
+    /// Enforce `x(z/s G + r/s Q) = r mod n` for the message hash `z`,
+    /// unsatisfiable for `r = 0`, `s = 0` or a public key off the curve
+    pub fn enforce_valid<CS: CircuitDriver<Scalar = N>>(
+        &self,
+        cs: &mut R1cs<CS>,
+        public_key: &NonNativePointAssignment<N, C>,
+        message_hash: &NonNativeFieldAssignment<N, C::Scalar>,
+    ) {
+        public_key.enforce_on_curve(cs);
+        // r has an inverse only if it is not zero
+        NonNativeFieldAssignment::inverse(cs, &self.r);
+        let w = NonNativeFieldAssignment::inverse(cs, &self.s);
+        let u1 = NonNativeFieldAssignment::mul(cs, message_hash, &w);
+        let u2 = NonNativeFieldAssignment::mul(cs, &self.r, &w);
+        let point = linear_combination(cs, &u1, public_key, &u2);
+
+        // x is less than p, so comparing modulo n is the reduction of the x coordinate
+        let x = NonNativeFieldAssignment::canonical(cs, point.get_x());
+        NonNativeFieldAssignment::enforce_eq(cs, &x.cast(), &self.r);
+    }
+
+    /// Recover the public key `r^-1 (sR - zG)` which this signature is valid for,
+    /// the nonce point `R` has the x coordinate `r` and y coordinate of parity `v`
+    pub fn recover<CS: CircuitDriver<Scalar = N>>(
+        &self,
+        cs: &mut R1cs<CS>,
+        message_hash: &NonNativeFieldAssignment<N, C::Scalar>,
+    ) -> NonNativePointAssignment<N, C> {
+        // r < n < p is the x coordinate itself
+        let r = NonNativeFieldAssignment::canonical(cs, &self.r);
+        let x = r.cast::<C::Base>();
+        let x_value = x.value(cs);
+        let y_value = sqrt(x_value.square() * x_value + C::PARAM_B).unwrap_or_else(C::Base::zero);
+        let y_value = match u8::from(y_value.to_raw_bytes()[0] & 1 == 1) == self.v_value(cs) {
+            true => y_value,
+            false => -y_value,
+        };
+        let y = NonNativeFieldAssignment::witness(cs, y_value);
+        let y = NonNativeFieldAssignment::canonical(cs, &y);
+        let parity = FieldAssignment::to_bits(cs, &y.limbs()[0], 64);
+        FieldAssignment::enforce_eq_bits(cs, &y.limbs()[0], &parity);
+        FieldAssignment::enforce_eq(
+            cs,
+            &FieldAssignment::from(&parity[63]),
+            &FieldAssignment::from(&self.v),
+        );
+        let nonce = NonNativePointAssignment::new(x, y);
+        nonce.enforce_on_curve(cs);
+
+        let r_inv = NonNativeFieldAssignment::inverse(cs, &r);
+        let u1 = -&NonNativeFieldAssignment::mul(cs, message_hash, &r_inv);
+        let u2 = NonNativeFieldAssignment::mul(cs, &self.s, &r_inv);
+
+        linear_combination(cs, &u1, &nonce, &u2)
+    }
+
+    fn v_value<CS: CircuitDriver<Scalar = N>>(&self, cs: &R1cs<CS>) -> u8 {
+        u8::from(FieldAssignment::from(&self.v).value(cs) == N::one())
+    }
+}
+
+/// `[u1]G + [u2]Q`
+fn linear_combination<N: PrimeField, C: BNAffine, CS: CircuitDriver<Scalar = N>>(
+    cs: &mut R1cs<CS>,
+    u1: &NonNativeFieldAssignment<N, C::Scalar>,
+    q: &NonNativePointAssignment<N, C>,
+    u2: &NonNativeFieldAssignment<N, C::Scalar>,
+) -> NonNativePointAssignment<N, C> {
+    let generator = NonNativePointAssignment::constant(C::ADDITIVE_GENERATOR);
+    let u1 = NonNativeFieldAssignment::to_bits(cs, u1);
+    let u2 = NonNativeFieldAssignment::to_bits(cs, u2);
+
+    NonNativePointAssignment::double_scalar_mul(cs, &generator, &u1, q, &u2)
+}
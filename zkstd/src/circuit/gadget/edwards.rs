@@ -0,0 +1,237 @@
+use super::binary::BinaryAssignment;
+use super::field::FieldAssignment;
+
+use crate::circuit::CircuitDriver;
+use crate::common::{PrimeField, Ring, TwistedEdwardsAffine, TwistedEdwardsExtended, Vec};
+use crate::r1cs::R1cs;
+use core::marker::PhantomData;
+
+/// Affine point of a twisted Edwards curve `-x^2 + y^2 = 1 + dx^2y^2` defined over the circuit field.
+/// The formulas are complete for a non square `d`, so the identity and doubling need no special case
+/// as long as the points are on the curve.
+#[derive(Clone)]
+pub struct EdwardsPointAssignment<P: TwistedEdwardsAffine> {
+    x: FieldAssignment<P::Base>,
+    y: FieldAssignment<P::Base>,
+    marker: PhantomData<P>,
+}
+
+impl<P: TwistedEdwardsAffine> EdwardsPointAssignment<P> {
+    pub fn instance<C: CircuitDriver<Scalar = P::Base>>(cs: &mut R1cs<C>, point: P) -> Self {
+        let x = FieldAssignment::instance(cs, point.get_x());
+        let y = FieldAssignment::instance(cs, point.get_y());
+
+        Self::new(x, y)
+    }
+
+    /// The point is not checked to be on the curve, see `enforce_on_curve`
+    pub fn witness<C: CircuitDriver<Scalar = P::Base>>(cs: &mut R1cs<C>, point: P) -> Self {
+        let x = FieldAssignment::witness(cs, point.get_x());
+        let y = FieldAssignment::witness(cs, point.get_y());
+
+        Self::new(x, y)
+    }
+
+    pub fn constant(point: P) -> Self {
+        let x = FieldAssignment::constant(&point.get_x());
+        let y = FieldAssignment::constant(&point.get_y());
+
+        Self::new(x, y)
+    }
+
+    pub fn identity() -> Self {
+        Self::constant(P::ADDITIVE_IDENTITY)
+    }
+
+    pub fn value<C: CircuitDriver<Scalar = P::Base>>(&self, cs: &R1cs<C>) -> P {
+        P::from_raw_unchecked(self.x.value(cs), self.y.value(cs))
+    }
+
+    /// constraints: 3
+    pub fn enforce_on_curve<C: CircuitDriver<Scalar = P::Base>>(&self, cs: &mut R1cs<C>) {
+        let xx = FieldAssignment::square(cs, &self.x);
+        let yy = FieldAssignment::square(cs, &self.y);
+        let dxx = FieldAssignment::mul(cs, &xx, &FieldAssignment::constant(&P::PARAM_D));
+        // dx^2 * y^2 = -x^2 + y^2 - 1
+        let rhs = &(&yy - &xx) - &FieldAssignment::constant(&P::Base::one());
+        cs.mul_gate(dxx.inner(), yy.inner(), rhs.inner());
+    }
+
+    /// constraints: 2
+    pub fn enforce_equal<C: CircuitDriver<Scalar = P::Base>>(cs: &mut R1cs<C>, a: &Self, b: &Self) {
+        FieldAssignment::enforce_eq(cs, &a.x, &b.x);
+        FieldAssignment::enforce_eq(cs, &a.y, &b.y);
+    }
+
+    pub fn neg(&self) -> Self {
+        Self::new(-&self.x, self.y.clone())
+    }
+
+    /// constraints: 6, 3 if `rhs` is constant
+    pub fn add<C: CircuitDriver<Scalar = P::Base>>(&self, rhs: &Self, cs: &mut R1cs<C>) -> Self {
+        let d = FieldAssignment::constant(&P::PARAM_D);
+        let one = FieldAssignment::constant(&P::Base::one());
+        let a = FieldAssignment::mul(cs, &self.x, &rhs.y);
+        let b = FieldAssignment::mul(cs, &self.y, &rhs.x);
+        let u = FieldAssignment::mul(cs, &(&self.x + &self.y), &(&rhs.x + &rhs.y));
+        let ab = FieldAssignment::mul(cs, &a, &b);
+        let c = FieldAssignment::mul(cs, &ab, &d);
+
+        // x3 = (x1y2 + y1x2) / (1 + dx1x2y1y2)
+        let x = quotient(cs, &(&a + &b), &(&one + &c));
+        // y3 = (y1y2 + x1x2) / (1 - dx1x2y1y2)
+        let y = quotient(cs, &(&(&u - &a) - &b), &(&one - &c));
+
+        Self::new(x, y)
+    }
+
+    /// The point must be on the curve
+    /// constraints: 5
+    pub fn double<C: CircuitDriver<Scalar = P::Base>>(&self, cs: &mut R1cs<C>) -> Self {
+        let two = FieldAssignment::constant(&P::Base::from(2));
+        let xy = FieldAssignment::mul(cs, &self.x, &self.y);
+        let xx = FieldAssignment::square(cs, &self.x);
+        let yy = FieldAssignment::square(cs, &self.y);
+
+        // 1 + dx^2y^2 = -x^2 + y^2 on the curve
+        // x3 = 2xy / (y^2 - x^2)
+        let x = quotient(cs, &(&xy + &xy), &(&yy - &xx));
+        // y3 = (y^2 + x^2) / (2 + x^2 - y^2)
+        let y = quotient(cs, &(&yy + &xx), &(&(&two + &xx) - &yy));
+
+        Self::new(x, y)
+    }
+
+    /// Multiply by a scalar given in big-endian bits
+    /// constraints: 13 per bit except the first
+    pub fn scalar_point_bits<C: CircuitDriver<Scalar = P::Base>>(
+        &self,
+        cs: &mut R1cs<C>,
+        bits: &[BinaryAssignment],
+    ) -> Self {
+        let (first, rest) = match bits.split_first() {
+            Some(split) => split,
+            None => return Self::identity(),
+        };
+        let mut res = self.select_identity(cs, first);
+        for bit in rest.iter() {
+            res = res.double(cs);
+            let point_to_add = self.select_identity(cs, bit);
+            res = res.add(&point_to_add, cs);
+        }
+
+        res
+    }
+
+    /// Multiply by a scalar whose bits are constrained to its canonical representation
+    pub fn scalar_point<C: CircuitDriver<Scalar = P::Base>>(
+        &self,
+        cs: &mut R1cs<C>,
+        scalar: &FieldAssignment<P::Base>,
+    ) -> Self {
+        let bits = FieldAssignment::to_bits(cs, scalar, C::NUM_BITS as usize);
+        FieldAssignment::enforce_eq_bits(cs, scalar, &bits);
+
+        self.scalar_point_bits(cs, &bits)
+    }
+
+    /// Multiply a constant base by a scalar given in big-endian bits,
+    /// the multiples of the base are computed outside of the circuit
+    /// constraints: 6 per bit except the first
+    pub fn fixed_base_scalar_point<C: CircuitDriver<Scalar = P::Base>>(
+        cs: &mut R1cs<C>,
+        base: P,
+        bits: &[BinaryAssignment],
+    ) -> Self {
+        let mut multiple = base.to_extended();
+        let mut multiples = Vec::with_capacity(bits.len());
+        for _ in 0..bits.len() {
+            multiples.push(P::from(multiple));
+            multiple = multiple.double();
+        }
+
+        // selecting between the identity and a constant is linear
+        let one = FieldAssignment::constant(&P::Base::one());
+        let terms: Vec<Self> = bits
+            .iter()
+            .rev()
+            .zip(multiples)
+            .map(|(bit, multiple)| {
+                let bit = FieldAssignment::from(bit);
+                let x =
+                    FieldAssignment::mul(cs, &bit, &FieldAssignment::constant(&multiple.get_x()));
+                let y_minus_one = FieldAssignment::constant(&(multiple.get_y() - P::Base::one()));
+                let y = &FieldAssignment::mul(cs, &bit, &y_minus_one) + &one;
+                Self::new(x, y)
+            })
+            .collect();
+
+        terms
+            .into_iter()
+            .reduce(|acc, term| acc.add(&term, cs))
+            .unwrap_or_else(Self::identity)
+    }
+
+    /// Return `a` if the condition is true and `b` otherwise
+    /// constraints: 4
+    pub fn conditional_select<C: CircuitDriver<Scalar = P::Base>>(
+        cs: &mut R1cs<C>,
+        a: &Self,
+        b: &Self,
+        condition: &BinaryAssignment,
+    ) -> Self {
+        let x = FieldAssignment::conditional_select(cs, &a.x, &b.x, condition);
+        let y = FieldAssignment::conditional_select(cs, &a.y, &b.y, condition);
+
+        Self::new(x, y)
+    }
+
+    /// Return the point if the bit is set and the identity otherwise
+    /// constraints: 2
+    pub fn select_identity<C: CircuitDriver<Scalar = P::Base>>(
+        &self,
+        cs: &mut R1cs<C>,
+        bit: &BinaryAssignment,
+    ) -> Self {
+        let bit = FieldAssignment::from(bit);
+        let one = FieldAssignment::constant(&P::Base::one());
+        let x = FieldAssignment::mul(cs, &self.x, &bit);
+        // y * bit + 1 - bit
+        let y = &FieldAssignment::mul(cs, &(&self.y - &one), &bit) + &one;
+
+        Self::new(x, y)
+    }
+
+    pub fn get_x(&self) -> FieldAssignment<P::Base> {
+        self.x.clone()
+    }
+
+    pub fn get_y(&self) -> FieldAssignment<P::Base> {
+        self.y.clone()
+    }
+
+    fn new(x: FieldAssignment<P::Base>, y: FieldAssignment<P::Base>) -> Self {
+        Self {
+            x,
+            y,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Return `num / den` with a single constraint `quotient * den = num`
+pub(crate) fn quotient<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    num: &FieldAssignment<F>,
+    den: &FieldAssignment<F>,
+) -> FieldAssignment<F> {
+    if let (Some(num), Some(den)) = (num.inner().as_constant(), den.inner().as_constant()) {
+        return FieldAssignment::constant(&(num * den.invert().unwrap_or_else(F::zero)));
+    }
+
+    let value = num.value(cs) * den.value(cs).invert().unwrap_or_else(F::zero);
+    let quotient = FieldAssignment::witness(cs, value);
+    cs.mul_gate(quotient.inner(), den.inner(), num.inner());
+
+    quotient
+}
@@ -108,13 +108,15 @@ impl<F: PrimeField> FieldAssignment<F> {
             .skip(a_bits.len() - c_bits.len())
             .collect::<Vec<_>>();
 
+        // prefix products are only read up to the last zero of C
         let mut p = vec![FieldAssignment::from(a_bits[0])];
-        let t = c_bits
-            .iter()
-            .rposition(|&b| b != 1)
-            .unwrap_or(c_bits.len() - 1);
+        let t = c_bits.iter().rposition(|&b| b != 1).unwrap_or(0);
 
-        for (&a, &c) in a_bits.iter().skip(1).zip(c_bits.iter().skip(1).take(t + 1)) {
+        for (&a, &c) in a_bits
+            .iter()
+            .skip(1)
+            .zip(c_bits.iter().skip(1).take(t.saturating_sub(1)))
+        {
             if c == 1 {
                 p.push(FieldAssignment::mul(
                     cs,
@@ -128,33 +130,21 @@ impl<F: PrimeField> FieldAssignment<F> {
 
         for (i, (&a, &c)) in a_bits.iter().zip(c_bits.iter()).enumerate() {
             let bit_field = FieldAssignment::from(a);
+            let zero = FieldAssignment::constant(&F::zero());
             if c == 1 {
-                let bool_constr = FieldAssignment::mul(
-                    cs,
-                    &(&bit_field - &FieldAssignment::constant(&F::one())),
-                    &bit_field,
-                );
-                FieldAssignment::enforce_eq(
-                    cs,
-                    &bool_constr,
-                    &FieldAssignment::constant(&F::zero()),
-                );
+                // (a - 1) * a = 0
+                let bit_minus_one = &bit_field - &FieldAssignment::constant(&F::one());
+                cs.mul_gate(&bit_minus_one.0, &bit_field.0, &zero.0);
             } else if c == 0 {
-                let bool_constr = FieldAssignment::mul(
-                    cs,
-                    &(&(&FieldAssignment::constant(&F::one()) - &bit_field) - &p[i - 1]),
-                    &bit_field,
-                );
-                FieldAssignment::enforce_eq(
-                    cs,
-                    &bool_constr,
-                    &FieldAssignment::constant(&F::zero()),
-                );
+                // (1 - a - p) * a = 0
+                let lhs = &(&FieldAssignment::constant(&F::one()) - &bit_field) - &p[i - 1];
+                cs.mul_gate(&lhs.0, &bit_field.0, &zero.0);
             }
         }
     }
 
     /// To bit representation in Big-endian
+    /// The bits are range checked but not tied to `x`, use `enforce_eq_bits` for it
     pub fn to_bits<C: CircuitDriver<Scalar = F>>(
         cs: &mut R1cs<C>,
         x: &Self,
@@ -168,8 +158,8 @@ impl<F: PrimeField> FieldAssignment<F> {
             .evaluate(&cs.x, &cs.w)
             .to_bits()
             .iter()
-            .map(|b| BinaryAssignment::witness(cs, *b))
             .skip(256 - num_bits) // TODO: Decide on how to store bits. LE or BE
+            .map(|b| BinaryAssignment::witness(cs, *b))
             .collect();
         if num_bits < C::NUM_BITS as usize {
             FieldAssignment::range_check_bits(cs, &bit_repr, num_bits as u64);
@@ -232,6 +222,7 @@ impl<F: PrimeField> FieldAssignment<F> {
         &select_a + &select_b
     }
 
+    /// constraints: 3
     pub fn is_eq<C: CircuitDriver<Scalar = F>>(
         cs: &mut R1cs<C>,
         x: &Self,
@@ -241,6 +232,7 @@ impl<F: PrimeField> FieldAssignment<F> {
         BinaryAssignment::not(cs, &is_neq)
     }
 
+    /// constraints: 2
     pub fn is_neq<C: CircuitDriver<Scalar = F>>(
         cs: &mut R1cs<C>,
         x: &Self,
@@ -255,13 +247,18 @@ impl<F: PrimeField> FieldAssignment<F> {
             FieldAssignment::witness(cs, F::one())
         };
 
+        // (x - y) * multiplier = is_not_equal
         let diff = x - y;
-        let mul = FieldAssignment::mul(cs, &diff, &multiplier);
-        FieldAssignment::enforce_eq(cs, &mul, &FieldAssignment::from(&is_not_equal));
-
-        let not_is_not_equal = BinaryAssignment::not(cs, &is_not_equal);
-        let mul = FieldAssignment::mul(cs, &diff, &FieldAssignment::from(&not_is_not_equal));
-        FieldAssignment::enforce_eq(cs, &mul, &FieldAssignment::constant(&F::zero()));
+        let is_not_equal_field = FieldAssignment::from(&is_not_equal);
+        cs.mul_gate(&diff.0, &multiplier.0, &is_not_equal_field.0);
+
+        // (x - y) * (1 - is_not_equal) = 0
+        let not_is_not_equal = &FieldAssignment::constant(&F::one()) - &is_not_equal_field;
+        cs.mul_gate(
+            &diff.0,
+            &not_is_not_equal.0,
+            &FieldAssignment::constant(&F::zero()).0,
+        );
 
         is_not_equal
     }
@@ -269,6 +266,177 @@ impl<F: PrimeField> FieldAssignment<F> {
     pub fn enforce_eq_constant<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, x: &Self, c: &F) {
         cs.mul_gate(&x.0, &SparseRow::one(), &FieldAssignment::constant(c).0)
     }
+
+    /// Return `x^-1`, unsatisfiable for `x = 0`
+    /// constraints: 1
+    pub fn inverse<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, x: &Self) -> Self {
+        // a constant zero falls through to the unsatisfiable `0 * inv = 1`
+        if let Some(inv) = x.0.as_constant().and_then(|c| c.invert()) {
+            return Self::constant(&inv);
+        }
+
+        let x_val = x.value(cs);
+        let inv = Self::witness(cs, x_val.invert().unwrap_or_else(F::zero));
+        cs.mul_gate(&x.0, &inv.0, &SparseRow::one());
+
+        inv
+    }
+
+    /// Return `x / y`, unsatisfiable for `y = 0`
+    /// constraints: 2
+    pub fn div<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, x: &Self, y: &Self) -> Self {
+        let inv = Self::inverse(cs, y);
+        Self::mul(cs, x, &inv)
+    }
+
+    /// constraints: 3
+    pub fn is_zero<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, x: &Self) -> BinaryAssignment {
+        Self::is_eq(cs, x, &Self::constant(&F::zero()))
+    }
+
+    /// Return whether `x < y`
+    /// Both `x` and `y` must already be known to be less than `2^num_bits`,
+    /// e.g. from `to_bits`, and `num_bits + 1` must be less than the field size
+    /// constraints: num_bits + 3
+    pub fn less_than<C: CircuitDriver<Scalar = F>>(
+        cs: &mut R1cs<C>,
+        x: &Self,
+        y: &Self,
+        num_bits: usize,
+    ) -> BinaryAssignment {
+        assert!(num_bits < C::NUM_BITS as usize - 1);
+
+        // x - y + 2^num_bits has its top bit set iff x >= y
+        let diff = &(x - y) + &Self::constant(&F::pow_of_2(num_bits as u64));
+        let bits = Self::to_bits(cs, &diff, num_bits + 1);
+        Self::enforce_eq_bits(cs, &diff, &bits);
+
+        BinaryAssignment::not(cs, &bits[0])
+    }
+
+    /// Return whether `x <= y` under the same bounds as `less_than`
+    /// constraints: num_bits + 4
+    pub fn less_or_equal<C: CircuitDriver<Scalar = F>>(
+        cs: &mut R1cs<C>,
+        x: &Self,
+        y: &Self,
+        num_bits: usize,
+    ) -> BinaryAssignment {
+        let greater = Self::less_than(cs, y, x, num_bits);
+        BinaryAssignment::not(cs, &greater)
+    }
+
+    /// Return `x^exp` for a constant exponent by square and multiply
+    /// constraints: bit length of `exp` - 1 + number of ones in `exp` - 1
+    pub fn pow_constant<C: CircuitDriver<Scalar = F>>(
+        cs: &mut R1cs<C>,
+        x: &Self,
+        exp: u64,
+    ) -> Self {
+        if exp == 0 {
+            return Self::constant(&F::one());
+        }
+
+        let mut acc = x.clone();
+        for i in (0..63 - exp.leading_zeros()).rev() {
+            acc = Self::square(cs, &acc);
+            if (exp >> i) & 1 == 1 {
+                acc = Self::mul(cs, &acc, x);
+            }
+        }
+        acc
+    }
+
+    /// Return `x^exp` for an exponent less than `2^num_bits`
+    /// constraints: 4 * num_bits - 1
+    pub fn pow<C: CircuitDriver<Scalar = F>>(
+        cs: &mut R1cs<C>,
+        x: &Self,
+        exp: &Self,
+        num_bits: usize,
+    ) -> Self {
+        let bits = Self::to_bits(cs, exp, num_bits);
+        Self::enforce_eq_bits(cs, exp, &bits);
+
+        let one = Self::constant(&F::one());
+        let x_minus_one = x - &one;
+        let mut acc = one.clone();
+        for bit in bits.iter() {
+            acc = Self::square(cs, &acc);
+            // bit ? x : 1
+            let factor = &one + &Self::mul(cs, &Self::from(bit), &x_minus_one);
+            acc = Self::mul(cs, &acc, &factor);
+        }
+        acc
+    }
+
+    /// Return a square root of `x`, unsatisfiable if `x` is not a quadratic residue
+    /// Which of the two roots is returned is not constrained
+    /// constraints: 1
+    pub fn sqrt<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, x: &Self) -> Self {
+        let root = Self::witness(cs, sqrt(x.value(cs)).unwrap_or_else(F::zero));
+        cs.mul_gate(&root.0, &root.0, &x.0);
+
+        root
+    }
+}
+
+/// Big-endian bits of `p - 1`
+fn modulus_minus_one<F: PrimeField>() -> Vec<u8> {
+    (-F::one()).to_bits()
+}
+
+fn pow_bits<F: PrimeField>(base: F, exp: &[u8]) -> F {
+    exp.iter().fold(F::one(), |acc, bit| {
+        let acc = acc.square();
+        if *bit == 1 {
+            acc * base
+        } else {
+            acc
+        }
+    })
+}
+
+/// Tonelli-Shanks square root
+pub(crate) fn sqrt<F: PrimeField>(a: F) -> Option<F> {
+    if a.is_zero() {
+        return Some(F::zero());
+    }
+
+    let p_minus_one = modulus_minus_one::<F>();
+    let legendre = |x: F| pow_bits(x, &p_minus_one[..p_minus_one.len() - 1]);
+    if legendre(a) != F::one() {
+        return None;
+    }
+
+    // p - 1 = q * 2^s with odd q
+    let s = p_minus_one.iter().rev().take_while(|b| **b == 0).count();
+    let q = &p_minus_one[..p_minus_one.len() - s];
+    let q_half = &q[..q.len() - 1];
+
+    let mut z = F::from(2);
+    while legendre(z) == F::one() {
+        z += F::one();
+    }
+
+    let mut m = s;
+    let mut c = pow_bits(z, q);
+    let mut t = pow_bits(a, q);
+    let mut r = pow_bits(a, q_half) * a;
+    while t != F::one() {
+        let mut i = 1;
+        let mut t2i = t.square();
+        while t2i != F::one() {
+            t2i = t2i.square();
+            i += 1;
+        }
+        let b = (0..m - i - 1).fold(c, |b, _| b.square());
+        m = i;
+        c = b.square();
+        t *= c;
+        r *= b;
+    }
+    Some(r)
 }
 
 impl<F: PrimeField> From<&BinaryAssignment> for FieldAssignment<F> {
@@ -0,0 +1,115 @@
+use super::uint::{UInt64, UInt8};
+
+use crate::circuit::CircuitDriver;
+use crate::common::{PrimeField, Vec};
+use crate::r1cs::R1cs;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// rho offsets indexed by x + 5y
+const ROTATIONS: [usize; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// Keccak-256 rate in bytes
+const RATE: usize = 136;
+
+/// Keccak-256 digest as used by Ethereum, with the original `0x01` padding rather than SHA3's.
+/// The message length is fixed when the circuit is synthesized.
+pub fn keccak256<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    input: &[UInt8<F>],
+) -> Vec<UInt8<F>> {
+    let mut padded = input.to_vec();
+    if padded.len() % RATE == RATE - 1 {
+        padded.push(UInt8::constant(0x81));
+    } else {
+        padded.push(UInt8::constant(0x01));
+        while padded.len() % RATE != RATE - 1 {
+            padded.push(UInt8::constant(0));
+        }
+        padded.push(UInt8::constant(0x80));
+    }
+
+    let mut state: Vec<UInt64<F>> = (0..25).map(|_| UInt64::constant(0)).collect();
+    for block in padded.chunks(RATE) {
+        for (lane, bytes) in state.iter_mut().zip(block.chunks(8)) {
+            *lane = UInt64::xor(cs, lane, &UInt64::from_bytes_le(bytes));
+        }
+        state = keccak_f1600(cs, &state);
+    }
+
+    state[..4]
+        .iter()
+        .flat_map(|lane| lane.to_bytes_le())
+        .collect()
+}
+
+/// Keccak-f[1600] permutation of 25 lanes indexed by x + 5y
+/// constraints: 153600 for a variable state
+pub fn keccak_f1600<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    state: &[UInt64<F>],
+) -> Vec<UInt64<F>> {
+    assert_eq!(state.len(), 25);
+
+    let mut a = state.to_vec();
+    for rc in ROUND_CONSTANTS {
+        // theta
+        let c: Vec<_> = (0..5)
+            .map(|x| (1..5).fold(a[x].clone(), |acc, y| UInt64::xor(cs, &acc, &a[x + 5 * y])))
+            .collect();
+        for x in 0..5 {
+            let d = UInt64::xor(cs, &c[(x + 4) % 5], &c[(x + 1) % 5].rotate_left(1));
+            for y in 0..5 {
+                a[x + 5 * y] = UInt64::xor(cs, &a[x + 5 * y], &d);
+            }
+        }
+
+        // rho and pi
+        let mut b = a.clone();
+        for x in 0..5 {
+            for y in 0..5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = a[x + 5 * y].rotate_left(ROTATIONS[x + 5 * y]);
+            }
+        }
+
+        // chi
+        for x in 0..5 {
+            for y in 0..5 {
+                let t = UInt64::and(cs, &b[(x + 1) % 5 + 5 * y].not(), &b[(x + 2) % 5 + 5 * y]);
+                a[x + 5 * y] = UInt64::xor(cs, &b[x + 5 * y], &t);
+            }
+        }
+
+        // iota
+        a[0] = UInt64::xor(cs, &a[0], &UInt64::constant(rc));
+    }
+
+    a
+}
@@ -0,0 +1,675 @@
+use super::binary::BinaryAssignment;
+use super::field::FieldAssignment;
+use super::uint::{UInt64, UInt8};
+
+use crate::circuit::CircuitDriver;
+use crate::common::{vec, Add, Neg, PrimeField, Sub, Vec};
+use crate::r1cs::R1cs;
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+
+const LIMB_WIDTH: usize = 64;
+// bits kept free for the carries of the reduction
+const HEADROOM: usize = 8;
+
+/// Element of the emulated field `E` as little-endian 64 bit limbs over the native field `N`.
+/// Addition and subtraction are free and only grow the limb bounds, multiplication reduces
+/// its operands first when the product would not fit in the native field.
+#[derive(Clone)]
+pub struct NonNativeFieldAssignment<N: PrimeField, E: PrimeField> {
+    limbs: Vec<FieldAssignment<N>>,
+    // every limb is less than 2^max_bits
+    max_bits: usize,
+    marker: PhantomData<E>,
+}
+
+impl<N: PrimeField, E: PrimeField> NonNativeFieldAssignment<N, E> {
+    /// Allocate the canonical limbs of `value`
+    /// constraints: 65 per limb
+    pub fn witness<C: CircuitDriver<Scalar = N>>(cs: &mut R1cs<C>, value: E) -> Self {
+        let limbs = alloc_limbs(cs, &words(value), n_limbs::<E>());
+
+        Self::new(limbs, LIMB_WIDTH)
+    }
+
+    pub fn constant(value: E) -> Self {
+        let limbs = padded(words(value), n_limbs::<E>())
+            .into_iter()
+            .map(|word| FieldAssignment::constant(&N::from(word)))
+            .collect();
+
+        Self::new(limbs, LIMB_WIDTH)
+    }
+
+    pub fn value<C: CircuitDriver<Scalar = N>>(&self, cs: &R1cs<C>) -> E {
+        self.limbs
+            .iter()
+            .enumerate()
+            .fold(E::zero(), |acc, (i, limb)| {
+                let mut bytes = [0; 64];
+                let limb = limb.value(cs).to_raw_bytes();
+                bytes[..limb.len()].copy_from_slice(&limb);
+                acc + E::from_bytes_wide(&bytes) * E::pow_of_2((LIMB_WIDTH * i) as u64)
+            })
+    }
+
+    /// The integer of a native field element as an element of the emulated field
+    /// constraints: 130 per limb, the recomposition check and the bound check
+    pub fn from_field<C: CircuitDriver<Scalar = N>>(
+        cs: &mut R1cs<C>,
+        x: &FieldAssignment<N>,
+    ) -> Self {
+        let n = n_limbs::<E>();
+        assert!(n_limbs::<N>() <= n);
+        let integer = words(x.value(cs));
+        let limbs = alloc_limbs(cs, &integer, n);
+        let sum = limbs.iter().enumerate().fold(
+            FieldAssignment::constant(&N::zero()),
+            |acc, (i, limb)| {
+                let shift = FieldAssignment::constant(&N::pow_of_2((LIMB_WIDTH * i) as u64));
+                &acc + &FieldAssignment::mul(cs, limb, &shift)
+            },
+        );
+        FieldAssignment::enforce_eq(cs, &sum, x);
+
+        // the recomposition only holds modulo the native modulus p,
+        // limbs + diff = p - 1 over the integers makes the integer unique
+        let max = nat_sub(&modulus::<N>(), &[1]);
+        let diff = alloc_limbs(cs, &nat_sub(&max, &integer), n);
+        let coeffs = limbs
+            .iter()
+            .zip(diff.iter())
+            .zip(padded(max, n))
+            .map(|((a, b), c)| &(a + b) - &FieldAssignment::constant(&N::from(c)))
+            .collect();
+        enforce_zero_carried(cs, coeffs, LIMB_WIDTH + 2);
+
+        Self::new(limbs, LIMB_WIDTH)
+    }
+
+    pub fn limbs(&self) -> &[FieldAssignment<N>] {
+        &self.limbs
+    }
+
+    pub(crate) fn is_constant(&self) -> bool {
+        self.limbs
+            .iter()
+            .all(|limb| limb.inner().as_constant().is_some())
+    }
+
+    /// Pack big-endian bytes such as a digest into limbs
+    pub fn from_bytes_be<C: CircuitDriver<Scalar = N>>(
+        cs: &mut R1cs<C>,
+        bytes: &[UInt8<N>],
+    ) -> Self {
+        let n = n_limbs::<E>();
+        assert!(bytes.len() <= 8 * n);
+        let mut padded = vec![UInt8::constant(0); 8 * n - bytes.len()];
+        padded.extend_from_slice(bytes);
+        let limbs = padded
+            .chunks(8)
+            .rev()
+            .map(|chunk| UInt64::from_bytes_be(chunk).to_field(cs))
+            .collect();
+
+        Self::new(limbs, LIMB_WIDTH)
+    }
+
+    /// The same integer as an element of another emulated field with as many limbs
+    pub fn cast<F: PrimeField>(&self) -> NonNativeFieldAssignment<N, F> {
+        assert_eq!(self.limbs.len(), n_limbs::<F>());
+        NonNativeFieldAssignment::new(self.limbs.clone(), self.max_bits)
+    }
+
+    /// Reduce to limbs of 64 bits representing a value less than `2^(64 * limbs)`,
+    /// which is congruent to but not necessarily less than the modulus
+    pub fn reduce<C: CircuitDriver<Scalar = N>>(cs: &mut R1cs<C>, x: &Self) -> Self {
+        if x.max_bits == LIMB_WIDTH {
+            return x.clone();
+        }
+        let reduced = Self::witness(cs, x.value(cs));
+        Self::enforce_eq(cs, x, &reduced);
+
+        reduced
+    }
+
+    /// Reduce to the representation less than the modulus
+    pub fn canonical<C: CircuitDriver<Scalar = N>>(cs: &mut R1cs<C>, x: &Self) -> Self {
+        let canonical = Self::witness(cs, x.value(cs));
+        Self::enforce_eq(cs, x, &canonical);
+
+        // canonical + diff = p - 1 over the integers
+        let max = nat_sub(&modulus::<E>(), &[1]);
+        let diff = nat_sub(&max, &canonical.integer(cs));
+        let diff = alloc_limbs(cs, &diff, canonical.limbs.len());
+        let max = padded(max, canonical.limbs.len());
+        let coeffs = canonical
+            .limbs
+            .iter()
+            .zip(diff.iter())
+            .zip(max)
+            .map(|((a, b), c)| &(a + b) - &FieldAssignment::constant(&N::from(c)))
+            .collect();
+        enforce_zero_carried(cs, coeffs, LIMB_WIDTH + 2);
+
+        canonical
+    }
+
+    /// constraints: 2 * limbs - 1 for the product and the cost of a reduction
+    pub fn mul<C: CircuitDriver<Scalar = N>>(cs: &mut R1cs<C>, x: &Self, y: &Self) -> Self {
+        let product = Self::witness(cs, x.value(cs) * y.value(cs));
+        Self::enforce_mul(cs, x, y, &product);
+
+        product
+    }
+
+    /// Enforce `x * y = z` modulo the emulated modulus
+    pub fn enforce_mul<C: CircuitDriver<Scalar = N>>(
+        cs: &mut R1cs<C>,
+        x: &Self,
+        y: &Self,
+        z: &Self,
+    ) {
+        let (mut x, mut y) = (x.clone(), y.clone());
+        // lazily reduce the operands until the product coefficients fit
+        loop {
+            let product_bits = x.max_bits + y.max_bits + bit_len(x.limbs.len() as u64);
+            if product_bits + HEADROOM < capacity::<N>() {
+                break;
+            }
+            assert!(x.max_bits > LIMB_WIDTH || y.max_bits > LIMB_WIDTH);
+            if x.max_bits >= y.max_bits {
+                x = Self::reduce(cs, &x);
+            } else {
+                y = Self::reduce(cs, &y);
+            }
+        }
+        let product_bits = x.max_bits + y.max_bits + bit_len(x.limbs.len() as u64);
+        let product = poly_mul(cs, &x.limbs, &y.limbs);
+
+        // x * y - z as x * y + (pad - z) with non-negative coefficients
+        let neg_z = -z;
+        let mut coeffs = product;
+        for (coeff, limb) in coeffs.iter_mut().zip(neg_z.limbs.iter()) {
+            *coeff = &*coeff + limb;
+        }
+        let value = nat_add(&nat_mul(&x.integer(cs), &y.integer(cs)), &neg_z.integer(cs));
+
+        enforce_multiple::<N, E, C>(cs, coeffs, product_bits.max(neg_z.max_bits) + 1, &value);
+    }
+
+    pub fn square<C: CircuitDriver<Scalar = N>>(cs: &mut R1cs<C>, x: &Self) -> Self {
+        Self::mul(cs, x, x)
+    }
+
+    /// Return `x^-1`, unsatisfiable for `x = 0`
+    pub fn inverse<C: CircuitDriver<Scalar = N>>(cs: &mut R1cs<C>, x: &Self) -> Self {
+        let inv = Self::witness(cs, x.value(cs).invert().unwrap_or_else(E::zero));
+        Self::enforce_mul(cs, x, &inv, &Self::constant(E::one()));
+
+        inv
+    }
+
+    /// Return `x / y`, unsatisfiable for `y = 0`
+    pub fn div<C: CircuitDriver<Scalar = N>>(cs: &mut R1cs<C>, x: &Self, y: &Self) -> Self {
+        let inv = Self::inverse(cs, y);
+        Self::mul(cs, x, &inv)
+    }
+
+    /// Enforce `x = y` modulo the emulated modulus
+    pub fn enforce_eq<C: CircuitDriver<Scalar = N>>(cs: &mut R1cs<C>, x: &Self, y: &Self) {
+        let diff = x - y;
+        let value = diff.integer(cs);
+
+        enforce_multiple::<N, E, C>(cs, diff.limbs, diff.max_bits, &value);
+    }
+
+    /// constraints: a witness, a booleanity check, a product check, a limb-wise masking
+    /// and an equality check
+    pub fn is_eq<C: CircuitDriver<Scalar = N>>(
+        cs: &mut R1cs<C>,
+        x: &Self,
+        y: &Self,
+    ) -> BinaryAssignment {
+        let diff = x - y;
+        let diff_value = diff.value(cs);
+        let is_eq = BinaryAssignment::witness(cs, u8::from(diff_value == E::zero()));
+        let bit = FieldAssignment::from(&is_eq);
+        // is_eq * (1 - is_eq) = 0
+        let one = FieldAssignment::constant(&N::one());
+        cs.mul_gate(
+            bit.inner(),
+            (&one - &bit).inner(),
+            FieldAssignment::constant(&N::zero()).inner(),
+        );
+
+        // diff * inv = 1 - is_eq proves diff != 0 when is_eq = 0
+        let inv = Self::witness(cs, diff_value.invert().unwrap_or_else(E::one));
+        let mut not_eq = vec![FieldAssignment::constant(&N::zero()); n_limbs::<E>()];
+        not_eq[0] = &one - &bit;
+        Self::enforce_mul(cs, &diff, &inv, &Self::new(not_eq, LIMB_WIDTH));
+
+        // diff * is_eq = 0 proves diff = 0 when is_eq = 1
+        let limbs = diff
+            .limbs
+            .iter()
+            .map(|limb| FieldAssignment::mul(cs, limb, &bit))
+            .collect();
+        let masked = Self::new(limbs, diff.max_bits);
+        Self::enforce_eq(cs, &masked, &Self::constant(E::zero()));
+
+        is_eq
+    }
+
+    pub fn conditional_select<C: CircuitDriver<Scalar = N>>(
+        cs: &mut R1cs<C>,
+        a: &Self,
+        b: &Self,
+        condition: &BinaryAssignment,
+    ) -> Self {
+        let limbs = a
+            .limbs
+            .iter()
+            .zip(b.limbs.iter())
+            .map(|(a, b)| FieldAssignment::conditional_select(cs, a, b, condition))
+            .collect();
+
+        Self::new(limbs, a.max_bits.max(b.max_bits))
+    }
+
+    /// Big-endian bits of a value congruent to `x` of `64 * limbs` bits
+    pub fn to_bits<C: CircuitDriver<Scalar = N>>(
+        cs: &mut R1cs<C>,
+        x: &Self,
+    ) -> Vec<BinaryAssignment> {
+        let reduced = Self::reduce(cs, x);
+        reduced
+            .limbs
+            .iter()
+            .rev()
+            .flat_map(|limb| {
+                let bits = FieldAssignment::to_bits(cs, limb, LIMB_WIDTH);
+                FieldAssignment::enforce_eq_bits(cs, limb, &bits);
+                bits
+            })
+            .collect()
+    }
+
+    fn new(limbs: Vec<FieldAssignment<N>>, max_bits: usize) -> Self {
+        assert!(
+            max_bits + HEADROOM < capacity::<N>(),
+            "non-native limbs overflow, reduce before further additions"
+        );
+        Self {
+            limbs,
+            max_bits,
+            marker: PhantomData,
+        }
+    }
+
+    /// The integer represented by the limbs
+    fn integer<C: CircuitDriver<Scalar = N>>(&self, cs: &R1cs<C>) -> Vec<u64> {
+        self.limbs
+            .iter()
+            .enumerate()
+            .fold(vec![], |acc, (i, limb)| {
+                let mut shifted = vec![0; i];
+                shifted.extend(words(limb.value(cs)));
+                nat_add(&acc, &shifted)
+            })
+    }
+}
+
+impl<N: PrimeField, E: PrimeField> Add<&NonNativeFieldAssignment<N, E>>
+    for &NonNativeFieldAssignment<N, E>
+{
+    type Output = NonNativeFieldAssignment<N, E>;
+
+    fn add(self, rhs: &NonNativeFieldAssignment<N, E>) -> Self::Output {
+        let limbs = self
+            .limbs
+            .iter()
+            .zip(rhs.limbs.iter())
+            .map(|(a, b)| a + b)
+            .collect();
+
+        NonNativeFieldAssignment::new(limbs, self.max_bits.max(rhs.max_bits) + 1)
+    }
+}
+
+impl<N: PrimeField, E: PrimeField> Sub<&NonNativeFieldAssignment<N, E>>
+    for &NonNativeFieldAssignment<N, E>
+{
+    type Output = NonNativeFieldAssignment<N, E>;
+
+    /// Add a multiple of the modulus whose limbs exceed the limbs of `rhs`
+    /// so that no limb becomes negative
+    fn sub(self, rhs: &NonNativeFieldAssignment<N, E>) -> Self::Output {
+        let bits = rhs.max_bits.max(LIMB_WIDTH);
+        let n = self.limbs.len();
+        let padding = (0..n).fold(E::zero(), |acc, i| {
+            acc + E::pow_of_2((bits + LIMB_WIDTH * i) as u64)
+        });
+        let correction = padded(words(-padding), n);
+
+        let limbs = self
+            .limbs
+            .iter()
+            .zip(rhs.limbs.iter())
+            .zip(correction)
+            .map(|((a, b), c)| {
+                let pad = FieldAssignment::constant(&(N::pow_of_2(bits as u64) + N::from(c)));
+                &(a + &pad) - b
+            })
+            .collect();
+
+        NonNativeFieldAssignment::new(limbs, self.max_bits.max(bits) + 2)
+    }
+}
+
+impl<N: PrimeField, E: PrimeField> Neg for &NonNativeFieldAssignment<N, E> {
+    type Output = NonNativeFieldAssignment<N, E>;
+
+    fn neg(self) -> Self::Output {
+        &NonNativeFieldAssignment::constant(E::zero()) - self
+    }
+}
+
+/// Prove that the polynomial `coeffs` evaluated at `2^64` is a multiple of the modulus,
+/// `value` is the non-negative evaluation and the coefficients are in `(-2^bits, 2^bits)`
+fn enforce_multiple<N: PrimeField, E: PrimeField, C: CircuitDriver<Scalar = N>>(
+    cs: &mut R1cs<C>,
+    coeffs: Vec<FieldAssignment<N>>,
+    bits: usize,
+    value: &[u64],
+) {
+    let p = modulus::<E>();
+    let (quotient, _) = nat_divmod(value, &p);
+
+    let value_bits = bits + LIMB_WIDTH * (coeffs.len() - 1) + 1;
+    let quotient_limbs = limbs_for(value_bits, bit_len_nat(&p) - 1);
+    let quotient = alloc_limbs(cs, &quotient, quotient_limbs);
+
+    let qp = mul_constant(cs, &quotient, &p);
+    let coeffs = sub_coeffs(&coeffs, &qp);
+    let qp_bits = 2 * LIMB_WIDTH + bit_len(quotient_limbs.min(p.len()) as u64);
+    enforce_zero_carried(cs, coeffs, bits.max(qp_bits) + 1);
+}
+
+/// Enforce that the polynomial evaluates to zero at `2^64` with the coefficients
+/// in `(-2^bits, 2^bits)`. As many coefficients as fit the native field are grouped and
+/// each group is checked with a range checked signed carry.
+fn enforce_zero_carried<N: PrimeField, C: CircuitDriver<Scalar = N>>(
+    cs: &mut R1cs<C>,
+    coeffs: Vec<FieldAssignment<N>>,
+    bits: usize,
+) {
+    let capacity = capacity::<N>();
+    assert!(bits + 5 < capacity && bits > LIMB_WIDTH);
+    let group_size = (capacity - 5 - bits) / LIMB_WIDTH + 1;
+    let group_width = LIMB_WIDTH * group_size;
+
+    let groups: Vec<FieldAssignment<N>> = coeffs
+        .chunks(group_size)
+        .map(|chunk| {
+            chunk.iter().enumerate().fold(
+                FieldAssignment::constant(&N::zero()),
+                |acc, (i, coeff)| {
+                    let shift = FieldAssignment::constant(&N::pow_of_2((LIMB_WIDTH * i) as u64));
+                    &acc + &FieldAssignment::mul(cs, coeff, &shift)
+                },
+            )
+        })
+        .collect();
+
+    // |carry| < 2^carry_bits, shifted by the offset to be range checked
+    let carry_bits = bits + 2 - LIMB_WIDTH;
+    let offset = FieldAssignment::constant(&N::pow_of_2(carry_bits as u64));
+    let base = N::pow_of_2(group_width as u64);
+    let base_inv = base.invert().unwrap();
+    let mut carry = FieldAssignment::constant(&N::zero());
+    let last = groups.len() - 1;
+    for (i, group) in groups.iter().enumerate() {
+        let sum = group + &carry;
+        if i == last {
+            FieldAssignment::enforce_eq_constant(cs, &sum, &N::zero());
+            break;
+        }
+        let shifted = FieldAssignment::witness(
+            cs,
+            sum.value(cs) * base_inv + N::pow_of_2(carry_bits as u64),
+        );
+        let shifted_bits = FieldAssignment::to_bits(cs, &shifted, carry_bits + 1);
+        FieldAssignment::enforce_eq_bits(cs, &shifted, &shifted_bits);
+        let next = &shifted - &offset;
+        // sum = carry * 2^group_width
+        let carried = FieldAssignment::mul(cs, &next, &FieldAssignment::constant(&base));
+        FieldAssignment::enforce_eq(cs, &sum, &carried);
+        carry = next;
+    }
+}
+
+/// Coefficients of the product, checked by evaluation at `2 * limbs - 1` points
+fn poly_mul<N: PrimeField, C: CircuitDriver<Scalar = N>>(
+    cs: &mut R1cs<C>,
+    a: &[FieldAssignment<N>],
+    b: &[FieldAssignment<N>],
+) -> Vec<FieldAssignment<N>> {
+    let n_coeffs = a.len() + b.len() - 1;
+    let is_constant = |limbs: &[FieldAssignment<N>]| {
+        limbs
+            .iter()
+            .all(|limb| limb.inner().as_constant().is_some())
+    };
+    if is_constant(a) || is_constant(b) {
+        let mut product = vec![FieldAssignment::constant(&N::zero()); n_coeffs];
+        for (i, a) in a.iter().enumerate() {
+            for (j, b) in b.iter().enumerate() {
+                product[i + j] = &product[i + j] + &FieldAssignment::mul(cs, a, b);
+            }
+        }
+        return product;
+    }
+
+    let mut values = vec![N::zero(); n_coeffs];
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            values[i + j] += a.value(cs) * b.value(cs);
+        }
+    }
+    let product: Vec<FieldAssignment<N>> = values
+        .into_iter()
+        .map(|value| FieldAssignment::witness(cs, value))
+        .collect();
+
+    for x in 0..n_coeffs {
+        let x = N::from(x as u64);
+        let a_x = evaluate(cs, a, x);
+        let b_x = evaluate(cs, b, x);
+        let c_x = evaluate(cs, &product, x);
+        cs.mul_gate(a_x.inner(), b_x.inner(), c_x.inner());
+    }
+
+    product
+}
+
+fn evaluate<N: PrimeField, C: CircuitDriver<Scalar = N>>(
+    cs: &mut R1cs<C>,
+    coeffs: &[FieldAssignment<N>],
+    x: N,
+) -> FieldAssignment<N> {
+    let mut power = N::one();
+    coeffs
+        .iter()
+        .fold(FieldAssignment::constant(&N::zero()), |acc, coeff| {
+            let term = FieldAssignment::mul(cs, coeff, &FieldAssignment::constant(&power));
+            power *= x;
+            &acc + &term
+        })
+}
+
+/// Product of limbs with a constant, linear in the limbs
+fn mul_constant<N: PrimeField, C: CircuitDriver<Scalar = N>>(
+    cs: &mut R1cs<C>,
+    limbs: &[FieldAssignment<N>],
+    c: &[u64],
+) -> Vec<FieldAssignment<N>> {
+    let mut product = vec![FieldAssignment::constant(&N::zero()); limbs.len() + c.len() - 1];
+    for (i, limb) in limbs.iter().enumerate() {
+        for (j, word) in c.iter().enumerate() {
+            let term = FieldAssignment::mul(cs, limb, &FieldAssignment::constant(&N::from(*word)));
+            product[i + j] = &product[i + j] + &term;
+        }
+    }
+    product
+}
+
+fn sub_coeffs<N: PrimeField>(
+    a: &[FieldAssignment<N>],
+    b: &[FieldAssignment<N>],
+) -> Vec<FieldAssignment<N>> {
+    let zero = FieldAssignment::constant(&N::zero());
+    (0..a.len().max(b.len()))
+        .map(|i| a.get(i).unwrap_or(&zero) - b.get(i).unwrap_or(&zero))
+        .collect()
+}
+
+/// Allocate `n` limbs of 64 bits
+fn alloc_limbs<N: PrimeField, C: CircuitDriver<Scalar = N>>(
+    cs: &mut R1cs<C>,
+    words: &[u64],
+    n: usize,
+) -> Vec<FieldAssignment<N>> {
+    assert!(words.iter().skip(n).all(|word| *word == 0));
+    padded(words.to_vec(), n)
+        .into_iter()
+        .map(|word| {
+            let limb = FieldAssignment::witness(cs, N::from(word));
+            let bits = FieldAssignment::to_bits(cs, &limb, LIMB_WIDTH);
+            FieldAssignment::enforce_eq_bits(cs, &limb, &bits);
+            limb
+        })
+        .collect()
+}
+
+/// Number of limbs of a quotient of a `value_bits` integer by a modulus of at least `2^min_bits`
+fn limbs_for(value_bits: usize, min_bits: usize) -> usize {
+    (value_bits.saturating_sub(min_bits).max(1) - 1) / LIMB_WIDTH + 1
+}
+
+fn capacity<F: PrimeField>() -> usize {
+    bit_len_nat(&modulus::<F>()) - 1
+}
+
+fn n_limbs<F: PrimeField>() -> usize {
+    modulus::<F>().len()
+}
+
+/// Little-endian 64 bit words of the modulus
+fn modulus<F: PrimeField>() -> Vec<u64> {
+    let mut p = nat_add(&words(-F::one()), &[1]);
+    trim(&mut p);
+    p
+}
+
+/// Little-endian 64 bit words of the canonical representation
+fn words<F: PrimeField>(f: F) -> Vec<u64> {
+    f.to_raw_bytes()
+        .chunks(8)
+        .map(|chunk| {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(word)
+        })
+        .collect()
+}
+
+fn padded(mut words: Vec<u64>, n: usize) -> Vec<u64> {
+    words.resize(n, 0);
+    words
+}
+
+fn trim(a: &mut Vec<u64>) {
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+}
+
+fn bit_len(x: u64) -> usize {
+    (u64::BITS - x.leading_zeros()) as usize
+}
+
+fn bit_len_nat(a: &[u64]) -> usize {
+    match a.iter().rposition(|word| *word != 0) {
+        Some(i) => LIMB_WIDTH * i + bit_len(a[i]),
+        None => 0,
+    }
+}
+
+fn nat_add(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u128;
+    for i in 0..a.len().max(b.len()) {
+        let s = *a.get(i).unwrap_or(&0) as u128 + *b.get(i).unwrap_or(&0) as u128 + carry;
+        sum.push(s as u64);
+        carry = s >> 64;
+    }
+    sum.push(carry as u64);
+    trim(&mut sum);
+    sum
+}
+
+fn nat_mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut product = vec![0u64; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, y) in b.iter().enumerate() {
+            let t = *x as u128 * *y as u128 + product[i + j] as u128 + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        product[i + b.len()] = carry as u64;
+    }
+    trim(&mut product);
+    product
+}
+
+fn nat_cmp(a: &[u64], b: &[u64]) -> Ordering {
+    (0..a.len().max(b.len()))
+        .rev()
+        .map(|i| a.get(i).unwrap_or(&0).cmp(b.get(i).unwrap_or(&0)))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// `a - b` for `a >= b`
+fn nat_sub(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut diff = Vec::with_capacity(a.len());
+    let mut borrow = false;
+    for (i, x) in a.iter().enumerate() {
+        let (d, b0) = x.overflowing_sub(*b.get(i).unwrap_or(&0));
+        let (d, b1) = d.overflowing_sub(borrow as u64);
+        diff.push(d);
+        borrow = b0 || b1;
+    }
+    trim(&mut diff);
+    diff
+}
+
+/// Binary long division
+fn nat_divmod(a: &[u64], m: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    let mut quotient = vec![0u64; a.len()];
+    let mut remainder: Vec<u64> = vec![];
+    for i in (0..bit_len_nat(a)).rev() {
+        remainder = nat_add(&remainder, &remainder);
+        if (a[i / LIMB_WIDTH] >> (i % LIMB_WIDTH)) & 1 == 1 {
+            remainder = nat_add(&remainder, &[1]);
+        }
+        if nat_cmp(&remainder, m) != Ordering::Less {
+            remainder = nat_sub(&remainder, m);
+            quotient[i / LIMB_WIDTH] |= 1 << (i % LIMB_WIDTH);
+        }
+    }
+    trim(&mut quotient);
+    (quotient, remainder)
+}
@@ -0,0 +1,269 @@
+use super::binary::BinaryAssignment;
+use super::field::sqrt;
+use super::nonnative::NonNativeFieldAssignment;
+
+use crate::circuit::CircuitDriver;
+use crate::common::{BNAffine, Group, IntGroup, PrimeField, Ring};
+use crate::r1cs::R1cs;
+
+/// Affine point of a short Weierstrass curve `y^2 = x^3 + b` whose base field is emulated
+/// over the native field `N`. The identity is not representable and the addition formulas
+/// are incomplete, scalar multiplication avoids the exceptional cases by an offset point.
+#[derive(Clone)]
+pub struct NonNativePointAssignment<N: PrimeField, C: BNAffine> {
+    x: NonNativeFieldAssignment<N, C::Base>,
+    y: NonNativeFieldAssignment<N, C::Base>,
+}
+
+impl<N: PrimeField, C: BNAffine> NonNativePointAssignment<N, C> {
+    /// The point is not checked to be on the curve, see `enforce_on_curve`
+    pub fn witness<CS: CircuitDriver<Scalar = N>>(cs: &mut R1cs<CS>, point: C) -> Self {
+        assert!(!point.is_identity());
+        let x = NonNativeFieldAssignment::witness(cs, point.get_x());
+        let y = NonNativeFieldAssignment::witness(cs, point.get_y());
+
+        Self { x, y }
+    }
+
+    pub fn constant(point: C) -> Self {
+        assert!(!point.is_identity());
+        let x = NonNativeFieldAssignment::constant(point.get_x());
+        let y = NonNativeFieldAssignment::constant(point.get_y());
+
+        Self { x, y }
+    }
+
+    pub fn new(
+        x: NonNativeFieldAssignment<N, C::Base>,
+        y: NonNativeFieldAssignment<N, C::Base>,
+    ) -> Self {
+        Self { x, y }
+    }
+
+    pub fn value<CS: CircuitDriver<Scalar = N>>(&self, cs: &R1cs<CS>) -> C {
+        C::new_unchecked(self.x.value(cs), self.y.value(cs))
+    }
+
+    pub fn get_x(&self) -> &NonNativeFieldAssignment<N, C::Base> {
+        &self.x
+    }
+
+    pub fn get_y(&self) -> &NonNativeFieldAssignment<N, C::Base> {
+        &self.y
+    }
+
+    /// constraints: 2 non-native multiplications and a product check
+    pub fn enforce_on_curve<CS: CircuitDriver<Scalar = N>>(&self, cs: &mut R1cs<CS>) {
+        let b = NonNativeFieldAssignment::constant(C::PARAM_B);
+        let xx = NonNativeFieldAssignment::mul(cs, &self.x, &self.x);
+        let yy = NonNativeFieldAssignment::mul(cs, &self.y, &self.y);
+        NonNativeFieldAssignment::enforce_mul(cs, &xx, &self.x, &(&yy - &b));
+    }
+
+    pub fn enforce_equal<CS: CircuitDriver<Scalar = N>>(cs: &mut R1cs<CS>, a: &Self, b: &Self) {
+        NonNativeFieldAssignment::enforce_eq(cs, &a.x, &b.x);
+        NonNativeFieldAssignment::enforce_eq(cs, &a.y, &b.y);
+    }
+
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x.clone(),
+            y: -&self.y,
+        }
+    }
+
+    /// Chord addition, unsatisfiable when the x coordinates are equal
+    /// constraints: 4 witnesses and 4 product checks
+    pub fn add_incomplete<CS: CircuitDriver<Scalar = N>>(
+        &self,
+        rhs: &Self,
+        cs: &mut R1cs<CS>,
+    ) -> Self {
+        if self.is_constant() && rhs.is_constant() {
+            return Self::constant(C::from(self.value(cs) + rhs.value(cs)));
+        }
+        let (x1, y1, x2, y2) = (
+            self.x.value(cs),
+            self.y.value(cs),
+            rhs.x.value(cs),
+            rhs.y.value(cs),
+        );
+        let lambda = (y2 - y1) * (x2 - x1).invert().unwrap_or_else(C::Base::zero);
+        let x3 = lambda.square() - x1 - x2;
+        let y3 = lambda * (x1 - x3) - y1;
+
+        let lambda = NonNativeFieldAssignment::witness(cs, lambda);
+        let x3 = NonNativeFieldAssignment::witness(cs, x3);
+        let y3 = NonNativeFieldAssignment::witness(cs, y3);
+        let dx = &rhs.x - &self.x;
+        let dy = &rhs.y - &self.y;
+        // the slope is unconstrained for equal points
+        NonNativeFieldAssignment::inverse(cs, &dx);
+        NonNativeFieldAssignment::enforce_mul(cs, &lambda, &dx, &dy);
+        self.enforce_chord(cs, &lambda, &rhs.x, &x3, &y3);
+
+        Self { x: x3, y: y3 }
+    }
+
+    /// Tangent doubling, the curve has no point of order two
+    /// constraints: a multiplication, 3 witnesses and 3 product checks
+    pub fn double<CS: CircuitDriver<Scalar = N>>(&self, cs: &mut R1cs<CS>) -> Self {
+        if self.is_constant() {
+            return Self::constant(C::from(self.value(cs).double()));
+        }
+        let (x1, y1) = (self.x.value(cs), self.y.value(cs));
+        let lambda =
+            x1.square() * C::Base::from(3) * (y1 + y1).invert().unwrap_or_else(C::Base::zero);
+        let x3 = lambda.square() - x1 - x1;
+        let y3 = lambda * (x1 - x3) - y1;
+
+        let lambda = NonNativeFieldAssignment::witness(cs, lambda);
+        let x3 = NonNativeFieldAssignment::witness(cs, x3);
+        let y3 = NonNativeFieldAssignment::witness(cs, y3);
+        let xx = NonNativeFieldAssignment::mul(cs, &self.x, &self.x);
+        let three_xx = &(&xx + &xx) + &xx;
+        NonNativeFieldAssignment::enforce_mul(cs, &lambda, &(&self.y + &self.y), &three_xx);
+        self.enforce_chord(cs, &lambda, &self.x, &x3, &y3);
+
+        Self { x: x3, y: y3 }
+    }
+
+    /// `2 * self + rhs` sharing the slope computation, unsatisfiable when the x coordinate
+    /// of `self` equals the one of `rhs` or `self + rhs`
+    /// constraints: 7 witnesses and 7 product checks
+    pub fn double_and_add<CS: CircuitDriver<Scalar = N>>(
+        &self,
+        rhs: &Self,
+        cs: &mut R1cs<CS>,
+    ) -> Self {
+        let (x1, y1, x2, y2) = (
+            self.x.value(cs),
+            self.y.value(cs),
+            rhs.x.value(cs),
+            rhs.y.value(cs),
+        );
+        let lambda1 = (y2 - y1) * (x2 - x1).invert().unwrap_or_else(C::Base::zero);
+        let x3 = lambda1.square() - x1 - x2;
+        let lambda2 = -lambda1 - (y1 + y1) * (x3 - x1).invert().unwrap_or_else(C::Base::zero);
+        let x4 = lambda2.square() - x1 - x3;
+        let y4 = lambda2 * (x1 - x4) - y1;
+
+        let lambda1 = NonNativeFieldAssignment::witness(cs, lambda1);
+        let x3 = NonNativeFieldAssignment::witness(cs, x3);
+        let lambda2 = NonNativeFieldAssignment::witness(cs, lambda2);
+        let x4 = NonNativeFieldAssignment::witness(cs, x4);
+        let y4 = NonNativeFieldAssignment::witness(cs, y4);
+
+        // x3 is the x coordinate of self + rhs
+        let dx = &rhs.x - &self.x;
+        let dy = &rhs.y - &self.y;
+        NonNativeFieldAssignment::inverse(cs, &dx);
+        NonNativeFieldAssignment::enforce_mul(cs, &lambda1, &dx, &dy);
+        let sum = &(&self.x + &rhs.x) + &x3;
+        NonNativeFieldAssignment::enforce_mul(cs, &lambda1, &lambda1, &sum);
+
+        // the chord through self and self + rhs, whose y coordinate is -(y1 + lambda1 (x3 - x1))
+        let slopes = &lambda1 + &lambda2;
+        let dx = &x3 - &self.x;
+        let dy = -&(&self.y + &self.y);
+        NonNativeFieldAssignment::inverse(cs, &dx);
+        NonNativeFieldAssignment::enforce_mul(cs, &slopes, &dx, &dy);
+        self.enforce_chord(cs, &lambda2, &x3, &x4, &y4);
+
+        Self { x: x4, y: y4 }
+    }
+
+    pub fn conditional_select<CS: CircuitDriver<Scalar = N>>(
+        cs: &mut R1cs<CS>,
+        a: &Self,
+        b: &Self,
+        condition: &BinaryAssignment,
+    ) -> Self {
+        let x = NonNativeFieldAssignment::conditional_select(cs, &a.x, &b.x, condition);
+        let y = NonNativeFieldAssignment::conditional_select(cs, &a.y, &b.y, condition);
+
+        Self { x, y }
+    }
+
+    /// `[a]P + [b]Q` for big-endian scalar bits of equal length by Shamir's trick.
+    /// The accumulator is offset by a point of unknown discrete logarithm so that
+    /// an honest prover reaches no exceptional case of the incomplete formulas,
+    /// which are unsatisfiable, except for a result at the identity.
+    /// constraints: about 5300 per bit
+    pub fn double_scalar_mul<CS: CircuitDriver<Scalar = N>>(
+        cs: &mut R1cs<CS>,
+        p: &Self,
+        a: &[BinaryAssignment],
+        q: &Self,
+        b: &[BinaryAssignment],
+    ) -> Self {
+        assert_eq!(a.len(), b.len());
+        assert!(a.len() > 1);
+        let offset = offset_point::<C>();
+        let h = Self::constant(offset);
+
+        // table indexed by the bits of a and b
+        let hp = h.add_incomplete(p, cs);
+        let hq = h.add_incomplete(q, cs);
+        let hpq = hp.add_incomplete(q, cs);
+        let select = |cs: &mut R1cs<CS>, a: &BinaryAssignment, b: &BinaryAssignment| {
+            let with_q = Self::conditional_select(cs, &hpq, &hq, a);
+            let without_q = Self::conditional_select(cs, &hp, &h, a);
+            Self::conditional_select(cs, &with_q, &without_q, b)
+        };
+
+        let mut acc = select(cs, &a[0], &b[0]);
+        let addend = select(cs, &a[1], &b[1]);
+        acc = acc.double(cs).add_incomplete(&addend, cs);
+        for (a, b) in a.iter().zip(b.iter()).skip(2) {
+            let addend = select(cs, a, b);
+            acc = acc.double_and_add(&addend, cs);
+        }
+
+        // remove the (2^n - 1) multiple of the offset
+        let scalar = C::Scalar::pow_of_2(a.len() as u64) - C::Scalar::one();
+        let correction = Self::constant(-C::from(offset * scalar));
+        // the accumulator equals the correction only for the identity result
+        acc.add_incomplete(&correction, cs)
+    }
+
+    /// Enforce that (x3, y3) is the chord through self with `slope` third intersection
+    /// negated, given the other point x coordinate
+    fn enforce_chord<CS: CircuitDriver<Scalar = N>>(
+        &self,
+        cs: &mut R1cs<CS>,
+        slope: &NonNativeFieldAssignment<N, C::Base>,
+        x2: &NonNativeFieldAssignment<N, C::Base>,
+        x3: &NonNativeFieldAssignment<N, C::Base>,
+        y3: &NonNativeFieldAssignment<N, C::Base>,
+    ) {
+        // x3 = slope^2 - x1 - x2
+        let sum = &(&self.x + x2) + x3;
+        NonNativeFieldAssignment::enforce_mul(cs, slope, slope, &sum);
+        // y3 = slope (x1 - x3) - y1
+        let dx = &self.x - x3;
+        let sum = &self.y + y3;
+        NonNativeFieldAssignment::enforce_mul(cs, slope, &dx, &sum);
+    }
+
+    fn is_constant(&self) -> bool {
+        self.x.is_constant() && self.y.is_constant()
+    }
+}
+
+/// The point with the least x coordinate above the one of the generator and even y coordinate,
+/// whose discrete logarithm is unknown. The least positive x coordinate is the generator itself
+/// on some curves.
+pub(crate) fn offset_point<C: BNAffine>() -> C {
+    let mut x = C::ADDITIVE_GENERATOR.get_x() + C::Base::one();
+    loop {
+        if let Some(y) = sqrt(x.square() * x + C::PARAM_B) {
+            let y = match y.to_raw_bytes()[0] & 1 {
+                0 => y,
+                _ => -y,
+            };
+            return C::new_unchecked(x, y);
+        }
+        x += C::Base::one();
+    }
+}
@@ -0,0 +1,164 @@
+use super::field::FieldAssignment;
+use super::uint::{UInt32, UInt8};
+
+use crate::circuit::CircuitDriver;
+use crate::common::{vec, PrimeField, Vec};
+use crate::r1cs::R1cs;
+
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// SHA-256 digest of a message whose length is fixed when the circuit is synthesized.
+/// The padding only adds constants so the cost is one compression per 64 byte block.
+pub fn sha256<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    input: &[UInt8<F>],
+) -> Vec<UInt8<F>> {
+    let mut padded = input.to_vec();
+    padded.push(UInt8::constant(0x80));
+    while padded.len() % 64 != 56 {
+        padded.push(UInt8::constant(0));
+    }
+    let bit_len = input.len() as u64 * 8;
+    padded.extend(
+        bit_len
+            .to_be_bytes()
+            .iter()
+            .map(|b| UInt8::constant(*b as u64)),
+    );
+
+    let mut state: Vec<UInt32<F>> = IV.iter().map(|v| UInt32::constant(*v as u64)).collect();
+    for block in padded.chunks(64) {
+        let words: Vec<_> = block.chunks(4).map(UInt32::from_bytes_be).collect();
+        state = sha256_compression(cs, &state, &words);
+    }
+
+    state.iter().flat_map(|word| word.to_bytes_be()).collect()
+}
+
+/// SHA-256 compression of one block of 16 words into the 8 words state
+/// constraints: 26416 for a variable state and block
+pub fn sha256_compression<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    state: &[UInt32<F>],
+    block: &[UInt32<F>],
+) -> Vec<UInt32<F>> {
+    assert_eq!(state.len(), 8);
+    assert_eq!(block.len(), 16);
+
+    let mut w = block.to_vec();
+    for t in 16..64 {
+        let s0 = xor3(
+            cs,
+            &w[t - 15].rotate_right(7),
+            &w[t - 15].rotate_right(18),
+            &w[t - 15].shr(3),
+        );
+        let s1 = xor3(
+            cs,
+            &w[t - 2].rotate_right(17),
+            &w[t - 2].rotate_right(19),
+            &w[t - 2].shr(10),
+        );
+        let word = UInt32::add_many(cs, &[w[t - 16].clone(), s0, w[t - 7].clone(), s1]);
+        w.push(word);
+    }
+
+    let mut v = state.to_vec();
+    for (k, w) in ROUND_CONSTANTS.iter().zip(w.iter()) {
+        let (a, b, c, d) = (&v[0], &v[1], &v[2], &v[3]);
+        let (e, f, g, h) = (&v[4], &v[5], &v[6], &v[7]);
+
+        let s1 = xor3(
+            cs,
+            &e.rotate_right(6),
+            &e.rotate_right(11),
+            &e.rotate_right(25),
+        );
+        let ch = ch(cs, e, f, g);
+        let s0 = xor3(
+            cs,
+            &a.rotate_right(2),
+            &a.rotate_right(13),
+            &a.rotate_right(22),
+        );
+        let maj = maj(cs, a, b, c);
+
+        // t1 = h + s1 + ch + k + w, t2 = s0 + maj
+        let mut t1 = vec![h.clone(), s1, ch, UInt32::constant(*k as u64), w.clone()];
+        let mut t1_t2 = t1.clone();
+        t1.push(d.clone());
+        t1_t2.extend([s0, maj]);
+        let new_e = UInt32::add_many(cs, &t1);
+        let new_a = UInt32::add_many(cs, &t1_t2);
+
+        v.rotate_right(1);
+        v[0] = new_a;
+        v[4] = new_e;
+    }
+
+    state
+        .iter()
+        .zip(v.iter())
+        .map(|(x, y)| UInt32::add(cs, x, y))
+        .collect()
+}
+
+/// x ^ y ^ z, 2 constraints per bit
+fn xor3<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    x: &UInt32<F>,
+    y: &UInt32<F>,
+    z: &UInt32<F>,
+) -> UInt32<F> {
+    let xy = UInt32::xor(cs, x, y);
+    UInt32::xor(cs, &xy, z)
+}
+
+/// (e & f) ^ (!e & g) = e * (f - g) + g, 1 constraint per bit
+fn ch<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    e: &UInt32<F>,
+    f: &UInt32<F>,
+    g: &UInt32<F>,
+) -> UInt32<F> {
+    let bits = e
+        .bits()
+        .iter()
+        .zip(f.bits().iter().zip(g.bits().iter()))
+        .map(|(e, (f, g))| &FieldAssignment::mul(cs, e, &(f - g)) + g)
+        .collect();
+    UInt32::from_bits(bits)
+}
+
+/// (a & b) ^ (a & c) ^ (b & c) = ab + c * (a ^ b), 2 constraints per bit
+fn maj<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    a: &UInt32<F>,
+    b: &UInt32<F>,
+    c: &UInt32<F>,
+) -> UInt32<F> {
+    let bits = a
+        .bits()
+        .iter()
+        .zip(b.bits().iter().zip(c.bits().iter()))
+        .map(|(a, (b, c))| {
+            let ab = FieldAssignment::mul(cs, a, b);
+            let a_xor_b = &(a + b) - &(&ab + &ab);
+            &ab + &FieldAssignment::mul(cs, c, &a_xor_b)
+        })
+        .collect();
+    UInt32::from_bits(bits)
+}
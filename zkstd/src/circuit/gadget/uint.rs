@@ -0,0 +1,280 @@
+use super::binary::BinaryAssignment;
+use super::field::FieldAssignment;
+
+use crate::circuit::CircuitDriver;
+use crate::common::{vec, PrimeField, Vec};
+use crate::r1cs::R1cs;
+
+pub type UInt8<F> = UIntAssignment<F, 8>;
+pub type UInt32<F> = UIntAssignment<F, 32>;
+pub type UInt64<F> = UIntAssignment<F, 64>;
+
+/// Unsigned integer of `N <= 64` bits backed by boolean constrained bits.
+/// Bitwise negation, rotations and shifts are free linear operations.
+#[derive(Clone)]
+pub struct UIntAssignment<F: PrimeField, const N: usize> {
+    // Big-endian
+    bits: Vec<FieldAssignment<F>>,
+}
+
+impl<F: PrimeField, const N: usize> UIntAssignment<F, N> {
+    const MASK: u64 = u64::MAX >> (64 - N);
+
+    pub fn constant(value: u64) -> Self {
+        assert!(N <= 64 && value <= Self::MASK);
+        let bits = (0..N)
+            .rev()
+            .map(|i| FieldAssignment::constant(&F::from((value >> i) & 1)))
+            .collect();
+
+        Self { bits }
+    }
+
+    /// constraints: N
+    pub fn witness<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, value: u64) -> Self {
+        assert!(N <= 64 && value <= Self::MASK);
+        let bits = (0..N)
+            .rev()
+            .map(|i| {
+                let bit = FieldAssignment::witness(cs, F::from((value >> i) & 1));
+                enforce_boolean(cs, &bit);
+                bit
+            })
+            .collect();
+
+        Self { bits }
+    }
+
+    /// Decompose `x` which must be less than `2^N`
+    /// constraints: N + 1
+    pub fn from_field<C: CircuitDriver<Scalar = F>>(
+        cs: &mut R1cs<C>,
+        x: &FieldAssignment<F>,
+    ) -> Self {
+        let bits = FieldAssignment::to_bits(cs, x, N);
+        FieldAssignment::enforce_eq_bits(cs, x, &bits);
+
+        Self {
+            bits: bits.iter().map(FieldAssignment::from).collect(),
+        }
+    }
+
+    pub fn to_field<C: CircuitDriver<Scalar = F>>(&self, cs: &mut R1cs<C>) -> FieldAssignment<F> {
+        let mut f = F::one();
+        self.bits
+            .iter()
+            .rev()
+            .fold(FieldAssignment::constant(&F::zero()), |acc, bit| {
+                let term = FieldAssignment::mul(cs, &FieldAssignment::constant(&f), bit);
+                f = f.double();
+                &acc + &term
+            })
+    }
+
+    pub fn value<C: CircuitDriver<Scalar = F>>(&self, cs: &R1cs<C>) -> u64 {
+        self.bits.iter().fold(0, |acc, bit| {
+            (acc << 1) | u64::from(bit.value(cs) == F::one())
+        })
+    }
+
+    /// Big-endian bits
+    pub fn bits(&self) -> &[FieldAssignment<F>] {
+        &self.bits
+    }
+
+    /// Big-endian bits which must already be boolean constrained
+    pub(crate) fn from_bits(bits: Vec<FieldAssignment<F>>) -> Self {
+        assert_eq!(bits.len(), N);
+        Self { bits }
+    }
+
+    /// Concatenate big-endian ordered bytes, panics unless there are `N / 8` of them
+    pub fn from_bytes_be(bytes: &[UInt8<F>]) -> Self {
+        assert_eq!(bytes.len() * 8, N);
+        let bits = bytes.iter().flat_map(|b| b.bits.iter().cloned()).collect();
+
+        Self { bits }
+    }
+
+    /// Concatenate little-endian ordered bytes, panics unless there are `N / 8` of them
+    pub fn from_bytes_le(bytes: &[UInt8<F>]) -> Self {
+        assert_eq!(bytes.len() * 8, N);
+        let bits = bytes
+            .iter()
+            .rev()
+            .flat_map(|b| b.bits.iter().cloned())
+            .collect();
+
+        Self { bits }
+    }
+
+    pub fn to_bytes_be(&self) -> Vec<UInt8<F>> {
+        assert_eq!(N % 8, 0);
+        self.bits
+            .chunks(8)
+            .map(|bits| UInt8 {
+                bits: bits.to_vec(),
+            })
+            .collect()
+    }
+
+    pub fn to_bytes_le(&self) -> Vec<UInt8<F>> {
+        let mut bytes = self.to_bytes_be();
+        bytes.reverse();
+        bytes
+    }
+
+    pub fn enforce_equal<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, a: &Self, b: &Self) {
+        let a = a.to_field(cs);
+        let b = b.to_field(cs);
+        FieldAssignment::enforce_eq(cs, &a, &b);
+    }
+
+    pub fn not(&self) -> Self {
+        let one = FieldAssignment::constant(&F::one());
+        let bits = self.bits.iter().map(|bit| &one - bit).collect();
+
+        Self { bits }
+    }
+
+    /// constraints: N
+    pub fn and<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, a: &Self, b: &Self) -> Self {
+        Self::bitwise(cs, a, b, |cs, x, y| FieldAssignment::mul(cs, x, y))
+    }
+
+    /// constraints: N
+    pub fn or<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, a: &Self, b: &Self) -> Self {
+        Self::bitwise(cs, a, b, |cs, x, y| combine(cs, x, y, F::one()))
+    }
+
+    /// constraints: N
+    pub fn xor<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, a: &Self, b: &Self) -> Self {
+        Self::bitwise(cs, a, b, |cs, x, y| combine(cs, x, y, F::from(2)))
+    }
+
+    pub fn rotate_left(&self, n: usize) -> Self {
+        let mut bits = self.bits.clone();
+        bits.rotate_left(n % N);
+
+        Self { bits }
+    }
+
+    pub fn rotate_right(&self, n: usize) -> Self {
+        let mut bits = self.bits.clone();
+        bits.rotate_right(n % N);
+
+        Self { bits }
+    }
+
+    pub fn shl(&self, n: usize) -> Self {
+        let n = n.min(N);
+        let mut bits = self.bits[n..].to_vec();
+        bits.resize(N, FieldAssignment::constant(&F::zero()));
+
+        Self { bits }
+    }
+
+    pub fn shr(&self, n: usize) -> Self {
+        let n = n.min(N);
+        let mut bits = vec![FieldAssignment::constant(&F::zero()); n];
+        bits.extend_from_slice(&self.bits[..N - n]);
+
+        Self { bits }
+    }
+
+    /// Return `a + b mod 2^N` and the carry
+    /// constraints: N + 2
+    pub fn add_with_carry<C: CircuitDriver<Scalar = F>>(
+        cs: &mut R1cs<C>,
+        a: &Self,
+        b: &Self,
+    ) -> (Self, BinaryAssignment) {
+        let (sum, mut carry) = Self::sum(cs, &[a.clone(), b.clone()]);
+        (sum, carry.remove(0))
+    }
+
+    /// Return `a + b mod 2^N`
+    /// constraints: N + 2
+    pub fn add<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, a: &Self, b: &Self) -> Self {
+        Self::add_many(cs, &[a.clone(), b.clone()])
+    }
+
+    /// Return the sum of all operands modulo `2^N` with a single decomposition
+    /// constraints: N + log2(operands) + 1
+    pub fn add_many<C: CircuitDriver<Scalar = F>>(cs: &mut R1cs<C>, operands: &[Self]) -> Self {
+        Self::sum(cs, operands).0
+    }
+
+    fn sum<C: CircuitDriver<Scalar = F>>(
+        cs: &mut R1cs<C>,
+        operands: &[Self],
+    ) -> (Self, Vec<BinaryAssignment>) {
+        assert!(operands.len() >= 2);
+        let carry_bits = (usize::BITS - (operands.len() - 1).leading_zeros()) as usize;
+        assert!(N + carry_bits < C::NUM_BITS as usize);
+
+        let mut sum = FieldAssignment::constant(&F::zero());
+        for operand in operands {
+            let operand = operand.to_field(cs);
+            sum = &sum + &operand;
+        }
+
+        let sum_bits = FieldAssignment::to_bits(cs, &sum, N + carry_bits);
+        FieldAssignment::enforce_eq_bits(cs, &sum, &sum_bits);
+
+        let (carry, bits) = sum_bits.split_at(carry_bits);
+        let bits = bits.iter().map(FieldAssignment::from).collect();
+
+        (Self { bits }, carry.to_vec())
+    }
+
+    fn bitwise<C: CircuitDriver<Scalar = F>>(
+        cs: &mut R1cs<C>,
+        a: &Self,
+        b: &Self,
+        f: impl Fn(&mut R1cs<C>, &FieldAssignment<F>, &FieldAssignment<F>) -> FieldAssignment<F>,
+    ) -> Self {
+        let bits = a
+            .bits
+            .iter()
+            .zip(b.bits.iter())
+            .map(|(x, y)| f(cs, x, y))
+            .collect();
+
+        Self { bits }
+    }
+}
+
+/// Return x + y - k * x * y as a single new wire so that chained bitwise operations
+/// keep their linear combinations short
+fn combine<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    x: &FieldAssignment<F>,
+    y: &FieldAssignment<F>,
+    k: F,
+) -> FieldAssignment<F> {
+    let k = FieldAssignment::constant(&k);
+    if x.inner().as_constant().is_some() || y.inner().as_constant().is_some() {
+        let xy = FieldAssignment::mul(cs, x, y);
+        let kxy = FieldAssignment::mul(cs, &k, &xy);
+        return &(x + y) - &kxy;
+    }
+
+    // (k * x) * y = x + y - z
+    let kx = FieldAssignment::mul(cs, &k, x);
+    let (x_val, y_val) = (x.value(cs), y.value(cs));
+    let z = FieldAssignment::witness(cs, x_val + y_val - kx.value(cs) * y_val);
+    cs.mul_gate(kx.inner(), y.inner(), (&(x + y) - &z).inner());
+
+    z
+}
+
+/// (b - 1) * b = 0
+pub(crate) fn enforce_boolean<F: PrimeField, C: CircuitDriver<Scalar = F>>(
+    cs: &mut R1cs<C>,
+    bit: &FieldAssignment<F>,
+) {
+    let bit_minus_one = bit - &FieldAssignment::constant(&F::one());
+    let zero = FieldAssignment::constant(&F::zero());
+    cs.mul_gate(bit_minus_one.inner(), bit.inner(), zero.inner());
+}
@@ -1,5 +1,16 @@
 pub use super::CircuitDriver;
 pub use crate::circuit::gadget::{
-    binary::BinaryAssignment, curve::PointAssignment, field::FieldAssignment,
+    affine_curve::AffinePointAssignment,
+    binary::BinaryAssignment,
+    blake2::{blake2b, blake2s},
+    curve::PointAssignment,
+    ecdsa::EcdsaSignatureAssignment,
+    edwards::EdwardsPointAssignment,
+    field::FieldAssignment,
+    keccak::{keccak256, keccak_f1600},
+    nonnative::NonNativeFieldAssignment,
+    nonnative_curve::NonNativePointAssignment,
+    sha256::{sha256, sha256_compression},
+    uint::{UInt32, UInt64, UInt8, UIntAssignment},
 };
 pub use crate::r1cs::R1cs;
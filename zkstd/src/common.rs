@@ -10,6 +10,7 @@ pub use core::{
     ops::{AddAssign, DivAssign, MulAssign, SubAssign},
     ops::{BitAnd, BitXor},
 };
+pub use parity_scale_codec::alloc::string::String;
 pub use parity_scale_codec::alloc::vec;
 pub use parity_scale_codec::{Decode, Encode};
 pub use paste;
@@ -11,6 +11,14 @@ use crate::common::{vec, Debug, Decode, Encode, PrimeField, Vec};
 pub struct SparseMatrix<Field: PrimeField>(pub(crate) Vec<SparseRow<Field>>);
 
 impl<F: PrimeField> SparseMatrix<F> {
+    pub fn new(rows: Vec<SparseRow<F>>) -> Self {
+        Self(rows)
+    }
+
+    pub fn rows(&self) -> &[SparseRow<F>] {
+        &self.0
+    }
+
     #[allow(clippy::type_complexity)]
     pub(crate) fn x_and_w(
         &self,
@@ -23,6 +23,10 @@ impl<F: PrimeField> SparseRow<F> {
         self.0.iter()
     }
 
+    pub fn terms(&self) -> &[(Wire, F)] {
+        &self.0
+    }
+
     pub fn one() -> Self {
         Self::from(F::one())
     }
@@ -40,6 +44,41 @@ impl<F: PrimeField> SparseRow<F> {
         }
     }
 
+    /// Merge the terms sharing a wire and drop zero coefficients
+    pub fn coalesce(&self) -> Self {
+        let mut terms: Vec<(Wire, F)> = Vec::with_capacity(self.0.len());
+        for (wire, coeff) in self.0.iter() {
+            match terms.iter_mut().find(|(w, _)| w == wire) {
+                Some((_, sum)) => *sum += *coeff,
+                None => terms.push((*wire, *coeff)),
+            }
+        }
+        Self::new(terms)
+    }
+
+    /// Remove every term of the wire and return the sum of their coefficients
+    pub(crate) fn take(&mut self, wire: Wire) -> Option<F> {
+        let mut coeff = None;
+        self.0.retain(|(w, c)| {
+            if *w == wire {
+                coeff = Some(coeff.unwrap_or_else(F::zero) + *c);
+                false
+            } else {
+                true
+            }
+        });
+        coeff
+    }
+
+    pub(crate) fn map_wires(&self, f: impl Fn(Wire) -> Wire) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|(wire, coeff)| (f(*wire), *coeff))
+                .collect(),
+        )
+    }
+
     pub fn evaluate(&self, instance: &DenseVectors<F>, witness: &DenseVectors<F>) -> F {
         self.0.iter().fold(F::zero(), |sum, (wire, coefficient)| {
             let wire_value = match wire {
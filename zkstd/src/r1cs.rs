@@ -1,3 +1,5 @@
+mod export;
+mod optimize;
 pub mod test;
 mod wire;
 
@@ -5,8 +7,9 @@ use crate::circuit::CircuitDriver;
 use crate::common::{vec, Ring, Vec};
 use crate::matrix::{DenseVectors, SparseMatrix, SparseRow};
 
+pub use export::FormatError;
 use sp_std::ops::Index;
-pub(crate) use wire::Wire;
+pub use wire::Wire;
 
 #[derive(Clone, Debug)]
 pub struct R1cs<C: CircuitDriver> {
@@ -0,0 +1,392 @@
+//! iden3 `.r1cs`/`.wtns` binary formats and JSON export.
+//! Wire ids follow the circom layout: the constant one at 0, public inputs next, and witnesses last.
+use super::{R1cs, Wire};
+use crate::circuit::CircuitDriver;
+use crate::common::{vec, PrimeField, Ring, String, Vec};
+use crate::matrix::{DenseVectors, SparseMatrix, SparseRow};
+
+use core::fmt::Write;
+
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+const R1CS_VERSION: u32 = 1;
+const WTNS_MAGIC: &[u8; 4] = b"wtns";
+const WTNS_VERSION: u32 = 2;
+
+const HEADER_SECTION: u32 = 1;
+const CONSTRAINT_SECTION: u32 = 2;
+const WIRE_TO_LABEL_SECTION: u32 = 3;
+const WTNS_DATA_SECTION: u32 = 2;
+
+/// byte length of a field element
+const N8: usize = 32;
+
+/// error while importing the iden3 binary formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatError {
+    InvalidMagic,
+    UnsupportedVersion(u32),
+    UnexpectedEof,
+    MissingSection(u32),
+    FieldSizeMismatch(u32),
+    PrimeMismatch,
+    NonCanonicalFieldElement,
+    WireOutOfRange(u32),
+    WitnessLengthMismatch { expected: usize, provided: usize },
+}
+
+impl<C: CircuitDriver> R1cs<C> {
+    /// Write the constraint system in the iden3 `.r1cs` binary format
+    pub fn write_r1cs(&self, buf: &mut Vec<u8>) {
+        let l = self.l();
+        let n_wires = l + self.m_l_1();
+
+        let mut header = Vec::new();
+        put_u32(&mut header, N8 as u32);
+        header.extend(prime_bytes::<C::Scalar>());
+        put_u32(&mut header, n_wires as u32);
+        // public outputs are not distinguished from public inputs
+        put_u32(&mut header, 0);
+        put_u32(&mut header, (l - 1) as u32);
+        put_u32(&mut header, 0);
+        put_u64(&mut header, n_wires as u64);
+        put_u32(&mut header, self.m as u32);
+
+        let mut constraints = Vec::new();
+        for i in 0..self.m as usize {
+            for matrix in [&self.a, &self.b, &self.c] {
+                let row = &matrix.0[i];
+                put_u32(&mut constraints, row.num_terms() as u32);
+                for (wire, coeff) in row.iter() {
+                    put_u32(&mut constraints, wire_id(*wire, l) as u32);
+                    constraints.extend(coeff.to_raw_bytes());
+                }
+            }
+        }
+
+        let mut labels = Vec::new();
+        for label in 0..n_wires {
+            put_u64(&mut labels, label as u64);
+        }
+
+        buf.extend(R1CS_MAGIC);
+        put_u32(buf, R1CS_VERSION);
+        put_u32(buf, 3);
+        put_section(buf, HEADER_SECTION, header);
+        put_section(buf, CONSTRAINT_SECTION, constraints);
+        put_section(buf, WIRE_TO_LABEL_SECTION, labels);
+    }
+
+    /// Write the instance and witness in the iden3 `.wtns` binary format
+    pub fn write_wtns(&self, buf: &mut Vec<u8>) {
+        let mut header = Vec::new();
+        put_u32(&mut header, N8 as u32);
+        header.extend(prime_bytes::<C::Scalar>());
+        put_u32(&mut header, (self.l() + self.m_l_1()) as u32);
+
+        let mut values = Vec::new();
+        for value in self.x.iter().chain(self.w.iter()) {
+            values.extend(value.to_raw_bytes());
+        }
+
+        buf.extend(WTNS_MAGIC);
+        put_u32(buf, WTNS_VERSION);
+        put_u32(buf, 2);
+        put_section(buf, HEADER_SECTION, header);
+        put_section(buf, WTNS_DATA_SECTION, values);
+    }
+
+    /// Read a constraint system from the iden3 `.r1cs` binary format.
+    /// The assignment is zero except for the constant one and can be filled by `read_wtns`.
+    pub fn read_r1cs(bytes: &[u8]) -> Result<Self, FormatError> {
+        let sections = read_sections(bytes, R1CS_MAGIC, R1CS_VERSION)?;
+        let mut header = Reader(find_section(&sections, HEADER_SECTION)?);
+        let n8 = header.u32()?;
+        if n8 as usize != N8 {
+            return Err(FormatError::FieldSizeMismatch(n8));
+        }
+        if header.take(N8)? != prime_bytes::<C::Scalar>().as_slice() {
+            return Err(FormatError::PrimeMismatch);
+        }
+        let n_wires = header.u32()? as usize;
+        let n_pub_out = header.u32()? as usize;
+        let n_pub_in = header.u32()? as usize;
+        let _n_prv_in = header.u32()?;
+        let _n_labels = header.u64()?;
+        let m = header.u32()? as usize;
+
+        let l = 1 + n_pub_out + n_pub_in;
+        if l > n_wires {
+            return Err(FormatError::WireOutOfRange(l as u32));
+        }
+        // every wire has a label id, which bounds the wire count by the file size
+        let mut labels = Reader(find_section(&sections, WIRE_TO_LABEL_SECTION)?);
+        labels.take_many(n_wires, 8)?;
+        let mut constraints = Reader(find_section(&sections, CONSTRAINT_SECTION)?);
+        let mut matrices = [vec![], vec![], vec![]];
+        for _ in 0..m {
+            for matrix in matrices.iter_mut() {
+                let n_terms = constraints.u32()? as usize;
+                let mut row = Vec::with_capacity(constraints.bound(n_terms, 4 + N8)?);
+                for _ in 0..n_terms {
+                    let id = constraints.u32()?;
+                    if id as usize >= n_wires {
+                        return Err(FormatError::WireOutOfRange(id));
+                    }
+                    let wire = if (id as usize) < l {
+                        Wire::Instance(id as u64)
+                    } else {
+                        Wire::Witness((id as usize - l) as u64)
+                    };
+                    row.push((wire, constraints.field::<C::Scalar>()?));
+                }
+                matrix.push(SparseRow::new(row));
+            }
+        }
+        let [a, b, c] = matrices;
+
+        let mut x = DenseVectors::zero(l);
+        x[0] = C::Scalar::one();
+
+        Ok(Self {
+            m: m as u64,
+            a: SparseMatrix(a),
+            b: SparseMatrix(b),
+            c: SparseMatrix(c),
+            x,
+            w: DenseVectors::zero(n_wires - l),
+        })
+    }
+
+    /// Replace the instance and witness with the values of an iden3 `.wtns` file
+    pub fn read_wtns(&mut self, bytes: &[u8]) -> Result<(), FormatError> {
+        let sections = read_sections(bytes, WTNS_MAGIC, WTNS_VERSION)?;
+        let mut header = Reader(find_section(&sections, HEADER_SECTION)?);
+        let n8 = header.u32()?;
+        if n8 as usize != N8 {
+            return Err(FormatError::FieldSizeMismatch(n8));
+        }
+        if header.take(N8)? != prime_bytes::<C::Scalar>().as_slice() {
+            return Err(FormatError::PrimeMismatch);
+        }
+        let n_values = header.u32()? as usize;
+        let expected = self.l() + self.m_l_1();
+        if n_values != expected {
+            return Err(FormatError::WitnessLengthMismatch {
+                expected,
+                provided: n_values,
+            });
+        }
+
+        let mut data = Reader(find_section(&sections, WTNS_DATA_SECTION)?);
+        let mut values = Vec::with_capacity(n_values);
+        for _ in 0..n_values {
+            values.push(data.field::<C::Scalar>()?);
+        }
+        let w = values.split_off(self.l());
+        self.x = DenseVectors::new(values);
+        self.w = DenseVectors::new(w);
+
+        Ok(())
+    }
+
+    /// Export the A, B and C matrices as JSON in the `snarkjs r1cs export json` layout
+    /// with an additional `labels` entry naming every wire
+    pub fn to_json(&self) -> String {
+        let l = self.l();
+        let n_wires = l + self.m_l_1();
+        let mut json = String::new();
+
+        write!(
+            json,
+            "{{\"n8\":{},\"prime\":\"{}\",\"nVars\":{},\"nOutputs\":0,\"nPubInputs\":{},\"nPrvInputs\":0,\"nLabels\":{},\"nConstraints\":{},\"constraints\":[",
+            N8,
+            to_decimal(&prime_bytes::<C::Scalar>()),
+            n_wires,
+            l - 1,
+            n_wires,
+            self.m
+        )
+        .unwrap();
+        for i in 0..self.m as usize {
+            if i != 0 {
+                json.push(',');
+            }
+            json.push('[');
+            for (j, matrix) in [&self.a, &self.b, &self.c].iter().enumerate() {
+                if j != 0 {
+                    json.push(',');
+                }
+                json.push('{');
+                for (k, (wire, coeff)) in matrix.0[i].iter().enumerate() {
+                    if k != 0 {
+                        json.push(',');
+                    }
+                    write!(
+                        json,
+                        "\"{}\":\"{}\"",
+                        wire_id(*wire, l),
+                        to_decimal(&coeff.to_raw_bytes())
+                    )
+                    .unwrap();
+                }
+                json.push('}');
+            }
+            json.push(']');
+        }
+        json.push_str("],\"map\":[");
+        for id in 0..n_wires {
+            if id != 0 {
+                json.push(',');
+            }
+            write!(json, "{}", id).unwrap();
+        }
+        json.push_str("],\"labels\":[");
+        for id in 0..n_wires {
+            if id != 0 {
+                json.push(',');
+            }
+            let wire = if id < l {
+                Wire::Instance(id as u64)
+            } else {
+                Wire::Witness((id - l) as u64)
+            };
+            write!(json, "\"{}\"", wire).unwrap();
+        }
+        json.push_str("]}");
+
+        json
+    }
+}
+
+fn wire_id(wire: Wire, l: usize) -> usize {
+    match wire {
+        Wire::Instance(i) => i as usize,
+        Wire::Witness(i) => i as usize + l,
+    }
+}
+
+/// field modulus in little-endian, recovered as (p - 1) + 1
+fn prime_bytes<F: PrimeField>() -> Vec<u8> {
+    let mut bytes = (-F::one()).to_raw_bytes();
+    for byte in bytes.iter_mut() {
+        let (sum, carry) = byte.overflowing_add(1);
+        *byte = sum;
+        if !carry {
+            break;
+        }
+    }
+    bytes
+}
+
+/// decimal representation of a little-endian natural number
+fn to_decimal(bytes: &[u8]) -> String {
+    let mut digits = Vec::new();
+    let mut num = bytes.to_vec();
+    while num.iter().any(|byte| *byte != 0) {
+        let mut rem = 0u16;
+        for byte in num.iter_mut().rev() {
+            let cur = (rem << 8) | *byte as u16;
+            *byte = (cur / 10) as u8;
+            rem = cur % 10;
+        }
+        digits.push(b'0' + rem as u8);
+    }
+    if digits.is_empty() {
+        digits.push(b'0');
+    }
+    digits.iter().rev().map(|d| *d as char).collect()
+}
+
+fn put_u32(buf: &mut Vec<u8>, value: u32) {
+    buf.extend(value.to_le_bytes());
+}
+
+fn put_u64(buf: &mut Vec<u8>, value: u64) {
+    buf.extend(value.to_le_bytes());
+}
+
+fn put_section(buf: &mut Vec<u8>, section_type: u32, content: Vec<u8>) {
+    put_u32(buf, section_type);
+    put_u64(buf, content.len() as u64);
+    buf.extend(content);
+}
+
+fn read_sections<'a>(
+    bytes: &'a [u8],
+    magic: &[u8; 4],
+    version: u32,
+) -> Result<Vec<(u32, &'a [u8])>, FormatError> {
+    let mut reader = Reader(bytes);
+    if reader.take(4)? != magic {
+        return Err(FormatError::InvalidMagic);
+    }
+    let file_version = reader.u32()?;
+    if file_version != version {
+        return Err(FormatError::UnsupportedVersion(file_version));
+    }
+    let n_sections = reader.u32()? as usize;
+    // a section is at least its type and size
+    let mut sections = Vec::with_capacity(reader.bound(n_sections, 12)?);
+    for _ in 0..n_sections {
+        let section_type = reader.u32()?;
+        let size = reader.u64()?;
+        sections.push((section_type, reader.take(size as usize)?));
+    }
+    Ok(sections)
+}
+
+fn find_section<'a>(
+    sections: &[(u32, &'a [u8])],
+    section_type: u32,
+) -> Result<&'a [u8], FormatError> {
+    sections
+        .iter()
+        .find(|(t, _)| *t == section_type)
+        .map(|(_, content)| *content)
+        .ok_or(FormatError::MissingSection(section_type))
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], FormatError> {
+        if self.0.len() < n {
+            return Err(FormatError::UnexpectedEof);
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    /// check that `n` items of `size` bytes fit in the remaining bytes
+    fn bound(&self, n: usize, size: usize) -> Result<usize, FormatError> {
+        if n > self.0.len() / size {
+            return Err(FormatError::UnexpectedEof);
+        }
+        Ok(n)
+    }
+
+    fn take_many(&mut self, n: usize, size: usize) -> Result<&'a [u8], FormatError> {
+        self.bound(n, size)?;
+        self.take(n * size)
+    }
+
+    fn u32(&mut self) -> Result<u32, FormatError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, FormatError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn field<F: PrimeField>(&mut self) -> Result<F, FormatError> {
+        let bytes = self.take(N8)?;
+        let mut wide = [0; 64];
+        wide[..N8].copy_from_slice(bytes);
+        let value = F::from_bytes_wide(&wide);
+        if value.to_raw_bytes() != bytes {
+            return Err(FormatError::NonCanonicalFieldElement);
+        }
+        Ok(value)
+    }
+}
@@ -0,0 +1,159 @@
+use super::{R1cs, Wire};
+use crate::circuit::CircuitDriver;
+use crate::common::{vec, Group, PrimeField, Vec};
+use crate::matrix::{DenseVectors, SparseMatrix, SparseRow};
+
+impl<C: CircuitDriver> R1cs<C> {
+    /// Reduce the constraint system without changing the relation it encodes.
+    /// 1. merge duplicate wire terms of every linear combination
+    /// 2. eliminate constraints with a constant A or B by substituting one of their witnesses
+    /// 3. remove witnesses no constraint refers to
+    ///
+    /// Instances are never substituted or reordered. The choice of the substituted witness only
+    /// depends on the matrices, so a circuit always reduces to the same shape regardless of
+    /// its assignment. Assignments allocated before calling this are invalidated.
+    pub fn optimize(&mut self) {
+        let m = self.m as usize;
+        let mut constraints: Vec<[SparseRow<C::Scalar>; 3]> = self
+            .a
+            .0
+            .iter()
+            .zip(self.b.0.iter())
+            .zip(self.c.0.iter())
+            .map(|((a, b), c)| [a.coalesce(), b.coalesce(), c.coalesce()])
+            .collect();
+        let mut removed = vec![false; m];
+
+        // constraints each witness appears in, may contain stale entries
+        let mut occurrences = vec![vec![]; self.m_l_1()];
+        for (i, constraint) in constraints.iter().enumerate() {
+            for row in constraint.iter() {
+                for (wire, _) in row.iter() {
+                    if let Wire::Witness(k) = wire {
+                        occurrences[*k as usize].push(i);
+                    }
+                }
+            }
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for i in 0..m {
+                if removed[i] {
+                    continue;
+                }
+                let linear = match linear_form(&constraints[i]) {
+                    Some(linear) => linear,
+                    None => continue,
+                };
+                if linear.num_terms() == 0 {
+                    removed[i] = true;
+                    changed = true;
+                    continue;
+                }
+
+                // substitute the witness appearing in the fewest constraints
+                let pivot = linear
+                    .iter()
+                    .filter_map(|(wire, coeff)| match wire {
+                        Wire::Witness(k) => Some((*k as usize, *coeff)),
+                        Wire::Instance(_) => None,
+                    })
+                    .min_by_key(|(k, _)| occurrences[*k].len());
+                let (k, coeff) = match pivot {
+                    Some(pivot) => pivot,
+                    None => continue,
+                };
+                removed[i] = true;
+                changed = true;
+
+                // witness = -(linear - coeff * witness) / coeff
+                let wire = Wire::Witness(k as u64);
+                let mut expr = linear;
+                expr.take(wire);
+                let expr = expr * -coeff.invert().unwrap();
+
+                let mut targets = core::mem::take(&mut occurrences[k]);
+                targets.sort_unstable();
+                targets.dedup();
+                for j in targets.into_iter().filter(|j| !removed[*j]) {
+                    for row in constraints[j].iter_mut() {
+                        if let Some(c) = row.take(wire) {
+                            *row = (&*row + &(&expr * c)).coalesce();
+                        }
+                    }
+                    for (w, _) in expr.iter() {
+                        if let Wire::Witness(w) = w {
+                            occurrences[*w as usize].push(j);
+                        }
+                    }
+                }
+            }
+        }
+
+        // renumber the remaining witnesses keeping their order
+        let mut used = vec![false; self.m_l_1()];
+        for constraint in constraints
+            .iter()
+            .zip(removed.iter())
+            .filter(|(_, removed)| !**removed)
+            .map(|(constraint, _)| constraint)
+        {
+            for row in constraint.iter() {
+                for (wire, _) in row.iter() {
+                    if let Wire::Witness(k) = wire {
+                        used[*k as usize] = true;
+                    }
+                }
+            }
+        }
+        let mut index = vec![0; self.m_l_1()];
+        let mut w = vec![];
+        for (k, value) in self.w.iter().enumerate() {
+            if used[k] {
+                index[k] = w.len() as u64;
+                w.push(value);
+            }
+        }
+        let rewire = |wire: Wire| match wire {
+            Wire::Instance(i) => Wire::Instance(i),
+            Wire::Witness(k) => Wire::Witness(index[k as usize]),
+        };
+
+        let (mut a, mut b, mut c) = (vec![], vec![], vec![]);
+        for [a_row, b_row, c_row] in constraints
+            .into_iter()
+            .zip(removed)
+            .filter(|(_, removed)| !removed)
+            .map(|(constraint, _)| constraint)
+        {
+            a.push(a_row.map_wires(rewire));
+            b.push(b_row.map_wires(rewire));
+            c.push(c_row.map_wires(rewire));
+        }
+
+        self.m = a.len() as u64;
+        self.a = SparseMatrix(a);
+        self.b = SparseMatrix(b);
+        self.c = SparseMatrix(c);
+        self.w = DenseVectors::new(w);
+    }
+}
+
+/// Return the linear combination which must be zero if A or B is a constant
+fn linear_form<F: PrimeField>(constraint: &[SparseRow<F>; 3]) -> Option<SparseRow<F>> {
+    let [a, b, c] = constraint;
+    let constant = |row: &SparseRow<F>| {
+        if row.num_terms() == 0 {
+            Some(F::zero())
+        } else {
+            row.as_constant()
+        }
+    };
+    if let Some(k) = constant(a) {
+        Some((&(b * k) - c).coalesce())
+    } else {
+        constant(b).map(|k| (&(a * k) - c).coalesce())
+    }
+}
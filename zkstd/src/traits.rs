@@ -4,6 +4,7 @@ mod algebra;
 mod curve;
 mod fft;
 mod field;
+mod pairing;
 mod primitive;
 mod sign;
 
@@ -11,5 +12,6 @@ pub use algebra::*;
 pub use curve::*;
 pub use fft::*;
 pub use field::*;
+pub use pairing::*;
 pub use primitive::*;
 pub use sign::*;
@@ -0,0 +1,40 @@
+// This trait represents pairing-friendly curves used by pairing-based arguments
+
+use super::algebra::IntGroup;
+use super::curve::{BNAffine, BNProjective};
+use super::fft::FftField;
+use core::fmt::Debug;
+
+/// pairing-friendly curve
+/// two source groups of prime order with a common scalar field, a target group
+/// written additively and a bilinear map between them
+pub trait PairingEngine: Clone + Copy + Debug + Default + Eq + Send + Sync + 'static {
+    // scalar field of both source groups
+    type Fr: FftField;
+
+    // first source group
+    type G1Affine: BNAffine<Scalar = Self::Fr, Extended = Self::G1Projective>;
+    type G1Projective: BNProjective<Affine = Self::G1Affine, Scalar = Self::Fr>;
+
+    // second source group
+    type G2Affine: BNAffine<Scalar = Self::Fr, Extended = Self::G2Projective>;
+    type G2Projective: BNProjective<Affine = Self::G2Affine, Scalar = Self::Fr>;
+
+    // point of second source group with precomputed miller loop coefficients
+    type G2Prepared: Clone + Debug + Send + Sync + From<Self::G2Affine>;
+
+    // target group
+    type Gt: IntGroup;
+
+    // miller loop output before the final exponentiation
+    type MillerLoopOutput;
+
+    // product of the miller loops of the pairs
+    fn multi_miller_loop(pairs: &[(Self::G1Affine, Self::G2Prepared)]) -> Self::MillerLoopOutput;
+
+    fn final_exponentiation(f: Self::MillerLoopOutput) -> Self::Gt;
+
+    fn pairing(g1: Self::G1Affine, g2: Self::G2Affine) -> Self::Gt {
+        Self::final_exponentiation(Self::multi_miller_loop(&[(g1, g2.into())]))
+    }
+}
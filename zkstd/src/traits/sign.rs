@@ -12,6 +12,9 @@ pub trait SigUtils<const L: usize>: Sized {
 }
 
 pub trait RedDSA: Copy + Debug + Default + Ord + PartialEq {
+    // the cofactor of the curve is 2^COFACTOR_BITS
+    const COFACTOR_BITS: usize;
+
     type Base: FftField + SigUtils<32> + EncodeLike + Decode;
 
     type Scalar: FftField + SigUtils<32> + Into<Self::Base>;
@@ -459,6 +459,19 @@ pub const BLS12_381_R3: [u64; 6] = [
     0x0aa6346091755d4d,
 ];
 
+pub const SECP256K1_MODULUS: [u64; 4] = [
+    0xfffffffefffffc2f,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+];
+
+pub const SECP256K1_INV: u64 = 0xd838091dd2253531;
+
+pub const SECP256K1_R2: [u64; 4] = [0x000007a2000e90a1, 0x0000000000000001, 0, 0];
+
+pub const SECP256K1_R3: [u64; 4] = [0x002bb1e33795f671, 0x0000000100000b73, 0, 0];
+
 pub(crate) type Bits256Limbs = [u64; 4];
 pub(crate) type Bits384Limbs = [u64; 6];
 
@@ -481,3 +494,13 @@ field_test_data!(
     BLS12_381_R2,
     BLS12_381_R3
 );
+
+field_test_data!(
+    secp256k1_field,
+    bits_256,
+    Bits256Limbs,
+    SECP256K1_MODULUS,
+    SECP256K1_INV,
+    SECP256K1_R2,
+    SECP256K1_R3
+);
@@ -135,3 +135,73 @@ limbs_test!(
     [1, 0, 0, 0, 0, 0],
     [2, 0, 0, 0, 0, 0]
 );
+limbs_test!(
+    secp256k1_limbs_tests,
+    bits_256,
+    secp256k1_field,
+    Bits256Limbs,
+    [1, 0, 0, 0],
+    [2, 0, 0, 0]
+);
+
+// the secp256k1 modulus uses the full 256 bits, so the intermediate values exceed 2^256
+#[cfg(test)]
+mod secp256k1_carry_tests {
+    use super::*;
+    use jubjub::secp256k1_field::*;
+    use zkstd::arithmetic::bits_256::*;
+
+    const MINUS_ONE: [u64; 4] = [
+        0xfffffffefffffc2e,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ];
+
+    const MINUS_TWO: [u64; 4] = [
+        0xfffffffefffffc2d,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ];
+
+    #[test]
+    fn secp256k1_add_carry_test() {
+        assert_eq!(add(MINUS_ONE, MINUS_ONE, MODULUS), MINUS_TWO);
+        assert_eq!(double(MINUS_ONE, MODULUS), MINUS_TWO);
+    }
+
+    #[test]
+    fn secp256k1_mul_carry_test() {
+        let a = from_raw(MINUS_ONE);
+        let one = from_raw([1, 0, 0, 0]);
+
+        // (-1) * (-1) = 1
+        assert_eq!(mul(a, a, MODULUS, INV), one);
+        assert_eq!(square(a, MODULUS, INV), one);
+    }
+
+    #[test]
+    fn secp256k1_mont_carry_test() {
+        let a = MINUS_ONE;
+        let wide = [a[0], a[1], a[2], a[3], a[0], a[1], a[2], a[3]];
+        let r_inv = mont([1, 0, 0, 0, 0, 0, 0, 0], MODULUS, INV);
+
+        // (-1 - 2^256) / 2^256 = -1 - 2^-256
+        assert_eq!(mont(wide, MODULUS, INV), sub(MINUS_ONE, r_inv, MODULUS));
+    }
+}
+
+// the cross products of the square carry into the seventh limb for any modulus
+#[cfg(test)]
+mod square_carry_tests {
+    use super::*;
+    use jubjub::jubjub_field::*;
+    use zkstd::arithmetic::bits_256::*;
+
+    #[test]
+    fn square_carry_test() {
+        let a = [0, u64::MAX, u64::MAX, 1];
+        assert_eq!(square(a, MODULUS, INV), mul(a, a, MODULUS, INV));
+    }
+}